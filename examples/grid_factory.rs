@@ -1,9 +1,11 @@
 use gtk::prelude::{BoxExt, ButtonExt, GridExt, GtkWindowExt, OrientableExt};
-use relm4::factory::positions::GridPosition;
-use relm4::factory::{DynamicIndex, FactoryComponent, FactorySender, FactoryVecDeque, Position};
-use relm4::{ComponentParts, ComponentSender, RelmApp, RelmWidgetExt, SimpleComponent};
+use relm4::factory::{DynamicIndex, FactoryComponent, FactorySender, FactoryVecDeque};
+use relm4::{
+    ComponentParts, ComponentSender, GridPosition, RelmApp, RelmWidgetExt, SimpleComponent,
+};
 
-#[derive(Debug)]
+#[derive(Debug, GridPosition)]
+#[grid(rows = 5)]
 struct Counter {
     value: u8,
 }
@@ -25,20 +27,6 @@ struct CounterWidgets {
     label: gtk::Label,
 }
 
-impl Position<GridPosition, DynamicIndex> for Counter {
-    fn position(&self, index: &DynamicIndex) -> GridPosition {
-        let index = index.current_index();
-        let x = index % 5;
-        let y = index / 5;
-        GridPosition {
-            column: y as i32,
-            row: x as i32,
-            width: 1,
-            height: 1,
-        }
-    }
-}
-
 impl FactoryComponent for Counter {
     type Init = u8;
     type Input = CounterMsg;
@@ -14,9 +14,10 @@ use gtk::prelude::{
 };
 use relm4::{ComponentParts, ComponentSender, RelmApp, RelmWidgetExt, SimpleComponent};
 
-#[tracker::track]
+#[derive(relm4::Tracked)]
 struct App {
     value: u8,
+    tracker: u8,
 }
 
 #[derive(Debug)]
@@ -27,13 +27,14 @@ enum Msg {
     UpdateSecond,
 }
 
-// The track proc macro allows to easily track changes to different
+// The tracked derive macro allows to easily track changes to different
 // fields of the model
-#[tracker::track]
+#[derive(relm4::Tracked)]
 struct App {
     first_icon: &'static str,
     second_icon: &'static str,
     identical: bool,
+    tracker: u8,
 }
 
 #[relm4::component]
@@ -130,6 +130,11 @@ impl SimpleComponent for App {
                     }
                 },
 
+                gtk::MenuButton {
+                    set_label: "Columns",
+                    set_popover: Some(&column_popover),
+                },
+
                 gtk::ScrolledWindow {
                     set_vexpand: true,
 
@@ -151,6 +156,9 @@ impl SimpleComponent for App {
         view_wrapper.append_column::<Label2Column>();
         view_wrapper.append_column::<ButtonColumn>();
 
+        // Let users show, hide and reorder the columns
+        let column_popover = view_wrapper.column_popover();
+
         // Add a filter and disable it
         view_wrapper.add_filter(|item| item.value % 2 == 0);
         view_wrapper.set_filter_status(0, false);
@@ -0,0 +1,154 @@
+use gtk::prelude::*;
+use relm4::{
+    prelude::*,
+    typed_view::{
+        column::{CheckEditor, EditableColumn, EntryEditor, SpinEditor, TypedColumnView},
+        TypedListItem,
+    },
+};
+
+#[derive(Debug)]
+struct Task {
+    name: String,
+    hours: f64,
+    done: bool,
+}
+
+impl Task {
+    fn new(name: &str, hours: f64) -> Self {
+        Self {
+            name: name.to_owned(),
+            hours,
+            done: false,
+        }
+    }
+}
+
+struct NameColumn;
+
+impl EditableColumn for NameColumn {
+    type Item = Task;
+    type Editor = EntryEditor;
+
+    const COLUMN_NAME: &'static str = "Task";
+    const ENABLE_EXPAND: bool = true;
+
+    fn get_cell_value(item: &Self::Item) -> String {
+        item.name.clone()
+    }
+}
+
+struct HoursColumn;
+
+impl EditableColumn for HoursColumn {
+    type Item = Task;
+    type Editor = SpinEditor;
+
+    const COLUMN_NAME: &'static str = "Hours";
+
+    fn get_cell_value(item: &Self::Item) -> f64 {
+        item.hours
+    }
+
+    fn setup_editor(editor: &gtk::SpinButton) {
+        editor.set_range(0.0, 24.0);
+        editor.set_increments(0.5, 1.0);
+        editor.set_digits(1);
+    }
+}
+
+struct DoneColumn;
+
+impl EditableColumn for DoneColumn {
+    type Item = Task;
+    type Editor = CheckEditor;
+
+    const COLUMN_NAME: &'static str = "Done";
+
+    fn get_cell_value(item: &Self::Item) -> bool {
+        item.done
+    }
+}
+
+struct App {
+    view_wrapper: TypedColumnView<Task, gtk::NoSelection>,
+}
+
+#[derive(Debug)]
+enum Msg {
+    Rename(TypedListItem<Task>, String),
+    SetHours(TypedListItem<Task>, f64),
+    SetDone(TypedListItem<Task>, bool),
+}
+
+#[relm4::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = Msg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            set_title: Some("Editable cells"),
+            set_default_size: (400, 250),
+
+            gtk::ScrolledWindow {
+                #[local_ref]
+                my_view -> gtk::ColumnView {}
+            }
+        }
+    }
+
+    fn init(
+        _: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let mut view_wrapper = TypedColumnView::<Task, gtk::NoSelection>::new();
+
+        // Every edit sends a message with the row item and the new value
+        view_wrapper.append_editable_column::<NameColumn, _, _>(sender.input_sender(), Msg::Rename);
+        view_wrapper
+            .append_editable_column::<HoursColumn, _, _>(sender.input_sender(), Msg::SetHours);
+        view_wrapper
+            .append_editable_column::<DoneColumn, _, _>(sender.input_sender(), Msg::SetDone);
+
+        view_wrapper.extend_from_iter([
+            Task::new("Write documentation", 2.0),
+            Task::new("Fix bugs", 4.5),
+            Task::new("Review changes", 1.0),
+        ]);
+
+        let model = App { view_wrapper };
+
+        let my_view = &model.view_wrapper.view;
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        // Apply the new value to the item
+        let item = match msg {
+            Msg::Rename(item, name) => {
+                item.borrow_mut().name = name;
+                item
+            }
+            Msg::SetHours(item, hours) => {
+                item.borrow_mut().hours = hours;
+                item
+            }
+            Msg::SetDone(item, done) => {
+                item.borrow_mut().done = done;
+                item
+            }
+        };
+        println!("{:?}", item.borrow());
+    }
+}
+
+fn main() {
+    let app = RelmApp::new("relm4.example.typed-column-view-editable");
+    app.run::<App>(());
+}
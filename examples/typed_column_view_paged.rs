@@ -0,0 +1,138 @@
+use std::{ops::Range, time::Duration};
+
+use gtk::{glib, prelude::*};
+use relm4::{
+    prelude::*,
+    typed_view::{
+        column::{LabelColumn, TypedColumnView},
+        PagedLoader,
+    },
+};
+
+/// The amount of rows in the simulated database.
+const ROWS: u32 = 100_000;
+
+#[derive(Debug)]
+struct Row {
+    id: u32,
+    /// [`None`] while the row is loading.
+    name: Option<String>,
+}
+
+struct IdColumn;
+
+impl LabelColumn for IdColumn {
+    type Item = Row;
+    type Value = u32;
+
+    const COLUMN_NAME: &'static str = "ID";
+    const ENABLE_SORT: bool = false;
+
+    fn get_cell_value(item: &Self::Item) -> Self::Value {
+        item.id
+    }
+}
+
+struct NameColumn;
+
+impl LabelColumn for NameColumn {
+    type Item = Row;
+    type Value = String;
+
+    const COLUMN_NAME: &'static str = "Name";
+    const ENABLE_SORT: bool = false;
+    const ENABLE_EXPAND: bool = true;
+
+    fn get_cell_value(item: &Self::Item) -> Self::Value {
+        item.name.clone().unwrap_or_else(|| "Loading…".to_owned())
+    }
+}
+
+/// Simulates a slow database query.
+async fn fetch_rows(range: Range<u32>) -> Vec<Row> {
+    glib::timeout_future(Duration::from_millis(500)).await;
+
+    range
+        .map(|id| Row {
+            id,
+            name: Some(format!("Row number {id}")),
+        })
+        .collect()
+}
+
+struct App {
+    view_wrapper: TypedColumnView<Row, gtk::SingleSelection>,
+    loader: PagedLoader<Row>,
+}
+
+#[derive(Debug)]
+enum Msg {
+    Reload,
+}
+
+#[relm4::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = Msg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            set_title: Some("Paged loading"),
+            set_default_size: (400, 500),
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 5,
+                set_margin_all: 5,
+
+                gtk::Button {
+                    set_label: "Reload",
+                    connect_clicked => Msg::Reload,
+                },
+
+                gtk::ScrolledWindow {
+                    set_vexpand: true,
+
+                    #[local_ref]
+                    my_view -> gtk::ColumnView {}
+                }
+            }
+        }
+    }
+
+    fn init(
+        _: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let mut view_wrapper = TypedColumnView::<Row, gtk::SingleSelection>::new();
+        view_wrapper.append_column::<IdColumn>();
+        view_wrapper.append_column::<NameColumn>();
+
+        // Fetch 50 rows at once as soon as they are scrolled into view
+        let loader = view_wrapper.load_paged(ROWS, 50, |id| Row { id, name: None }, fetch_rows);
+
+        let model = App {
+            view_wrapper,
+            loader,
+        };
+
+        let my_view = &model.view_wrapper.view;
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            Msg::Reload => self.loader.reload(ROWS),
+        }
+    }
+}
+
+fn main() {
+    let app = RelmApp::new("relm4.example.typed-column-view-paged");
+    app.run::<App>(());
+}
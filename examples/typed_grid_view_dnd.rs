@@ -0,0 +1,127 @@
+use gtk::prelude::*;
+use relm4::{
+    prelude::*,
+    typed_view::{
+        grid::{RelmGridItem, TypedGridView},
+        TypedListItem,
+    },
+};
+
+#[derive(Debug)]
+struct Icon {
+    name: &'static str,
+}
+
+impl RelmGridItem for Icon {
+    type Root = gtk::Box;
+    type Widgets = (gtk::Image, gtk::Label);
+
+    fn setup(_item: &gtk::ListItem) -> (gtk::Box, Self::Widgets) {
+        relm4::view! {
+            my_box = gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_margin_all: 5,
+                set_spacing: 5,
+
+                #[name = "image"]
+                gtk::Image {
+                    set_pixel_size: 48,
+                },
+
+                #[name = "label"]
+                gtk::Label,
+            }
+        }
+
+        (my_box, (image, label))
+    }
+
+    fn bind(&mut self, (image, label): &mut Self::Widgets, _root: &mut Self::Root) {
+        image.set_icon_name(Some(self.name));
+        label.set_label(self.name);
+    }
+}
+
+const ICONS: &[&str] = &[
+    "audio-x-generic",
+    "camera-photo",
+    "computer",
+    "document-open",
+    "edit-copy",
+    "folder",
+    "go-home",
+    "help-browser",
+    "mail-send",
+    "user-trash",
+];
+
+struct App {
+    grid_view_wrapper: TypedGridView<Icon, gtk::SingleSelection>,
+}
+
+#[derive(Debug)]
+enum Msg {
+    Move(TypedListItem<Icon>, u32),
+}
+
+#[relm4::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = Msg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            set_title: Some("Reorder icons with drag and drop"),
+            set_default_size: (500, 400),
+
+            gtk::ScrolledWindow {
+                #[local_ref]
+                my_view -> gtk::GridView {
+                    set_orientation: gtk::Orientation::Vertical,
+                    set_max_columns: 5,
+                }
+            }
+        }
+    }
+
+    fn init(
+        _: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let mut grid_view_wrapper: TypedGridView<Icon, gtk::SingleSelection> = TypedGridView::new();
+        grid_view_wrapper.extend_from_iter(ICONS.iter().map(|&name| Icon { name }));
+
+        // Send a message whenever an icon is dropped
+        grid_view_wrapper.enable_drag_and_drop(sender.input_sender(), Msg::Move);
+
+        let model = App { grid_view_wrapper };
+
+        let my_view = &model.grid_view_wrapper.view;
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            Msg::Move(item, position) => {
+                self.grid_view_wrapper.move_item(&item, position);
+            }
+        }
+    }
+}
+
+fn main() {
+    let app = RelmApp::new("relm4.example.typed-grid-view-dnd");
+
+    // Show where the dragged icon will be inserted
+    relm4::set_global_css(
+        ".drop-before { box-shadow: inset 3px 0 @accent_bg_color; }
+        .drop-after { box-shadow: inset -3px 0 @accent_bg_color; }",
+    );
+
+    app.run::<App>(());
+}
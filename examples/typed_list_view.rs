@@ -111,6 +111,11 @@ impl SimpleComponent for App {
                     connect_clicked => Msg::Remove,
                 },
 
+                #[local_ref]
+                search_entry -> gtk::SearchEntry {
+                    set_placeholder_text: Some("Search numbers"),
+                },
+
                 gtk::ToggleButton {
                     set_label: "Only show even numbers",
                     connect_clicked[sender] => move |btn| {
@@ -141,6 +146,12 @@ impl SimpleComponent for App {
         list_view_wrapper.add_filter(|item| item.value % 2 == 0);
         list_view_wrapper.set_filter_status(0, false);
 
+        // Only show the numbers that contain the text of the search entry
+        let search_entry = gtk::SearchEntry::new();
+        list_view_wrapper.bind_search_entry(&search_entry, |item, query| {
+            item.value.to_string().contains(query)
+        });
+
         let model = App {
             counter,
             list_view_wrapper,
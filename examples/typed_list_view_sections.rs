@@ -0,0 +1,100 @@
+use gtk::prelude::*;
+use relm4::{
+    prelude::*,
+    typed_view::list::{RelmListItem, RelmSectionItem, TypedListView},
+};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Contact {
+    name: &'static str,
+}
+
+impl RelmListItem for Contact {
+    type Root = gtk::Label;
+    type Widgets = ();
+
+    fn setup(_item: &gtk::ListItem) -> (gtk::Label, ()) {
+        let label = gtk::Label::builder()
+            .halign(gtk::Align::Start)
+            .margin_start(10)
+            .build();
+        (label, ())
+    }
+
+    fn bind(&mut self, _widgets: &mut Self::Widgets, root: &mut Self::Root) {
+        root.set_label(self.name);
+    }
+}
+
+impl RelmSectionItem for Contact {
+    type Section = char;
+    type HeaderRoot = gtk::Label;
+    type HeaderWidgets = ();
+
+    fn section(&self) -> char {
+        self.name.chars().next().unwrap_or_default()
+    }
+
+    fn setup_header(_header: &gtk::ListHeader) -> (gtk::Label, ()) {
+        let label = gtk::Label::builder().halign(gtk::Align::Start).build();
+        label.add_css_class("heading");
+        (label, ())
+    }
+
+    fn bind_header(
+        section: &char,
+        _widgets: &mut Self::HeaderWidgets,
+        root: &mut Self::HeaderRoot,
+    ) {
+        root.set_label(&section.to_string());
+    }
+}
+
+struct App {
+    contacts: TypedListView<Contact, gtk::NoSelection>,
+}
+
+#[relm4::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = ();
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            set_title: Some("Contacts"),
+            set_default_size: (300, 400),
+
+            gtk::ScrolledWindow {
+                #[local_ref]
+                contacts_view -> gtk::ListView {}
+            }
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        root: Self::Root,
+        _sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let mut contacts = TypedListView::with_sections_and_sorting();
+        contacts.extend_from_iter(
+            ["Bob", "Alice", "Carol", "Anna", "Bert", "Chris", "Aaron"]
+                .into_iter()
+                .map(|name| Contact { name }),
+        );
+
+        let model = App { contacts };
+
+        let contacts_view = &model.contacts.view;
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+}
+
+fn main() {
+    let app = RelmApp::new("relm4.example.typed-list-view-sections");
+    app.run::<App>(());
+}
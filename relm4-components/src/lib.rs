@@ -37,6 +37,7 @@
 pub mod alert;
 pub mod open_button;
 pub mod open_dialog;
+pub mod progress_bar;
 pub mod save_dialog;
 #[cfg(feature = "libadwaita")]
 pub mod simple_adw_combo_row;
@@ -22,8 +22,7 @@ use factory::FileListItem;
 /// Creates a button with custom text that can be used to open a file chooser dialog. If a file is
 /// chosen, then it will be emitted as an output. The component can also optionally display a
 /// popover list of open files if [`OpenButtonSettings::recently_opened_files`] is set to a value.
-#[tracker::track]
-#[derive(Debug)]
+#[derive(Debug, relm4::Tracked)]
 pub struct OpenButton {
     #[do_not_track]
     config: OpenButtonSettings,
@@ -34,6 +33,7 @@ pub struct OpenButton {
     initialized: bool,
     #[do_not_track]
     reset_popover: bool,
+    tracker: u8,
 }
 
 #[derive(Debug)]
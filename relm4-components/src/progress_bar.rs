@@ -0,0 +1,86 @@
+//! A progress bar that displays the [`Progress`] reported by a [`ProgressSender`].
+
+use relm4::gtk::prelude::ProgressBarExt;
+use relm4::{gtk, ComponentParts, ComponentSender, Controller, Progress, ProgressSender};
+use relm4::{Component, ComponentController};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// A [`gtk::ProgressBar`] that shows the latest [`Progress`] it received.
+///
+/// The message of the progress is shown as text of the progress bar.
+/// If the total is unknown, the progress bar pulses instead.
+///
+/// ```ignore
+/// let progress_bar = SimpleProgressBar::builder().launch(()).detach();
+/// let progress = SimpleProgressBar::progress_sender(&progress_bar);
+///
+/// sender.command(move |out, shutdown| {
+///     shutdown
+///         .register(async move {
+///             for step in 0..10 {
+///                 progress.update(step, 10);
+///                 work(step).await;
+///             }
+///             out.emit(CmdOut::Finished);
+///         })
+///         .drop_on_shutdown()
+/// });
+/// ```
+pub struct SimpleProgressBar {
+    /// The latest progress.
+    pub progress: Progress,
+}
+
+impl SimpleProgressBar {
+    /// Creates a [`ProgressSender`] that updates the progress bar.
+    #[must_use]
+    pub fn progress_sender(controller: &Controller<Self>) -> ProgressSender {
+        ProgressSender::from(controller.sender().clone())
+    }
+
+    fn render(&self, progress_bar: &gtk::ProgressBar) {
+        match self.progress.fraction() {
+            Some(fraction) => progress_bar.set_fraction(fraction),
+            None => progress_bar.pulse(),
+        }
+
+        let message = self.progress.message.as_deref();
+        progress_bar.set_show_text(message.is_some());
+        progress_bar.set_text(message);
+    }
+}
+
+impl Component for SimpleProgressBar {
+    type CommandOutput = ();
+    type Input = Progress;
+    type Output = ();
+    type Init = ();
+    type Root = gtk::ProgressBar;
+    type Widgets = gtk::ProgressBar;
+
+    fn init_root() -> Self::Root {
+        gtk::ProgressBar::default()
+    }
+
+    fn init(
+        _init: Self::Init,
+        widgets: Self::Root,
+        _sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self::default();
+        model.render(&widgets);
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        progress: Self::Input,
+        _sender: ComponentSender<Self>,
+        _root: &Self::Root,
+    ) {
+        self.progress = progress;
+        self.render(widgets);
+    }
+}
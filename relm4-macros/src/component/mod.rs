@@ -31,6 +31,7 @@ pub(crate) fn generate_tokens(
         .map(|menus| menus.menus_stream());
 
     let mut struct_fields = None;
+    let mut group_structs = Vec::new();
 
     match &component_visitor.view_widgets {
         None => component_visitor.errors.push(syn::Error::new_spanned(
@@ -62,6 +63,7 @@ pub(crate) fn generate_tokens(
             init_root,
             rename_root,
             struct_fields: struct_fields_stream,
+            group_structs: group_structs_stream,
             init: init_widgets,
             assign,
             return_fields,
@@ -72,7 +74,8 @@ pub(crate) fn generate_tokens(
         let model_name = trait_impl_details.model_name;
 
         struct_fields = Some(struct_fields_stream);
-        let root_widget_type = view_widgets.root_type();
+        group_structs = group_structs_stream;
+        let root_widget_type = view_widgets.component_root_type();
 
         // Extract identifiers from additional fields for struct initialization: "test: u8" => "test"
         let additional_fields_return_stream = if let Some(fields) = &additional_fields {
@@ -113,6 +116,42 @@ pub(crate) fn generate_tokens(
         let init_root = util::verbatim_impl_item_fn("init_root", Vec::new(), ty, init_root);
         component_impl.items.push(init_root);
 
+        let has_post_init_widget = component_impl.items.iter().any(
+            |item| matches!(item, syn::ImplItem::Fn(func) if func.sig.ident == "post_init_widget"),
+        );
+        if view_widgets.has_single_root() && !has_post_init_widget {
+            // Roots that aren't widgets, like native dialogs, can't be mapped.
+            // The probe falls back to `None` for them through auto-ref.
+            component_impl.items.push(parse_quote! {
+                #[allow(clippy::needless_borrow, dead_code)]
+                fn post_init_widget(root: &Self::Root) -> Option<relm4::gtk::Widget> {
+                    struct Probe<'a, T>(&'a T);
+
+                    trait IsWidget {
+                        fn widget(&self) -> Option<relm4::gtk::Widget>;
+                    }
+
+                    impl<T: relm4::gtk::prelude::IsA<relm4::gtk::Widget>> IsWidget for Probe<'_, T> {
+                        fn widget(&self) -> Option<relm4::gtk::Widget> {
+                            Some(relm4::gtk::prelude::Cast::upcast_ref::<relm4::gtk::Widget>(self.0).clone())
+                        }
+                    }
+
+                    trait NotWidget {
+                        fn widget(&self) -> Option<relm4::gtk::Widget>;
+                    }
+
+                    impl<T> NotWidget for &Probe<'_, T> {
+                        fn widget(&self) -> Option<relm4::gtk::Widget> {
+                            None
+                        }
+                    }
+
+                    (&Probe(root)).widget()
+                }
+            });
+        }
+
         let PreAndPostView {
             pre_view,
             post_view,
@@ -180,6 +219,12 @@ pub(crate) fn generate_tokens(
     quote! {
         #widgets_struct
 
+        #(
+            #[allow(dead_code)]
+            #[derive(Debug)]
+            #group_structs
+        )*
+
         #component_impl
 
         #(#errors)*
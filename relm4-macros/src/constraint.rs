@@ -0,0 +1,251 @@
+use proc_macro2::{Span as Span2, TokenStream as TokenStream2};
+use quote::{quote, quote_spanned};
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{token, Error, Expr, ExprLit, ExprParen, ExprPath, Ident, Result, Token};
+
+/// A constraint like `button.start == label.end * 2.0 + 8.0, weak`.
+#[derive(Debug)]
+pub(super) struct Constraint {
+    target: Option<Ident>,
+    target_attr: Ident,
+    relation: Relation,
+    source: Option<ConstraintSource>,
+    constant: Option<Expr>,
+    strength: Option<Ident>,
+}
+
+#[derive(Debug)]
+struct ConstraintSource {
+    widget: Option<Ident>,
+    attr: Ident,
+    multiplier: Option<Expr>,
+}
+
+#[derive(Debug)]
+enum Relation {
+    Eq(Span2),
+    Le(Span2),
+    Ge(Span2),
+}
+
+const ATTRIBUTES: &[(&str, &str)] = &[
+    ("none", "None"),
+    ("left", "Left"),
+    ("right", "Right"),
+    ("top", "Top"),
+    ("bottom", "Bottom"),
+    ("start", "Start"),
+    ("end", "End"),
+    ("width", "Width"),
+    ("height", "Height"),
+    ("center_x", "CenterX"),
+    ("center_y", "CenterY"),
+    ("baseline", "Baseline"),
+];
+
+const STRENGTHS: &[(&str, &str)] = &[
+    ("required", "Required"),
+    ("strong", "Strong"),
+    ("medium", "Medium"),
+    ("weak", "Weak"),
+];
+
+fn variant(variants: &[(&str, &str)], ident: &Ident, kind: &str) -> Result<Ident> {
+    variants
+        .iter()
+        .find(|(name, _)| ident == name)
+        .map(|(_, variant)| Ident::new(variant, ident.span()))
+        .ok_or_else(|| {
+            let names: Vec<&str> = variants.iter().map(|(name, _)| *name).collect();
+            Error::new(
+                ident.span(),
+                format!(
+                    "Unknown {kind} `{ident}`. Expected one of: {}",
+                    names.join(", ")
+                ),
+            )
+        })
+}
+
+fn is_attribute(ident: &Ident) -> bool {
+    ATTRIBUTES.iter().any(|(name, _)| ident == name)
+}
+
+/// Parses `super` or the name of a widget.
+fn parse_widget(input: ParseStream<'_>) -> Result<Option<Ident>> {
+    if input.peek(Token![super]) {
+        let _super: Token![super] = input.parse()?;
+        Ok(None)
+    } else {
+        input.parse().map(Some)
+    }
+}
+
+/// Parses a literal, a variable or an expression in parentheses.
+fn parse_factor(input: ParseStream<'_>) -> Result<Expr> {
+    if input.peek(token::Paren) {
+        input.parse::<ExprParen>().map(Expr::Paren)
+    } else if input.peek(syn::Lit) {
+        input.parse::<ExprLit>().map(Expr::Lit)
+    } else {
+        input.parse::<ExprPath>().map(Expr::Path)
+    }
+}
+
+impl Parse for Relation {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(Token![==]) {
+            let token: Token![==] = input.parse()?;
+            Ok(Self::Eq(token.span()))
+        } else if lookahead.peek(Token![<=]) {
+            let token: Token![<=] = input.parse()?;
+            Ok(Self::Le(token.span()))
+        } else if lookahead.peek(Token![>=]) {
+            let token: Token![>=] = input.parse()?;
+            Ok(Self::Ge(token.span()))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl Parse for Constraint {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let target = parse_widget(input)?;
+        let _dot: Token![.] = input.parse()?;
+        let target_attr = input.parse()?;
+        let relation = input.parse()?;
+
+        // Constants with the name of an attribute can be put in parentheses.
+        let fork = input.fork();
+        let is_source = parse_widget(&fork).is_ok()
+            && fork.parse::<Token![.]>().is_ok()
+            && fork
+                .parse::<Ident>()
+                .is_ok_and(|ident| is_attribute(&ident));
+
+        let (source, constant) = if is_source {
+            let widget = parse_widget(input)?;
+            let _dot: Token![.] = input.parse()?;
+            let attr = input.parse()?;
+
+            let multiplier = if input.peek(Token![*]) {
+                let _star: Token![*] = input.parse()?;
+                Some(parse_factor(input)?)
+            } else {
+                None
+            };
+
+            let constant = if input.peek(Token![+]) {
+                let _plus: Token![+] = input.parse()?;
+                Some(input.parse()?)
+            } else if input.peek(Token![-]) {
+                let minus: Token![-] = input.parse()?;
+                let expr: Expr = input.parse()?;
+                Some(syn::parse_quote_spanned! { minus.span() => -(#expr) })
+            } else {
+                None
+            };
+
+            (
+                Some(ConstraintSource {
+                    widget,
+                    attr,
+                    multiplier,
+                }),
+                constant,
+            )
+        } else {
+            (None, Some(input.parse()?))
+        };
+
+        let strength = if input.peek(Token![,]) {
+            let _comma: Token![,] = input.parse()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        if !input.is_empty() {
+            return Err(input.error("Expected `,` followed by the strength of the constraint."));
+        }
+
+        Ok(Self {
+            target,
+            target_attr,
+            relation,
+            source,
+            constant,
+            strength,
+        })
+    }
+}
+
+fn target_stream(widget: Option<&Ident>) -> TokenStream2 {
+    let gtk_import = crate::gtk_import();
+    if let Some(widget) = widget {
+        quote_spanned! { widget.span() => ::std::option::Option::Some(&#widget) }
+    } else {
+        quote! { ::std::option::Option::None::<&#gtk_import::ConstraintTarget> }
+    }
+}
+
+impl Constraint {
+    pub(super) fn constraint_stream(&self) -> Result<TokenStream2> {
+        let gtk_import = crate::gtk_import();
+
+        let target = target_stream(self.target.as_ref());
+        let target_attr = variant(ATTRIBUTES, &self.target_attr, "attribute")?;
+        let relation = match self.relation {
+            Relation::Eq(span) => Ident::new("Eq", span),
+            Relation::Le(span) => Ident::new("Le", span),
+            Relation::Ge(span) => Ident::new("Ge", span),
+        };
+        let strength = if let Some(strength) = &self.strength {
+            variant(STRENGTHS, strength, "strength")?
+        } else {
+            Ident::new("Required", Span2::call_site())
+        };
+        let strength = quote! {
+            #gtk_import::glib::translate::IntoGlib::into_glib(#gtk_import::ConstraintStrength::#strength)
+        };
+        let constant = self
+            .constant
+            .as_ref()
+            .map_or_else(|| quote! { 0.0 }, |constant| quote! { #constant });
+
+        Ok(if let Some(source) = &self.source {
+            let source_widget = target_stream(source.widget.as_ref());
+            let source_attr = variant(ATTRIBUTES, &source.attr, "attribute")?;
+            let multiplier = source
+                .multiplier
+                .as_ref()
+                .map_or_else(|| quote! { 1.0 }, |multiplier| quote! { #multiplier });
+
+            quote_spanned! { self.target_attr.span() =>
+                #gtk_import::Constraint::new(
+                    #target,
+                    #gtk_import::ConstraintAttribute::#target_attr,
+                    #gtk_import::ConstraintRelation::#relation,
+                    #source_widget,
+                    #gtk_import::ConstraintAttribute::#source_attr,
+                    #multiplier,
+                    #constant,
+                    #strength,
+                )
+            }
+        } else {
+            quote_spanned! { self.target_attr.span() =>
+                #gtk_import::Constraint::new_constant(
+                    #target,
+                    #gtk_import::ConstraintAttribute::#target_attr,
+                    #gtk_import::ConstraintRelation::#relation,
+                    #constant,
+                    #strength,
+                )
+            }
+        })
+    }
+}
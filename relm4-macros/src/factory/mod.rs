@@ -27,6 +27,7 @@ pub(crate) fn generate_tokens(
     let menus_stream = factory_visitor.menus.take().map(|m| m.menus_stream());
 
     let mut struct_fields = None;
+    let mut group_structs = Vec::new();
 
     match &factory_visitor.view_widgets {
         None => factory_visitor.errors.push(syn::Error::new_spanned(
@@ -58,6 +59,7 @@ pub(crate) fn generate_tokens(
             init_root,
             rename_root,
             struct_fields: struct_fields_stream,
+            group_structs: group_structs_stream,
             init,
             assign,
             return_fields,
@@ -76,6 +78,7 @@ pub(crate) fn generate_tokens(
         );
 
         struct_fields = Some(struct_fields_stream);
+        group_structs = group_structs_stream;
 
         let root_widget_type = view_widgets.root_type();
 
@@ -204,6 +207,12 @@ pub(crate) fn generate_tokens(
     quote! {
         #widgets_struct
 
+        #(
+            #[allow(dead_code)]
+            #[derive(Debug)]
+            #group_structs
+        )*
+
         #factory_impl
 
         #(#errors)*
@@ -0,0 +1,75 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{DeriveInput, Error, Expr, Result};
+
+/// The direction in which the widgets fill the grid.
+enum Layout {
+    /// Fill the grid row by row, with the given number of columns.
+    Columns(Expr),
+    /// Fill the grid column by column, with the given number of rows.
+    Rows(Expr),
+}
+
+fn parse_layout(input: &DeriveInput) -> Result<Layout> {
+    let mut layout = None;
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("grid"))
+    {
+        attr.parse_nested_meta(|meta| {
+            let kind = if meta.path.is_ident("columns") {
+                Layout::Columns
+            } else if meta.path.is_ident("rows") {
+                Layout::Rows
+            } else {
+                return Err(meta.error("Expected `columns` or `rows`."));
+            };
+
+            if layout.is_some() {
+                return Err(meta.error("Only one of `columns` or `rows` can be set."));
+            }
+            layout = Some(kind(meta.value()?.parse()?));
+            Ok(())
+        })?;
+    }
+
+    layout.ok_or_else(|| {
+        Error::new(
+            input.span(),
+            "Expected an attribute like `#[grid(columns = 3)]` or `#[grid(rows = 3)]`.",
+        )
+    })
+}
+
+pub(super) fn generate_tokens(input: &DeriveInput) -> Result<TokenStream2> {
+    let layout = parse_layout(input)?;
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let position = match layout {
+        Layout::Columns(columns) => quote! {
+            relm4::factory::positions::GridPosition::row_major(index.current_index(), #columns)
+        },
+        Layout::Rows(rows) => quote! {
+            relm4::factory::positions::GridPosition::column_major(index.current_index(), #rows)
+        },
+    };
+
+    Ok(quote! {
+        impl #impl_generics relm4::factory::Position<
+            relm4::factory::positions::GridPosition,
+            relm4::factory::DynamicIndex,
+        > for #ident #ty_generics #where_clause {
+            fn position(
+                &self,
+                index: &relm4::factory::DynamicIndex,
+            ) -> relm4::factory::positions::GridPosition {
+                #position
+            }
+        }
+    })
+}
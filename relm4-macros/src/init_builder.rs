@@ -0,0 +1,125 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote, ToTokens};
+use syn::spanned::Spanned;
+use syn::{Attribute, Data, DeriveInput, Error, Expr, Fields, Result};
+
+/// Returns the expression of `#[init(default = expr)]` if the attribute is set.
+fn parse_default(attrs: &[Attribute]) -> Result<Option<Expr>> {
+    let mut default = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("init")) {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("default") {
+                return Err(meta.error("Expected `default`."));
+            }
+            if default.is_some() {
+                return Err(meta.error("`default` can only be set once."));
+            }
+            default = Some(meta.value()?.parse()?);
+            Ok(())
+        })?;
+    }
+
+    Ok(default)
+}
+
+pub(super) fn generate_tokens(input: &DeriveInput) -> Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.span(),
+            "`InitBuilder` can only be derived for structs.",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new(
+            data.fields.span(),
+            "`InitBuilder` can only be derived for structs with named fields.",
+        ));
+    };
+
+    let ident = &input.ident;
+    let vis = &input.vis;
+    let builder = format_ident!("{ident}Builder");
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut builder_fields = Vec::new();
+    let mut defaults = Vec::new();
+    let mut setters = Vec::new();
+    let mut build_fields = Vec::new();
+
+    for field in &fields.named {
+        let name = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+
+        let default = parse_default(&field.attrs)?.map_or_else(
+            || quote! { ::std::default::Default::default() },
+            |expr| expr.to_token_stream(),
+        );
+
+        // Use the docs of the field for the setter.
+        let docs: Vec<&Attribute> = field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"))
+            .collect();
+        let docs = if docs.is_empty() {
+            let doc = format!("Sets `{name}`.");
+            quote! { #[doc = #doc] }
+        } else {
+            quote! { #(#docs)* }
+        };
+
+        builder_fields.push(quote! { #name: #ty, });
+        defaults.push(quote! { #name: #default, });
+        setters.push(quote! {
+            #docs
+            #[must_use]
+            #vis fn #name(mut self, #name: impl ::std::convert::Into<#ty>) -> Self {
+                self.#name = #name.into();
+                self
+            }
+        });
+        build_fields.push(quote! { #name: self.#name, });
+    }
+
+    let builder_doc = format!("Builder for [`{ident}`].");
+    let build_doc = format!("Creates [`{ident}`] from the builder.");
+
+    Ok(quote! {
+        #[doc = #builder_doc]
+        #vis struct #builder #generics #where_clause {
+            #(#builder_fields)*
+        }
+
+        impl #impl_generics ::std::default::Default for #builder #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#defaults)*
+                }
+            }
+        }
+
+        impl #impl_generics #builder #ty_generics #where_clause {
+            #(#setters)*
+
+            #[doc = #build_doc]
+            #[must_use]
+            #vis fn build(self) -> #ident #ty_generics {
+                #ident {
+                    #(#build_fields)*
+                }
+            }
+        }
+
+        impl #impl_generics ::std::convert::From<#builder #ty_generics> for #ident #ty_generics #where_clause {
+            fn from(builder: #builder #ty_generics) -> Self {
+                builder.build()
+            }
+        }
+
+        impl #impl_generics relm4::component::InitBuilder for #ident #ty_generics #where_clause {
+            type Builder = #builder #ty_generics;
+        }
+    })
+}
@@ -34,7 +34,11 @@ mod additional_fields;
 mod args;
 mod attrs;
 mod component;
+mod constraint;
+mod grid_position;
+mod init_builder;
 mod menu;
+mod tracked;
 mod view;
 mod visitors;
 mod widgets;
@@ -139,6 +143,148 @@ fn gtk_import() -> syn::Path {
 /// }
 /// ```
 ///
+/// # Async signal handlers
+///
+/// Signal handlers can be async closures or async blocks.
+/// Each time the signal is emitted, the values passed in brackets are cloned
+/// and the handler is spawned on the main thread using `relm4::spawn_local`.
+/// The arguments of the signal can't be used inside the future because they're only borrowed.
+///
+/// ```ignore
+/// gtk::Button {
+///     set_label: "Load",
+///     connect_clicked[sender] => async move |_| {
+///         let data = load_data().await;
+///         sender.input(Msg::Loaded(data));
+///     },
+/// },
+/// gtk::Button {
+///     set_label: "Ping",
+///     connect_clicked[sender] => async move {
+///         ping().await;
+///         sender.input(Msg::Pong);
+///     },
+/// }
+/// ```
+///
+/// # Signal blocking
+///
+/// Properties with `#[watch]` or `#[track]` block all signal handlers of their widget
+/// while they are updated, so the update doesn't send an input message back to the component.
+/// Handlers of other widgets are not blocked.
+/// Use `#[block_signal(handler_id)]` to only block specific signal handlers instead,
+/// or `#[block_signal()]` to not block any handlers.
+/// `#[block_signal]` without arguments blocks all handlers of the widget for properties
+/// that aren't watched or tracked.
+///
+/// ```ignore
+/// gtk::Entry {
+///     #[watch]
+///     set_text: &model.text,
+///     // Not emitted when the text is updated with `set_text`.
+///     connect_changed[sender] => move |entry| {
+///         sender.input(Msg::SetText(entry.text().into()));
+///     },
+/// }
+/// ```
+///
+/// # Expressions
+///
+/// Properties with `#[expression]` are bound to a `gtk::Expression` once,
+/// instead of being assigned and updated in `update_view`.
+/// The value can be an expression or a model field that implements `relm4::binding::Binding`,
+/// in which case the property follows the primary property of the binding.
+/// The name of the GObject property is derived from the setter, so `set_label` binds `label`.
+/// Use `#[expression(object)]` to evaluate the expression with `object` as `this`.
+///
+/// ```ignore
+/// gtk::Label {
+///     // `model.title` is a `relm4::binding::StringBinding`
+///     #[expression]
+///     set_label: model.title,
+/// }
+/// ```
+///
+/// # UI files
+///
+/// Widgets can be loaded from GtkBuilder UI files with `#[builder = "path"]`.
+/// The file is included at compile time, using a path relative to the current file.
+/// The name of the widget must match the ID of the object in the UI file.
+/// Other objects of the UI file are available with `#[builder_child]`.
+/// They become fields of the widgets struct and support properties,
+/// signal handlers and `#[watch]` like regular widgets.
+/// The root widget of a component can't be loaded from a UI file.
+///
+/// ```ignore
+/// gtk::Window {
+///     #[builder = "header.ui"]
+///     header -> gtk::Box {
+///         #[builder_child]
+///         title -> gtk::Label {
+///             #[watch]
+///             set_label: &model.title,
+///         },
+///         #[builder_child]
+///         save_button -> gtk::Button {
+///             connect_clicked => Msg::Save,
+///         },
+///     }
+/// }
+/// ```
+///
+/// # Widget groups
+///
+/// Large components can group widgets with `#[group(name)]`.
+/// The widget and all of its named children are stored in a separate struct,
+/// which is a field of the widgets struct, for example `widgets.header.title`.
+/// The name of the struct is derived from the name of the group,
+/// so the group `header` generates a `HeaderWidgets` struct.
+/// Helper functions can then take a reference to a single group instead of all widgets.
+///
+/// ```ignore
+/// gtk::Window {
+///     #[group(header)]
+///     gtk::HeaderBar {
+///         #[wrap(Some)]
+///         #[name = "title"]
+///         set_title_widget = &gtk::Label {
+///             #[watch]
+///             set_label: &model.title,
+///         },
+///     },
+/// }
+///
+/// fn update_header(header: &HeaderWidgets) {
+///     header.title.set_label("Title");
+/// }
+/// ```
+///
+/// # Multiple root widgets
+///
+/// A component can have several top-level widgets with the `#[root]` attribute.
+/// Then, the `Root` type is a tuple of all root widgets in the order they are declared,
+/// so the parent can place each widget in a different container,
+/// for example the title in a header bar and the content in a view stack.
+///
+/// ```ignore
+/// view! {
+///     #[root]
+///     title = gtk::Label {
+///         #[watch]
+///         set_label: &model.title,
+///     },
+///     #[root]
+///     content = gtk::Box {
+///         // ...
+///     }
+/// }
+///
+/// // In the parent component
+/// let (title, content) = controller.widget();
+/// header_bar.set_title_widget(Some(title));
+/// stack.add_child(content);
+/// ```
+///
 /// # Notes on `pre_view`
 ///
 /// Using `return` in `pre_view` will cause a compiler warning.
@@ -315,6 +461,9 @@ pub fn factory(attributes: TokenStream, input: TokenStream) -> TokenStream {
 /// + `"Label text" => ActionType(value),` to create new entries with action value.
 /// + `custom => "widget_id",` add a placeholder for custom widgets you can add later with [`set_attribute_name`](https://gtk-rs.org/gtk-rs-core/stable/0.15/docs/gio/struct.MenuItem.html#method.set_attribute_value).
 /// + `section! { ... }` to create new sections.
+/// + `name = section! { ... }` to create new sections that can be accessed after the macro.
+/// + `for pattern in iterator { ... }` to create entries for each element of an iterator.
+/// + `existing_menu += { ... }` instead of `name: { ... }` to append entries to an existing menu.
 ///
 /// # Example
 ///
@@ -424,12 +573,206 @@ pub fn factory(attributes: TokenStream, input: TokenStream) -> TokenStream {
 /// );
 /// _section_1.append_item(&new_entry);
 /// ```
+///
+/// # Dynamic sections
+///
+/// Named sections and loops can be combined to rebuild a section later,
+/// for example after the list of recently opened files changed.
+///
+/// ```
+/// relm4::new_action_group!(WindowActionGroup, "win");
+/// relm4::new_stateful_action!(OpenRecentAction, WindowActionGroup, "open-recent", u32, u32);
+///
+/// let recent_files = vec![(0, "a.txt"), (1, "b.txt")];
+///
+/// relm4_macros::menu! {
+///     file_menu: {
+///         recent_section = section! {
+///             for (id, file) in &recent_files {
+///                 *file => OpenRecentAction(*id),
+///             }
+///         },
+///     }
+/// };
+///
+/// let recent_files = vec![(2, "c.txt")];
+///
+/// recent_section.remove_all();
+/// relm4_macros::menu! {
+///     recent_section += {
+///         for (id, file) in &recent_files {
+///             *file => OpenRecentAction(*id),
+///         }
+///     }
+/// };
+/// ```
 #[proc_macro]
 pub fn menu(input: TokenStream) -> TokenStream {
     let menus = parse_macro_input!(input as Menus);
     menus.menus_stream().into()
 }
 
+/// A macro to create a `gtk::Constraint` for a `gtk::ConstraintLayout`.
+///
+/// A constraint describes a linear relation between attributes of two widgets,
+/// `target.attribute <relation> source.attribute * multiplier + constant`,
+/// where `<relation>` is `==`, `<=` or `>=`.
+/// Use `super` instead of the name of a widget to refer to the widget that uses the layout.
+/// The source can also be omitted to compare the target attribute with a constant.
+///
+/// Multipliers are literals, variables or expressions in parentheses.
+/// Constants that look like the attribute of a widget must be put in parentheses.
+/// An optional strength of `required` (the default), `strong`, `medium` or `weak` can follow after a comma.
+///
+/// The generated constraint can be added with `relm4::RelmConstraintExt::add_constraint`,
+/// which makes it easy to declare constraints between sibling widgets in the view macro.
+/// Add the constraints after the children, so the widgets are already part of the layout.
+/// Custom layout managers can be set with `set_layout_manager` like any other property.
+///
+/// ```ignore
+/// gtk::Box {
+///     set_layout_manager: Some(gtk::ConstraintLayout::new()),
+///
+///     #[name = "label"]
+///     gtk::Label {},
+///     #[name = "entry"]
+///     gtk::Entry {},
+///
+///     add_constraint: constraint!(label.start == super.start),
+///     add_constraint: constraint!(entry.start == label.end + 12.0),
+/// }
+/// ```
+///
+/// ```
+/// # use relm4::gtk;
+/// # gtk::init().unwrap();
+/// let label = gtk::Label::default();
+/// let button = gtk::Button::default();
+/// let spacing = 8.0;
+///
+/// let _ = relm4_macros::constraint!(label.start == super.start + spacing);
+/// let _ = relm4_macros::constraint!(button.start == label.end * 1.0 + 12.0, strong);
+/// let _ = relm4_macros::constraint!(button.width >= 100.0, weak);
+/// ```
+#[proc_macro]
+pub fn constraint(input: TokenStream) -> TokenStream {
+    let constraint = parse_macro_input!(input as constraint::Constraint);
+    constraint
+        .constraint_stream()
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro that places factory components in a `gtk::Grid` based on their index.
+///
+/// The macro implements `relm4::factory::Position<GridPosition, DynamicIndex>`, so
+/// it can be used by factories with a `ParentWidget` of `gtk::Grid` and an `Index` of `DynamicIndex`.
+/// Every widget takes exactly one cell of the grid.
+///
+/// Use `#[grid(columns = 3)]` to fill the grid row by row or
+/// `#[grid(rows = 3)]` to fill the grid column by column.
+/// The number can be any expression of type `usize`, for example a constant.
+///
+/// ```
+/// #[derive(relm4_macros::GridPosition)]
+/// #[grid(columns = 3)]
+/// struct Cell {
+///     value: u8,
+/// }
+/// ```
+#[proc_macro_derive(GridPosition, attributes(grid))]
+pub fn derive_grid_position(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    grid_position::generate_tokens(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro that generates a builder for the `Init` type of a component.
+///
+/// The builder is named after the struct, so `AppInit` generates `AppInitBuilder`.
+/// It has a setter for each field that accepts anything that implements `Into` for
+/// the type of the field. Fields that aren't set use `Default::default()` or
+/// the expression of `#[init(default = expr)]`.
+///
+/// The macro also implements `relm4::component::InitBuilder`, so the component can be launched
+/// with `ComponentBuilder::launch_with` to only set the parameters that differ from the defaults.
+///
+/// ```
+/// #[derive(relm4_macros::InitBuilder)]
+/// struct AppInit {
+///     /// The title of the window.
+///     title: String,
+///     #[init(default = 400)]
+///     width: i32,
+///     icon: Option<String>,
+/// }
+///
+/// let init = AppInitBuilder::default().title("Relm4").build();
+/// assert_eq!(init.title, "Relm4");
+/// assert_eq!(init.width, 400);
+/// assert_eq!(init.icon, None);
+/// ```
+#[proc_macro_derive(InitBuilder, attributes(init))]
+pub fn derive_init_builder(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    init_builder::generate_tokens(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro that tracks changes to the fields of a struct.
+///
+/// The struct needs a field named `tracker` of an unsigned integer type
+/// that stores one bit for each tracked field, for example `tracker: u8`
+/// for up to 8 fields. It should be initialized with `0`.
+///
+/// For each tracked field, the macro generates the methods `get_field`, `get_mut_field`,
+/// `set_field` and `update_field` as well as the associated function `field()` that returns the
+/// bit of the field. The setters only mark the field as changed if the new value is different,
+/// which requires `PartialEq`.
+/// Use `#[no_eq]` to always mark the field as changed and
+/// `#[do_not_track]` to skip the field entirely.
+///
+/// `changed` checks for changes, which works well with the `#[track]` attribute
+/// of the [`view!`] macro. Call `reset` at the beginning of `update` to clear the changes
+/// of the previous update.
+///
+/// ```
+/// #[derive(relm4_macros::Tracked)]
+/// struct App {
+///     value: u8,
+///     #[no_eq]
+///     name: String,
+///     #[do_not_track]
+///     cache: Vec<u8>,
+///     tracker: u8,
+/// }
+///
+/// let mut app = App {
+///     value: 0,
+///     name: String::new(),
+///     cache: Vec::new(),
+///     tracker: 0,
+/// };
+///
+/// app.set_value(0);
+/// assert!(!app.changed(App::value()));
+///
+/// app.update_name(|name| name.push('a'));
+/// assert!(app.changed(App::name()));
+///
+/// app.reset();
+/// assert!(!app.changed(App::track_all()));
+/// ```
+#[proc_macro_derive(Tracked, attributes(do_not_track, no_eq))]
+pub fn derive_tracked(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    tracked::generate_tokens(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 /// The [`view!`] macro allows you to construct your UI easily and cleanly.
 ///
 /// It does the same as inside the [`macro@component`] attribute macro,
@@ -628,6 +971,121 @@ pub fn view(input: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
+///
+/// Templates can have generic parameters and receive arguments for initialization.
+/// Multiple arguments are passed to `WidgetTemplate::init` as tuple,
+/// so the template below can be used as `LabeledRow::<gtk::Switch>("Enabled", 10)`.
+///
+/// ```
+/// # use relm4::prelude::*;
+/// # use gtk::prelude::*;
+/// #
+/// #[relm4::widget_template]
+/// impl<T: IsA<gtk::Widget> + Default> WidgetTemplate for LabeledRow<T> {
+///     type Init = (&'static str, i32);
+///
+///     view! {
+///         gtk::Box {
+///             set_spacing: init.1,
+///
+///             gtk::Label {
+///                 set_label: init.0,
+///             },
+///
+///             #[name = "content"]
+///             T {}
+///         }
+///     }
+/// }
+/// ```
+///
+/// Widgets added to a `#[template_child]` extend the children defined by the template.
+/// With `#[template_child(replace)]`, the children defined by the template are removed first,
+/// which allows using template children as slots for custom content.
+///
+/// Templates can extend other templates by using a template as root widget.
+/// The new template keeps all children of the base template, which can be modified
+/// with `#[template_child]`, and shares its root widget.
+/// Children of the base template are accessible through the field of the root widget.
+///
+/// ```
+/// # use relm4::prelude::*;
+/// # use gtk::prelude::*;
+/// #
+/// #[relm4::widget_template]
+/// impl WidgetTemplate for BasePage {
+///     view! {
+///         gtk::Box {
+///             set_orientation: gtk::Orientation::Vertical,
+///
+///             #[name = "title"]
+///             gtk::Label {
+///                 set_label: "Untitled",
+///             },
+///         }
+///     }
+/// }
+///
+/// #[relm4::widget_template]
+/// impl WidgetTemplate for SettingsPage {
+///     view! {
+///         // Accessible as `settings_page.base.title`.
+///         #[name = "base"]
+///         #[template]
+///         BasePage {
+///             #[template_child]
+///             title {
+///                 set_label: "Settings",
+///             },
+///
+///             #[name = "dark_mode"]
+///             gtk::Switch {},
+///         }
+///     }
+/// }
+/// ```
+///
+/// Templates can declare slots with `#[slot]` on a named widget.
+/// Users fill a slot by referring to it with `#[slot]`,
+/// which replaces the default children of the slot with their own children.
+/// Slots can be accessed with a method like `content_slot()`
+/// and only declared slots can be filled.
+///
+/// ```
+/// # use relm4::prelude::*;
+/// # use gtk::prelude::*;
+/// #
+/// #[relm4::widget_template]
+/// impl WidgetTemplate for Card {
+///     view! {
+///         gtk::Frame {
+///             #[wrap(Some)]
+///             #[slot]
+///             #[name = "content"]
+///             set_child = &gtk::Box {
+///                 gtk::Label {
+///                     set_label: "Empty",
+///                 },
+///             },
+///         }
+///     }
+/// }
+///
+/// fn build_card() -> Card {
+///     relm4::view! {
+///         #[template]
+///         card = Card {
+///             #[slot]
+///             content {
+///                 gtk::Button {
+///                     set_label: "Click me",
+///                 },
+///             },
+///         }
+///     }
+///     card
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn widget_template(attributes: TokenStream, input: TokenStream) -> TokenStream {
     let SyncOnlyAttrs { visibility } = parse_macro_input!(attributes);
@@ -2,7 +2,7 @@ use proc_macro2::{Span as Span2, TokenStream as TokenStream2};
 use quote::{quote, quote_spanned};
 use syn::{spanned::Spanned, Ident, LitStr};
 
-use super::{Menu, MenuElement, MenuEntry, MenuItem, MenuSection, Menus, SubMenu};
+use super::{Menu, MenuElement, MenuEntry, MenuForLoop, MenuItem, MenuSection, Menus, SubMenu};
 
 impl Menus {
     pub(crate) fn menus_stream(&self) -> TokenStream2 {
@@ -21,10 +21,17 @@ impl Menu {
         let name = &self.name;
         let gtk_import = crate::gtk_import();
 
-        // Create new menu
-        let mut menu_stream = quote_spanned! {
-            name.span() =>
-                let #name = #gtk_import ::gio::Menu::new();
+        let mut menu_stream = if let Some(existing) = &self.existing {
+            // Use existing menu
+            quote! {
+                let #name: &#gtk_import::gio::Menu = &#existing;
+            }
+        } else {
+            // Create new menu
+            quote_spanned! {
+                name.span() =>
+                    let #name = #gtk_import ::gio::Menu::new();
+            }
         };
 
         // Add items
@@ -32,7 +39,16 @@ impl Menu {
             menu_stream.extend(item.item_stream(name));
         }
 
-        menu_stream
+        if self.existing.is_some() {
+            // Wrap the generated code in a new scope to avoid side-effects
+            quote! {
+                {
+                    #menu_stream
+                }
+            }
+        } else {
+            menu_stream
+        }
     }
 }
 
@@ -44,6 +60,7 @@ impl MenuElement {
             Self::Item(entry) => entry.item_stream(parent_ident),
             Self::Section(section) => section.section_stream(parent_ident),
             Self::Custom(id) => custom_stream(parent_ident, id),
+            Self::ForLoop(for_loop) => for_loop.for_loop_stream(parent_ident),
         });
 
         item_stream
@@ -134,3 +151,26 @@ impl MenuSection {
         section_stream
     }
 }
+
+impl MenuForLoop {
+    fn for_loop_stream(&self, parent_ident: &Ident) -> TokenStream2 {
+        let Self {
+            for_token,
+            pattern,
+            in_token,
+            expr,
+            ..
+        } = self;
+
+        let mut items_stream = TokenStream2::new();
+        for item in &self.items {
+            items_stream.extend(item.item_stream(parent_ident));
+        }
+
+        quote! {
+            #for_token #pattern #in_token #expr {
+                #items_stream
+            }
+        }
+    }
+}
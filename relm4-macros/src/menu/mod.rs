@@ -1,6 +1,7 @@
+use proc_macro2::TokenStream as TokenStream2;
 use syn::punctuated::Punctuated;
-use syn::token::Comma;
-use syn::{Expr, Ident, LitStr, Path};
+use syn::token::{Comma, For, In};
+use syn::{Expr, Ident, LitStr, Pat, Path};
 
 mod gen;
 mod parse;
@@ -13,6 +14,8 @@ pub(crate) struct Menus {
 #[derive(Debug)]
 struct Menu {
     name: Ident,
+    /// An existing menu the items are appended to.
+    existing: Option<TokenStream2>,
     items: Punctuated<MenuElement, Comma>,
 }
 
@@ -21,6 +24,7 @@ enum MenuElement {
     Item(Box<MenuItem>),
     Custom(LitStr),
     Section(MenuSection),
+    ForLoop(Box<MenuForLoop>),
 }
 
 #[derive(Debug)]
@@ -47,3 +51,13 @@ struct MenuSection {
     name: Ident,
     items: Punctuated<MenuElement, Comma>,
 }
+
+/// Items that are created for each element of an iterator.
+#[derive(Debug)]
+struct MenuForLoop {
+    for_token: For,
+    pattern: Pat,
+    in_token: In,
+    expr: Expr,
+    items: Punctuated<MenuElement, Comma>,
+}
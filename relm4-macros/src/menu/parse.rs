@@ -1,11 +1,13 @@
 use proc_macro2::Span as Span2;
+use quote::ToTokens;
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
-use syn::{braced, parenthesized, token, Ident, Path, Result, Token};
+use syn::{braced, parenthesized, token, Expr, Ident, Pat, Path, Result, Token};
 
 use crate::menu::SubMenu;
 
-use super::{Menu, MenuElement, MenuEntry, MenuItem, MenuSection, Menus};
+use super::{Menu, MenuElement, MenuEntry, MenuForLoop, MenuItem, MenuSection, Menus};
 
 syn::custom_keyword!(custom);
 
@@ -19,15 +21,38 @@ impl Parse for Menus {
 
 impl Parse for Menu {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
-        let name = input.parse()?;
-        let _colon: Token![:] = input.parse()?;
+        let first = input.call(Ident::parse_any)?;
+
+        // `menu_name: { ... }` creates a new menu, while
+        // `existing.menu += { ... }` appends the items to an existing menu.
+        let (name, existing) = if input.peek(Token![:]) {
+            let _colon: Token![:] = input.parse()?;
+            (first, None)
+        } else {
+            let mut existing = first.into_token_stream();
+            while input.peek(Token![.]) {
+                let dot: Token![.] = input.parse()?;
+                let member = input.call(Ident::parse_any)?;
+                dot.to_tokens(&mut existing);
+                member.to_tokens(&mut existing);
+            }
+            let _add_assign: Token![+=] = input.parse()?;
+            (
+                Ident::new("_existing_menu", Span2::mixed_site()),
+                Some(existing),
+            )
+        };
 
         let braced_input;
         braced!(braced_input in input);
 
         let items = braced_input.call(Punctuated::parse_terminated)?;
 
-        Ok(Menu { name, items })
+        Ok(Menu {
+            name,
+            existing,
+            items,
+        })
     }
 }
 
@@ -69,7 +94,13 @@ impl Parse for MenuElement {
             let _custom: custom = input.parse()?;
             let _colon: Token![:] = input.parse()?;
             input.parse().map(MenuElement::Custom)?
-        } else if input.peek2(Token![!]) {
+        } else if input.peek(Token![for]) {
+            input
+                .parse()
+                .map(|for_loop| MenuElement::ForLoop(Box::new(for_loop)))?
+        } else if input.peek2(Token![!])
+            || (input.peek(Ident) && input.peek2(Token![=]) && !input.peek2(Token![=>]))
+        {
             input.parse().map(MenuElement::Section)?
         } else {
             input.parse().map(MenuElement::Item)?
@@ -79,20 +110,53 @@ impl Parse for MenuElement {
 
 impl Parse for MenuSection {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
-        let name: Ident = input.parse()?;
-        assert!(name == "section");
+        // Named sections can be accessed after the macro, for example to rebuild them.
+        let name = if input.peek2(Token![=]) {
+            let name = input.parse()?;
+            let _eq: Token![=] = input.parse()?;
+            Some(name)
+        } else {
+            None
+        };
+
+        let section: Ident = input.parse()?;
+        if section != "section" {
+            return Err(syn::Error::new(section.span(), "Expected `section!`."));
+        }
         let _excl: Token![!] = input.parse()?;
 
         let braced_input;
         braced!(braced_input in input);
 
         let items = braced_input.call(Punctuated::parse_terminated)?;
-        let name = section_name();
+        let name = name.unwrap_or_else(section_name);
 
         Ok(MenuSection { name, items })
     }
 }
 
+impl Parse for MenuForLoop {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let for_token = input.parse()?;
+        let pattern = Pat::parse_multi_with_leading_vert(input)?;
+        let in_token = input.parse()?;
+        let expr = Expr::parse_without_eager_brace(input)?;
+
+        let braced_input;
+        braced!(braced_input in input);
+
+        let items = braced_input.call(Punctuated::parse_terminated)?;
+
+        Ok(MenuForLoop {
+            for_token,
+            pattern,
+            in_token,
+            expr,
+            items,
+        })
+    }
+}
+
 fn section_name() -> Ident {
     use std::sync::atomic::{AtomicU8, Ordering};
     static COUNTER: AtomicU8 = AtomicU8::new(0);
@@ -1,6 +1,6 @@
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{quote, ToTokens};
-use syn::{Error, Ident, Visibility};
+use syn::{Error, Ident, Index, Visibility};
 
 use crate::widgets::{TopLevelWidget, ViewWidgets, Widget};
 
@@ -14,6 +14,10 @@ pub(super) struct TokenStreams {
     pub(super) rename_root: TokenStream2,
     /// The tokens for the struct fields -> name: Type,
     pub(super) struct_fields: TokenStream2,
+    /// The structs of widget groups, without derives.
+    pub(super) group_structs: Vec<TokenStream2>,
+    /// The methods that return the slots of a template.
+    pub(super) slots: TokenStream2,
     /// The tokens initializing the widgets.
     pub(super) init: TokenStream2,
     /// The tokens initializing the properties.
@@ -44,26 +48,81 @@ impl ViewWidgets {
         standalone_view: bool,
     ) -> TokenStreams {
         let mut streams = TokenStreams::default();
+        let multiple_roots = self.root_widgets().count() > 1;
+        let mut root_inits = Vec::new();
 
         for top_level_widget in &self.top_level_widgets {
-            top_level_widget.generate_streams(&mut streams, trait_impl_details, standalone_view);
+            // Multiple root widgets are stored in a tuple.
+            let root_index = (multiple_roots && top_level_widget.root_attr.is_some())
+                .then(|| Index::from(root_inits.len()));
+
+            top_level_widget.generate_streams(
+                &mut streams,
+                trait_impl_details,
+                standalone_view,
+                root_index.as_ref(),
+            );
+
+            if root_index.is_some() {
+                root_inits.push(std::mem::take(&mut streams.init_root));
+            }
+        }
+
+        if !root_inits.is_empty() {
+            streams.init_root = quote! { ( #({ #root_inits },)* ) };
         }
 
         streams
     }
 
-    /// Get the root widget
-    pub(super) fn get_root_widget(&self) -> syn::Result<&Widget> {
+    /// Iterate over all widgets with the `#[root]` attribute.
+    fn root_widgets(&self) -> impl Iterator<Item = &TopLevelWidget> {
         self.top_level_widgets
             .iter()
-            .find(|w| w.root_attr.is_some())
-            .map(|w| &w.inner)
-            .ok_or_else(|| {
-                Error::new(
-                    self.span,
-                    "You need to specify the root widget using the `#[root]` attribute.",
-                )
-            })
+            .filter(|w| w.root_attr.is_some())
+    }
+
+    /// Get the root widget
+    pub(super) fn get_root_widget(&self) -> syn::Result<&Widget> {
+        let mut root_widgets = self.root_widgets();
+        let root_widget = root_widgets.next().ok_or_else(|| {
+            Error::new(
+                self.span,
+                "You need to specify the root widget using the `#[root]` attribute.",
+            )
+        })?;
+
+        if let Some(TopLevelWidget {
+            root_attr: Some(root_attr),
+            ..
+        }) = root_widgets.next()
+        {
+            Err(Error::new(
+                root_attr.span(),
+                "Only components can have multiple root widgets.",
+            ))
+        } else {
+            Ok(&root_widget.inner)
+        }
+    }
+
+    /// Whether the component has exactly one root widget
+    pub(super) fn has_single_root(&self) -> bool {
+        self.root_widgets().count() == 1
+    }
+
+    /// Generate root type for `Root` parameter in `Component` impl
+    ///
+    /// Multiple root widgets are combined into a tuple.
+    pub(super) fn component_root_type(&self) -> TokenStream2 {
+        if self.root_widgets().count() > 1 {
+            let types = self
+                .root_widgets()
+                .map(|root_widget| root_widget.inner.func_type_token_stream());
+            quote! { ( #(#types,)* ) }
+        } else {
+            self.root_type()
+        }
     }
 
     /// Generate root type for `Root` parameter in `Component` impl
@@ -89,10 +148,15 @@ impl TopLevelWidget {
         streams: &mut TokenStreams,
         trait_impl_details: &TraitImplDetails,
         standalone_view: bool,
+        root_index: Option<&Index>,
     ) {
         let generate_init_root_stream = !standalone_view && self.root_attr.is_some();
-        self.inner
-            .init_token_generation(streams, trait_impl_details, generate_init_root_stream);
+        self.inner.init_token_generation(
+            streams,
+            trait_impl_details,
+            generate_init_root_stream,
+            root_index,
+        );
     }
 }
 
@@ -102,6 +166,7 @@ impl Widget {
         streams: &mut TokenStreams,
         trait_impl_details: &TraitImplDetails,
         generate_root_init_stream: bool,
+        root_index: Option<&Index>,
     ) {
         let TraitImplDetails {
             vis,
@@ -129,7 +194,8 @@ impl Widget {
         self.error_stream(&mut streams.error);
         self.start_assign_stream(&mut streams.assign, sender_name);
         self.init_conditional_init_stream(&mut streams.assign, model_name);
-        self.struct_fields_stream(&mut streams.struct_fields, vis);
+        self.struct_fields_stream(&mut streams.struct_fields, &mut streams.group_structs, vis);
+        self.slots_stream(&mut streams.slots, &quote! { self }, vis);
         self.return_stream(&mut streams.return_fields);
         self.destructure_stream(&mut streams.destructure_fields);
         self.init_update_view_stream(&mut streams.update_view, model_name);
@@ -138,8 +204,13 @@ impl Widget {
         if generate_root_init_stream {
             let mut_token = self.mutable.as_ref();
             if let Some(root_name) = root_name {
+                let root = if let Some(index) = root_index {
+                    quote! { #root_name.#index }
+                } else {
+                    root_name.to_token_stream()
+                };
                 streams.rename_root.extend(quote! {
-                    let #mut_token #name = #root_name.clone();
+                    let #mut_token #name = #root.clone();
                 });
             }
         }
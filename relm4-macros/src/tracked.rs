@@ -0,0 +1,187 @@
+use proc_macro2::{Literal, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Error, Fields, Ident, Result, Type};
+
+/// The name of the field that stores the change flags.
+const TRACKER_FIELD: &str = "tracker";
+
+/// A field whose changes are tracked.
+struct TrackedField<'a> {
+    ident: &'a Ident,
+    ty: &'a Type,
+    /// Only mark the field as changed if the new value is different.
+    compare: bool,
+}
+
+/// Returns the number of bits of primitive integer types.
+fn bit_width(ty: &Type) -> Option<usize> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let ident = path.path.get_ident()?;
+    let bits = match ident.to_string().as_str() {
+        "u8" => 8,
+        "u16" => 16,
+        "u32" => 32,
+        "u64" => 64,
+        "u128" => 128,
+        _ => return None,
+    };
+    Some(bits)
+}
+
+pub(super) fn generate_tokens(input: &DeriveInput) -> Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.span(),
+            "`Tracked` can only be derived for structs.",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new(
+            data.fields.span(),
+            "`Tracked` can only be derived for structs with named fields.",
+        ));
+    };
+
+    let mut tracker_ty = None;
+    let mut tracked_fields = Vec::new();
+
+    for field in &fields.named {
+        let ident = field.ident.as_ref().unwrap();
+        if ident == TRACKER_FIELD {
+            tracker_ty = Some(&field.ty);
+            continue;
+        }
+
+        let mut track = true;
+        let mut compare = true;
+        for attr in &field.attrs {
+            if attr.path().is_ident("do_not_track") {
+                attr.meta.require_path_only()?;
+                track = false;
+            } else if attr.path().is_ident("no_eq") {
+                attr.meta.require_path_only()?;
+                compare = false;
+            }
+        }
+
+        if track {
+            tracked_fields.push(TrackedField {
+                ident,
+                ty: &field.ty,
+                compare,
+            });
+        }
+    }
+
+    let tracker_ty = tracker_ty.ok_or_else(|| {
+        Error::new(
+            fields.span(),
+            "Expected a field like `tracker: u8` that stores the changes.",
+        )
+    })?;
+
+    if let Some(bits) = bit_width(tracker_ty) {
+        if tracked_fields.len() > bits {
+            return Err(Error::new(
+                tracker_ty.span(),
+                format!(
+                    "The tracker field can only track {bits} fields, but {} fields are tracked. \
+                    Use a larger integer type.",
+                    tracked_fields.len()
+                ),
+            ));
+        }
+    }
+
+    let ident = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let methods = tracked_fields.iter().enumerate().map(|(index, field)| {
+        let TrackedField { ident, ty, compare } = field;
+        let get = format_ident!("get_{ident}");
+        let get_mut = format_ident!("get_mut_{ident}");
+        let set = format_ident!("set_{ident}");
+        let update = format_ident!("update_{ident}");
+        let index = Literal::usize_unsuffixed(index);
+
+        let set_body = if *compare {
+            quote! {
+                if self.#ident != value {
+                    self.#ident = value;
+                    self.tracker |= Self::#ident();
+                }
+            }
+        } else {
+            quote! {
+                self.#ident = value;
+                self.tracker |= Self::#ident();
+            }
+        };
+
+        quote! {
+            #[allow(dead_code, missing_docs)]
+            #vis fn #get(&self) -> &#ty {
+                &self.#ident
+            }
+
+            #[allow(dead_code, missing_docs)]
+            #vis fn #get_mut(&mut self) -> &mut #ty {
+                self.tracker |= Self::#ident();
+                &mut self.#ident
+            }
+
+            #[allow(dead_code, missing_docs)]
+            #vis fn #set(&mut self, value: #ty) {
+                #set_body
+            }
+
+            #[allow(dead_code, missing_docs)]
+            #vis fn #update<F: FnOnce(&mut #ty)>(&mut self, f: F) {
+                self.tracker |= Self::#ident();
+                f(&mut self.#ident);
+            }
+
+            #[allow(dead_code, missing_docs)]
+            #[must_use]
+            #vis fn #ident() -> #tracker_ty {
+                1 << #index
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #(#methods)*
+
+            /// Returns a value that matches the changes of all fields.
+            #[allow(dead_code)]
+            #[must_use]
+            #vis fn track_all() -> #tracker_ty {
+                !0
+            }
+
+            /// Returns `true` if any of the fields in `mask` changed.
+            #[allow(dead_code)]
+            #[must_use]
+            #vis fn changed(&self, mask: #tracker_ty) -> bool {
+                self.tracker & mask != 0
+            }
+
+            /// Marks all fields as changed.
+            #[allow(dead_code)]
+            #vis fn mark_all_changed(&mut self) {
+                self.tracker = Self::track_all();
+            }
+
+            /// Marks all fields as unchanged.
+            #[allow(dead_code)]
+            #vis fn reset(&mut self) {
+                self.tracker = 0;
+            }
+        }
+    })
+}
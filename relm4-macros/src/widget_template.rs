@@ -1,6 +1,6 @@
 use proc_macro2::{Span as Span2, TokenStream as TokenStream2};
 use quote::quote;
-use syn::{spanned::Spanned, Error, Ident, ImplItem, ItemImpl, Visibility};
+use syn::{spanned::Spanned, Error, GenericParam, Ident, ImplItem, ItemImpl, Type, Visibility};
 
 use crate::{
     token_streams::{TokenStreams, TraitImplDetails},
@@ -86,6 +86,8 @@ fn try_generate_tokens(
             init,
             assign,
             struct_fields,
+            group_structs,
+            slots,
             return_fields,
             ..
         } = view_widgets.generate_streams(
@@ -106,47 +108,124 @@ fn try_generate_tokens(
             }
         };
 
-        let root_widget_type = view_widgets.root_type();
+        let root_name = view_widgets.root_name();
+
+        // Templates that extend another template share its root widget.
+        let (root_widget_type, root_ref) = if view_widgets.root_is_template() {
+            let base_type = view_widgets.root_type();
+            (
+                quote! { <#base_type as relm4::WidgetTemplate>::Root },
+                quote! { ::std::ops::Deref::deref(&self.#root_name) },
+            )
+        } else {
+            (view_widgets.root_type(), quote! { &self.#root_name })
+        };
         item_impl.items.push(ImplItem::Verbatim(quote! {
             type Root = #root_widget_type;
         }));
 
-        let root_name = view_widgets.root_name();
+        let (marker_field, marker_return) = marker_tokens(&item_impl);
 
         item_impl.items.push(ImplItem::Verbatim(quote! {
             fn init(init: Self::Init) -> Self {
                 #view_output
                 Self {
                     #return_fields
+                    #marker_return
                 }
             }
         }));
 
         let type_name = &item_impl.self_ty;
+        let struct_name = struct_name(type_name)?;
+        let (impl_generics, _, where_clause) = item_impl.generics.split_for_impl();
+
+        let slots_impl = (!slots.is_empty()).then(|| {
+            quote! {
+                impl #impl_generics #type_name #where_clause {
+                    #slots
+                }
+            }
+        });
 
         Ok(quote! {
             #[derive(Debug, Clone)]
-            #vis struct #type_name {
+            #vis struct #struct_name #impl_generics #where_clause {
                 #struct_fields
+                #marker_field
             }
 
-            impl ::std::convert::AsRef<#root_widget_type> for #type_name {
+            #(
+                #[derive(Debug, Clone)]
+                #group_structs
+            )*
+
+            impl #impl_generics ::std::convert::AsRef<#root_widget_type> for #type_name #where_clause {
                 fn as_ref(&self) -> &#root_widget_type {
-                    &self.#root_name
+                    #root_ref
                 }
             }
 
-            impl ::std::ops::Deref for #type_name {
+            impl #impl_generics ::std::ops::Deref for #type_name #where_clause {
                 type Target = #root_widget_type;
 
                 fn deref(&self) -> &Self::Target {
-                    &self.#root_name
+                    #root_ref
                 }
             }
 
+            #slots_impl
+
             #item_impl
         })
     } else {
         Err(Error::new(item_impl.span(), "Expected a view macro"))
     }
 }
+
+/// Returns the name of the struct that is generated for the template.
+fn struct_name(self_ty: &Type) -> syn::Result<&Ident> {
+    if let Type::Path(path) = self_ty {
+        if path.qself.is_none() {
+            if let Some(segment) = path.path.segments.last() {
+                return Ok(&segment.ident);
+            }
+        }
+    }
+    Err(Error::new(
+        self_ty.span(),
+        "Expected the name of the template",
+    ))
+}
+
+/// Generic type parameters and lifetimes that aren't used by any
+/// template child must still be used by the struct of the template.
+fn marker_tokens(item_impl: &ItemImpl) -> (Option<TokenStream2>, Option<TokenStream2>) {
+    let mut params = Vec::new();
+    for param in &item_impl.generics.params {
+        match param {
+            GenericParam::Type(ty) => {
+                let ident = &ty.ident;
+                params.push(quote! { #ident });
+            }
+            GenericParam::Lifetime(lifetime) => {
+                let lifetime = &lifetime.lifetime;
+                params.push(quote! { &#lifetime () });
+            }
+            GenericParam::Const(_) => (),
+        }
+    }
+
+    if params.is_empty() {
+        (None, None)
+    } else {
+        (
+            Some(quote! {
+                _marker: ::std::marker::PhantomData<fn() -> (#(#params,)*)>,
+            }),
+            Some(quote! {
+                _marker: ::std::marker::PhantomData,
+            }),
+        )
+    }
+}
@@ -1,9 +1,9 @@
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{quote, quote_spanned, ToTokens};
 use syn::spanned::Spanned;
-use syn::Expr;
+use syn::{Expr, Ident};
 
-use crate::widgets::{AssignProperty, AssignPropertyAttr, PropertyName};
+use crate::widgets::{AssignProperty, AssignPropertyAttr, Bind, ExpressionBind, PropertyName};
 
 use super::AssignInfo;
 
@@ -13,7 +13,20 @@ impl AssignProperty {
         info: &mut AssignInfo<'_>,
         p_name: &PropertyName,
         init: bool,
+        sender_name: &Ident,
     ) {
+        // Connect the handler of bound properties before the first assignment,
+        // so the assignments can block it.
+        if let Some(bind) = &self.bind {
+            bind.connect_stream(info, p_name, sender_name);
+        }
+
+        // Expressions are bound once and update the property by themselves.
+        if let Some(expression) = &self.expression {
+            expression.bind_stream(info, p_name, &self.expr);
+            return;
+        }
+
         // If the code gen path is behind a conditional widgets, handle `watch` and `track` later.
         // Normally, those would be initialized right away, but they might need access to
         // variables from a pattern, for example `Some(variable)` so they are moved inside the
@@ -61,15 +74,25 @@ impl AssignProperty {
             }
         });
 
-        let (block_stream, unblock_stream) = if init || self.block_signals.is_empty() {
+        let mut block_signals: Vec<&Ident> = if init {
+            Vec::new()
+        } else {
+            self.block_signals.iter().collect()
+        };
+        // Changes caused by the assignment itself shouldn't be sent back to the model.
+        if let Some(bind) = &self.bind {
+            block_signals.push(&bind.handler_id);
+        }
+
+        let (block_stream, unblock_stream) = if block_signals.is_empty() {
             (None, None)
         } else {
             let mut block_stream = TokenStream2::default();
             let mut unblock_stream = TokenStream2::default();
             let gtk_import = crate::gtk_import();
 
-            let w_name = info.widget_name;
-            for signal_handler in &self.block_signals {
+            let w_name = info.widget_stream();
+            for signal_handler in block_signals {
                 block_stream.extend(quote_spanned! {
                     signal_handler.span() =>
                         {
@@ -132,3 +155,75 @@ impl AssignProperty {
             });
     }
 }
+
+impl Bind {
+    fn connect_stream(
+        &self,
+        info: &mut AssignInfo<'_>,
+        p_name: &PropertyName,
+        sender_name: &Ident,
+    ) {
+        let Self {
+            message,
+            handler_id,
+        } = self;
+
+        let property = match p_name.bound_property_name() {
+            Ok(property) => property,
+            Err(err) => {
+                info.stream.extend(err.into_compile_error());
+                return;
+            }
+        };
+
+        let widget = info.widget_stream();
+        let gtk_import = crate::gtk_import();
+
+        info.stream.extend(quote_spanned! {
+            message.span() =>
+                let #handler_id = {
+                    use #gtk_import::prelude::ObjectExt as _;
+
+                    #[allow(clippy::redundant_clone)]
+                    let sender = #sender_name.clone();
+                    #widget.connect_notify_local(Some(#property), move |widget, _| {
+                        sender.input(#message(widget.property(#property)));
+                    })
+                };
+        });
+    }
+}
+
+impl ExpressionBind {
+    fn bind_stream(&self, info: &mut AssignInfo<'_>, p_name: &PropertyName, expr: &Expr) {
+        let property = match p_name.bound_property_name() {
+            Ok(property) => property,
+            Err(err) => {
+                info.stream.extend(err.into_compile_error());
+                return;
+            }
+        };
+
+        let widget = info.widget_stream();
+        let gtk_import = crate::gtk_import();
+        let this = if let Some(this) = &self.this {
+            quote! { Some(&#this) }
+        } else {
+            quote! { None::<&#gtk_import::glib::Object> }
+        };
+
+        info.stream.extend(quote_spanned! {
+            expr.span() =>
+                {
+                    use #gtk_import::prelude::*;
+
+                    let expression = relm4::binding::ToExpression::to_expression(&#expr);
+                    expression.bind(
+                        #widget.upcast_ref::<#gtk_import::glib::Object>(),
+                        #property,
+                        #this,
+                    );
+                }
+        });
+    }
+}
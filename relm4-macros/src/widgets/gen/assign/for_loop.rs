@@ -0,0 +1,83 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{punctuated::Punctuated, token, Ident};
+
+use crate::widgets::{ForLoop, PropertyName};
+
+use super::AssignInfo;
+
+impl ForLoop {
+    pub(super) fn assign_stream(
+        &self,
+        info: &mut AssignInfo<'_>,
+        p_name: &PropertyName,
+        sender_name: &Ident,
+    ) {
+        // Remember the preceding widget, so rebuilt widgets can be moved back after it.
+        if self.watch {
+            let anchor = &self.anchor;
+            let container = Self::container(info.widget_name, info.template_path.as_ref());
+            info.stream.extend(quote! {
+                #anchor = relm4::gtk::prelude::WidgetExt::last_child(&#container);
+            });
+        }
+
+        let loop_stream = self.loop_stream(
+            info.widget_name,
+            info.template_path.clone(),
+            p_name,
+            sender_name,
+        );
+        info.stream.extend(loop_stream);
+    }
+
+    /// Returns the container the widgets of the loop are added to.
+    pub(crate) fn container(
+        widget_name: &Ident,
+        template_path: Option<&Punctuated<Ident, token::Dot>>,
+    ) -> TokenStream2 {
+        if let Some(template_path) = template_path {
+            quote! { #template_path }
+        } else {
+            quote! { #widget_name }
+        }
+    }
+
+    /// Generates a loop that creates a widget for each element,
+    /// adds it to the container and stores it in the vector of the loop.
+    pub(crate) fn loop_stream(
+        &self,
+        widget_name: &Ident,
+        template_path: Option<Punctuated<Ident, token::Dot>>,
+        p_name: &PropertyName,
+        sender_name: &Ident,
+    ) -> TokenStream2 {
+        let Self {
+            name,
+            for_token,
+            pattern,
+            in_token,
+            expr,
+            widget,
+        } = self;
+
+        let mut body = TokenStream2::new();
+        widget.init_stream(&mut body);
+
+        let mut info = AssignInfo {
+            stream: &mut body,
+            widget_name,
+            template_path,
+            is_conditional: false,
+        };
+        widget.assign_stream(&mut info, p_name, sender_name);
+
+        let child_name = &widget.name;
+        quote! {
+            #for_token #pattern #in_token #expr {
+                #body
+                #name.push(#child_name);
+            }
+        }
+    }
+}
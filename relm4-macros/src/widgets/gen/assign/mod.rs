@@ -1,10 +1,12 @@
 use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
 use syn::{punctuated::Punctuated, token, Ident};
 
 use crate::widgets::{Property, PropertyType};
 
 mod assign_property;
 mod conditional_widget;
+mod for_loop;
 mod properties;
 mod signal_handler;
 mod widgets;
@@ -16,11 +18,23 @@ pub(crate) struct AssignInfo<'a> {
     pub(crate) is_conditional: bool,
 }
 
+impl AssignInfo<'_> {
+    /// Returns the tokens to access the widget, which might be a template child.
+    pub(crate) fn widget_stream(&self) -> TokenStream2 {
+        if let Some(template_path) = &self.template_path {
+            quote! { #template_path }
+        } else {
+            let widget_name = self.widget_name;
+            quote! { #widget_name }
+        }
+    }
+}
+
 impl Property {
     fn assign_stream<'a>(&'a self, info: &mut AssignInfo<'a>, sender_name: &'a Ident) {
         match &self.ty {
             PropertyType::Assign(assign) => {
-                assign.conditional_assign_stream(info, &self.name, true);
+                assign.conditional_assign_stream(info, &self.name, true, sender_name);
             }
             PropertyType::Widget(widget) => {
                 widget.assign_stream(info, &self.name, sender_name);
@@ -28,6 +42,9 @@ impl Property {
             PropertyType::ConditionalWidget(cond_widget) => {
                 cond_widget.assign_stream(info, &self.name, sender_name);
             }
+            PropertyType::ForLoop(for_loop) => {
+                for_loop.assign_stream(info, &self.name, sender_name);
+            }
             PropertyType::SignalHandler(signal_handler) => {
                 signal_handler.connect_signals_stream(info, &self.name, sender_name);
             }
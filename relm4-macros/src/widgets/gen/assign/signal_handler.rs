@@ -1,7 +1,7 @@
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{quote_spanned, ToTokens};
-use syn::Expr;
 use syn::{spanned::Spanned, Ident};
+use syn::{Expr, ExprClosure};
 
 use crate::widgets::{PropertyName, SignalHandler, SignalHandlerVariant};
 
@@ -32,6 +32,7 @@ impl SignalHandler {
             ),
             SignalHandlerVariant::Closure(inner) => {
                 let mut clone_stream = TokenStream2::new();
+                let mut cloned_idents = Vec::new();
                 if let Some(args) = &inner.args {
                     for arg in &args.inner {
                         if let Expr::Path(path) = arg {
@@ -42,6 +43,7 @@ impl SignalHandler {
                                     #[allow(clippy::clone_on_copy)]
                                     let #ident = #ident.clone();
                                 });
+                                cloned_idents.push(ident);
                                 continue;
                             }
                         }
@@ -51,9 +53,20 @@ impl SignalHandler {
                             #[allow(clippy::clone_on_copy)]
                             let #arg;
                         });
+                        if let Expr::Assign(assign) = arg {
+                            if let Expr::Path(path) = &*assign.left {
+                                cloned_idents.extend(path.path.get_ident());
+                            }
+                        }
                     }
                 }
-                (clone_stream, inner.closure.to_token_stream())
+
+                let assignment = if inner.closure.asyncness.is_some() {
+                    async_closure_stream(&inner.closure, &cloned_idents)
+                } else {
+                    inner.closure.to_token_stream()
+                };
+                (clone_stream, assignment)
             }
         };
 
@@ -75,3 +88,30 @@ impl SignalHandler {
             });
     }
 }
+
+/// Turns an async closure into a regular closure that spawns
+/// the body of the async closure on the main thread.
+///
+/// The values passed to the closure are cloned again for each future.
+fn async_closure_stream(closure: &ExprClosure, cloned_idents: &[&Ident]) -> TokenStream2 {
+    let ExprClosure {
+        attrs,
+        inputs,
+        body,
+        ..
+    } = closure;
+
+    quote_spanned! { closure.span() =>
+        #(#attrs)*
+        move |#inputs| {
+            #(
+                #[allow(clippy::redundant_clone)]
+                #[allow(clippy::clone_on_copy)]
+                let #cloned_idents = #cloned_idents.clone();
+            )*
+            relm4::spawn_local(async move {
+                #body
+            });
+        }
+    }
+}
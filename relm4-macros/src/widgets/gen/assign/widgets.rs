@@ -1,9 +1,9 @@
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{quote, quote_spanned};
+use quote::{format_ident, quote, quote_spanned};
 use syn::spanned::Spanned;
 use syn::Ident;
 
-use crate::widgets::{PropertyName, ReturnedWidget, Widget, WidgetTemplateAttr};
+use crate::widgets::{PropertyName, ReturnedWidget, Widget, WidgetAttr, WidgetTemplateAttr};
 
 use super::AssignInfo;
 
@@ -47,9 +47,31 @@ impl Widget {
     ) {
         // Recursively generate code for properties
         {
-            let template_path = (self.template_attr == WidgetTemplateAttr::TemplateChild)
+            let template_path = self
+                .template_attr
+                .is_template_child()
                 .then_some(self.func.widget_template_path(info.widget_name, &self.name));
 
+            // Remove the children of the template child so they can be replaced.
+            if let (WidgetTemplateAttr::TemplateChild { replace: true }, Some(template_path)) =
+                (&self.template_attr, &template_path)
+            {
+                info.stream.extend(quote_spanned! {
+                    self.name.span() => relm4::RelmRemoveAllExt::remove_all(&#template_path);
+                });
+            }
+
+            // Slots are accessed through their method, which fails to compile
+            // if the template doesn't declare the slot.
+            if self.template_attr == WidgetTemplateAttr::Slot {
+                let template_name = info.widget_name;
+                let slot_fn = format_ident!("{}_slot", self.name);
+                info.stream.extend(quote_spanned! {
+                    self.name.span() =>
+                        relm4::RelmRemoveAllExt::remove_all(#template_name.#slot_fn());
+                });
+            }
+
             let mut info = AssignInfo {
                 stream: info.stream,
                 widget_name: &self.name,
@@ -59,8 +81,8 @@ impl Widget {
             self.properties.assign_stream(&mut info, sender_name);
         }
 
-        // Template children are already assigned by the template.
-        if self.template_attr != WidgetTemplateAttr::TemplateChild {
+        // Template children and objects of UI files are already assigned by their parent.
+        if !self.template_attr.is_template_child() && self.attr != WidgetAttr::BuilderChild {
             let assign_fn = p_name.assign_fn_stream(info);
             let self_assign_args = p_name.assign_args_stream(info.widget_name);
             let assign = self.widget_assignment();
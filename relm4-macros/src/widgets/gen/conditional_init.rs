@@ -9,6 +9,7 @@ use crate::widgets::{
 };
 
 use super::assign::AssignInfo;
+use super::util::if_branch::if_branches_stream;
 
 impl Property {
     fn conditional_init_stream(
@@ -32,7 +33,10 @@ impl Property {
             PropertyType::ConditionalWidget(cond_widget) => {
                 cond_widget.conditional_init_stream(stream, model_name);
             }
-            PropertyType::SignalHandler(_) | PropertyType::ParseError(_) => (),
+            // Loops initialize their widgets completely in the assign stream.
+            PropertyType::SignalHandler(_)
+            | PropertyType::ForLoop(_)
+            | PropertyType::ParseError(_) => (),
         }
     }
 }
@@ -79,18 +83,19 @@ impl ConditionalWidget {
     fn conditional_init_stream(&self, stream: &mut TokenStream2, model_name: &Ident) {
         let brach_stream = match &self.branches {
             ConditionalBranches::If(if_branches) => {
-                let mut stream = TokenStream2::new();
-
-                for (index, branch) in if_branches.iter().enumerate() {
-                    let mut inner_update_stream = TokenStream2::new();
-                    branch.widget.conditional_init_stream(
-                        &mut inner_update_stream,
-                        model_name,
-                        true,
-                    );
-                    branch.update_stream(&mut stream, &inner_update_stream, index);
-                }
-                stream
+                let inner_update_streams: Vec<TokenStream2> = if_branches
+                    .iter()
+                    .map(|branch| {
+                        let mut inner_update_stream = TokenStream2::new();
+                        branch.widget.conditional_init_stream(
+                            &mut inner_update_stream,
+                            model_name,
+                            true,
+                        );
+                        inner_update_stream
+                    })
+                    .collect();
+                if_branches_stream(if_branches, &inner_update_streams)
             }
             ConditionalBranches::Match((match_token, expr, match_arms)) => {
                 let mut inner_tokens = TokenStream2::new();
@@ -2,8 +2,8 @@ use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 
 use crate::widgets::{
-    ConditionalBranches, ConditionalWidget, Properties, Property, PropertyType, ReturnedWidget,
-    SignalHandler, Widget,
+    parse_util, AssignProperty, ConditionalBranches, ConditionalWidget, ForLoop, Properties,
+    Property, PropertyType, ReturnedWidget, SignalHandler, Widget,
 };
 
 impl Property {
@@ -16,7 +16,9 @@ impl Property {
             PropertyType::ConditionalWidget(cond_widget) => {
                 cond_widget.destructure_stream(stream);
             }
-            PropertyType::Assign(_) | PropertyType::ParseError(_) => (),
+            PropertyType::ForLoop(for_loop) => for_loop.destructure_stream(stream),
+            PropertyType::Assign(assign) => assign.destructure_stream(stream),
+            PropertyType::ParseError(_) => (),
         }
     }
 }
@@ -31,6 +33,17 @@ impl Properties {
 
 impl Widget {
     pub(crate) fn destructure_stream(&self, stream: &mut TokenStream2) {
+        if let Some(group) = &self.group {
+            let struct_name = parse_util::group_struct_name(group);
+            let mut fields = TokenStream2::new();
+            self.ungrouped_destructure_stream(&mut fields);
+            stream.extend(quote! { #group: #struct_name { #fields .. }, });
+        } else {
+            self.ungrouped_destructure_stream(stream);
+        }
+    }
+
+    fn ungrouped_destructure_stream(&self, stream: &mut TokenStream2) {
         if self.has_struct_field() {
             let name = &self.name;
 
@@ -62,6 +75,18 @@ impl ConditionalWidget {
     }
 }
 
+impl ForLoop {
+    fn destructure_stream(&self, stream: &mut TokenStream2) {
+        let name = &self.name;
+
+        stream.extend(quote! { #name, });
+        if self.watch {
+            let anchor = &self.anchor;
+            stream.extend(quote! { #anchor, });
+        }
+    }
+}
+
 impl ReturnedWidget {
     pub(super) fn destructure_stream(&self, stream: &mut TokenStream2) {
         if self.ty.is_some() {
@@ -75,6 +100,17 @@ impl ReturnedWidget {
     }
 }
 
+impl AssignProperty {
+    pub(super) fn destructure_stream(&self, stream: &mut TokenStream2) {
+        if let Some(bind) = &self.bind {
+            let handler_id = &bind.handler_id;
+            stream.extend(quote! {
+                #handler_id,
+            });
+        }
+    }
+}
+
 impl SignalHandler {
     pub(super) fn destructure_stream(&self, stream: &mut TokenStream2) {
         if let Some(signal_handler_id) = &self.handler_id {
@@ -14,6 +14,7 @@ impl Property {
             PropertyType::SignalHandler(_) | PropertyType::Assign(_) => (),
             PropertyType::Widget(widget) => widget.error_stream(stream),
             PropertyType::ConditionalWidget(cond_widget) => cond_widget.error_stream(stream),
+            PropertyType::ForLoop(for_loop) => for_loop.widget.error_stream(stream),
         }
     }
 }
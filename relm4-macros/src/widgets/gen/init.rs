@@ -1,9 +1,10 @@
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{quote, quote_spanned};
+use syn::{Error, LitStr};
 
 use crate::widgets::{
-    ConditionalBranches, ConditionalWidget, Properties, Property, PropertyType, Widget, WidgetAttr,
-    WidgetTemplateAttr,
+    parse_util, ConditionalBranches, ConditionalWidget, ForLoop, Properties, Property,
+    PropertyType, Widget, WidgetAttr, WidgetTemplateAttr,
 };
 
 impl Property {
@@ -15,6 +16,7 @@ impl Property {
             PropertyType::ConditionalWidget(cond_widget) => {
                 cond_widget.init_stream(stream);
             }
+            PropertyType::ForLoop(for_loop) => for_loop.init_stream(stream),
             _ => (),
         }
     }
@@ -39,11 +41,21 @@ impl Widget {
         init_root_stream: &mut TokenStream2,
         init_stream: &mut TokenStream2,
     ) {
+        if let WidgetAttr::Builder(file) = &self.attr {
+            init_root_stream.extend(
+                Error::new(
+                    file.span(),
+                    "The root widget can't be loaded from a UI file. Load a child of the root widget instead.",
+                )
+                .into_compile_error(),
+            );
+        }
+
         // Init function as return value
         init_root_stream.extend(match self.template_attr {
-            WidgetTemplateAttr::None | WidgetTemplateAttr::TemplateChild => {
-                self.func.func_token_stream()
-            }
+            WidgetTemplateAttr::None
+            | WidgetTemplateAttr::TemplateChild { .. }
+            | WidgetTemplateAttr::Slot => self.func.func_token_stream(),
             WidgetTemplateAttr::Template => self.func.widget_template_init(),
         });
 
@@ -55,7 +67,9 @@ impl Widget {
         let name = &self.name;
 
         let ty = self.func.ty.as_ref().map(|ty| quote! {: #ty});
-        if self.attr == WidgetAttr::None {
+        if let WidgetAttr::Builder(file) = &self.attr {
+            self.builder_init_stream(stream, file);
+        } else if self.attr == WidgetAttr::None {
             match self.template_attr {
                 WidgetTemplateAttr::None => {
                     let func = self.func.func_token_stream();
@@ -70,7 +84,7 @@ impl Widget {
                     });
                 }
                 // Template children are already initialized by their template.
-                WidgetTemplateAttr::TemplateChild => (),
+                WidgetTemplateAttr::TemplateChild { .. } | WidgetTemplateAttr::Slot => (),
             }
         }
     }
@@ -78,6 +92,52 @@ impl Widget {
     fn other_init_stream(&self, stream: &mut TokenStream2) {
         self.properties.init_stream(stream);
     }
+
+    /// Loads the UI file and gets the widget and all
+    /// objects with the `builder_child` attribute from it.
+    fn builder_init_stream(&self, stream: &mut TokenStream2, file: &LitStr) {
+        let gtk_import = crate::gtk_import();
+        let builder = parse_util::unique_ident_from_parts(["builder"]);
+
+        stream.extend(quote_spanned! {
+            file.span() =>
+                let #builder = #gtk_import::Builder::from_string(::std::include_str!(#file));
+        });
+
+        let mut objects = vec![self];
+        self.properties.builder_children(&mut objects);
+
+        for object in objects {
+            let mutability = &object.mutable;
+            let name = &object.name;
+            let ty = object.func_type_token_stream();
+            let id = name.to_string();
+            let error_msg = format!("Couldn't find object `{id}` in `{}`", file.value());
+
+            stream.extend(quote_spanned! {
+                name.span() =>
+                    let #mutability #name: #ty = #builder.object(#id).expect(#error_msg);
+            });
+        }
+    }
+}
+
+impl Properties {
+    /// Collects the widgets with the `builder_child` attribute
+    /// that belong to the UI file of the parent widget.
+    fn builder_children<'a>(&'a self, objects: &mut Vec<&'a Widget>) {
+        for prop in &self.properties {
+            if let PropertyType::Widget(widget) = &prop.ty {
+                match widget.attr {
+                    WidgetAttr::BuilderChild => objects.push(widget),
+                    // Children of other UI files are loaded by their parent.
+                    WidgetAttr::Builder(_) => continue,
+                    _ => (),
+                }
+                widget.properties.builder_children(objects);
+            }
+        }
+    }
 }
 
 impl ConditionalWidget {
@@ -111,3 +171,25 @@ impl ConditionalWidget {
         }
     }
 }
+
+impl ForLoop {
+    fn init_stream(&self, stream: &mut TokenStream2) {
+        let name = &self.name;
+        let ty = self.widget.func_type_token_stream();
+
+        // The widgets are initialized by the loop in the assign stream.
+        stream.extend(quote_spanned! {
+            name.span() =>
+                let mut #name: Vec<#ty> = Vec::new();
+        });
+
+        if self.watch {
+            let anchor = &self.anchor;
+            stream.extend(quote_spanned! {
+                name.span() =>
+                    #[allow(unused_assignments)]
+                    let mut #anchor: Option<relm4::gtk::Widget> = None;
+            });
+        }
+    }
+}
@@ -18,6 +18,7 @@ mod conditional_init;
 mod destructure_fields;
 mod error;
 mod init;
+mod slots;
 mod update_view;
 
 impl Widget {
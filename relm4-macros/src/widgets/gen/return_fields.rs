@@ -2,8 +2,8 @@ use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 
 use crate::widgets::{
-    ConditionalBranches, ConditionalWidget, Properties, Property, PropertyType, ReturnedWidget,
-    SignalHandler, Widget, WidgetAttr,
+    parse_util, ConditionalBranches, ConditionalWidget, ForLoop, Properties, Property,
+    PropertyType, ReturnedWidget, SignalHandler, Widget, WidgetAttr,
 };
 
 impl Property {
@@ -12,7 +12,9 @@ impl Property {
             PropertyType::Widget(widget) => widget.return_stream(stream),
             PropertyType::SignalHandler(signal_handler) => signal_handler.return_stream(stream),
             PropertyType::ConditionalWidget(cond_widget) => cond_widget.return_stream(stream),
-            PropertyType::Assign(_) | PropertyType::ParseError(_) => (),
+            PropertyType::ForLoop(for_loop) => for_loop.return_stream(stream),
+            PropertyType::Assign(assign) => assign.destructure_stream(stream),
+            PropertyType::ParseError(_) => (),
         }
     }
 }
@@ -27,6 +29,17 @@ impl Properties {
 
 impl Widget {
     pub(crate) fn return_stream(&self, stream: &mut TokenStream2) {
+        if let Some(group) = &self.group {
+            let struct_name = parse_util::group_struct_name(group);
+            let mut fields = TokenStream2::new();
+            self.ungrouped_return_stream(&mut fields);
+            stream.extend(quote! { #group: #struct_name { #fields }, });
+        } else {
+            self.ungrouped_return_stream(stream);
+        }
+    }
+
+    fn ungrouped_return_stream(&self, stream: &mut TokenStream2) {
         if self.has_struct_field() {
             let name = &self.name;
 
@@ -66,6 +79,12 @@ impl ConditionalWidget {
     }
 }
 
+impl ForLoop {
+    fn return_stream(&self, stream: &mut TokenStream2) {
+        self.destructure_stream(stream);
+    }
+}
+
 impl ReturnedWidget {
     fn return_stream(&self, stream: &mut TokenStream2) {
         self.destructure_stream(stream);
@@ -0,0 +1,86 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote, quote_spanned};
+use syn::Visibility;
+
+use crate::widgets::{ConditionalBranches, ConditionalWidget, Properties, PropertyType, Widget};
+
+impl Properties {
+    fn slots_stream(
+        &self,
+        stream: &mut TokenStream2,
+        field: &TokenStream2,
+        vis: &Option<Visibility>,
+    ) {
+        for prop in &self.properties {
+            match &prop.ty {
+                PropertyType::Widget(widget) => widget.slots_stream(stream, field, vis),
+                PropertyType::ConditionalWidget(cond_widget) => {
+                    cond_widget.slots_stream(stream, field, vis);
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+impl Widget {
+    /// Generates methods that return the slots of a template.
+    ///
+    /// `field` is the path to the struct that stores the widget,
+    /// which differs from `self` for widget groups.
+    pub(crate) fn slots_stream(
+        &self,
+        stream: &mut TokenStream2,
+        field: &TokenStream2,
+        vis: &Option<Visibility>,
+    ) {
+        let field = if let Some(group) = &self.group {
+            quote! { #field.#group }
+        } else {
+            field.clone()
+        };
+
+        if self.slot {
+            let name = &self.name;
+            let ty = self.func_type_token_stream();
+            let slot_fn = format_ident!("{name}_slot");
+            let doc = format!("Returns the `{name}` slot of the template.");
+
+            stream.extend(quote_spanned! {
+                name.span() =>
+                    #[doc = #doc]
+                    #[allow(dead_code)]
+                    #vis fn #slot_fn(&self) -> &#ty {
+                        &#field.#name
+                    }
+            });
+        }
+
+        self.properties.slots_stream(stream, &field, vis);
+        if let Some(returned_widget) = &self.returned_widget {
+            returned_widget.properties.slots_stream(stream, &field, vis);
+        }
+    }
+}
+
+impl ConditionalWidget {
+    fn slots_stream(
+        &self,
+        stream: &mut TokenStream2,
+        field: &TokenStream2,
+        vis: &Option<Visibility>,
+    ) {
+        match &self.branches {
+            ConditionalBranches::If(if_branches) => {
+                for branch in if_branches {
+                    branch.widget.slots_stream(stream, field, vis);
+                }
+            }
+            ConditionalBranches::Match((_, _, match_arms)) => {
+                for arm in match_arms {
+                    arm.widget.slots_stream(stream, field, vis);
+                }
+            }
+        }
+    }
+}
@@ -4,34 +4,78 @@ use syn::Visibility;
 
 use super::{ReturnedWidget, Widget};
 use crate::widgets::{
-    ConditionalBranches, ConditionalWidget, Properties, Property, PropertyType, SignalHandler,
+    parse_util, AssignProperty, ConditionalBranches, ConditionalWidget, ForLoop, Properties,
+    Property, PropertyType, SignalHandler,
 };
 
 impl Property {
-    fn struct_fields_stream(&self, stream: &mut TokenStream2, vis: &Option<Visibility>) {
+    fn struct_fields_stream(
+        &self,
+        stream: &mut TokenStream2,
+        structs: &mut Vec<TokenStream2>,
+        vis: &Option<Visibility>,
+    ) {
         match &self.ty {
-            PropertyType::Widget(widget) => widget.struct_fields_stream(stream, vis),
+            PropertyType::Widget(widget) => widget.struct_fields_stream(stream, structs, vis),
             PropertyType::SignalHandler(signal_handler) => {
                 signal_handler.struct_fields_stream(stream, vis);
             }
             PropertyType::ConditionalWidget(cond_widget) => {
-                cond_widget.struct_fields_stream(stream, vis);
+                cond_widget.struct_fields_stream(stream, structs, vis);
             }
-            PropertyType::Assign(_) | PropertyType::ParseError(_) => (),
+            PropertyType::ForLoop(for_loop) => for_loop.struct_fields_stream(stream, vis),
+            PropertyType::Assign(assign) => assign.struct_fields_stream(stream, vis),
+            PropertyType::ParseError(_) => (),
         }
     }
 }
 
 impl Properties {
-    fn struct_fields_stream(&self, stream: &mut TokenStream2, vis: &Option<Visibility>) {
+    fn struct_fields_stream(
+        &self,
+        stream: &mut TokenStream2,
+        structs: &mut Vec<TokenStream2>,
+        vis: &Option<Visibility>,
+    ) {
         for prop in &self.properties {
-            prop.struct_fields_stream(stream, vis);
+            prop.struct_fields_stream(stream, structs, vis);
         }
     }
 }
 
 impl Widget {
-    pub(crate) fn struct_fields_stream(&self, stream: &mut TokenStream2, vis: &Option<Visibility>) {
+    pub(crate) fn struct_fields_stream(
+        &self,
+        stream: &mut TokenStream2,
+        structs: &mut Vec<TokenStream2>,
+        vis: &Option<Visibility>,
+    ) {
+        if let Some(group) = &self.group {
+            let struct_name = parse_util::group_struct_name(group);
+            let mut fields = TokenStream2::new();
+            self.ungrouped_struct_fields_stream(&mut fields, structs, vis);
+
+            structs.push(quote! {
+                #[allow(missing_docs)]
+                #vis struct #struct_name {
+                    #fields
+                }
+            });
+            stream.extend(quote! {
+                #[allow(missing_docs)]
+                #vis #group: #struct_name,
+            });
+        } else {
+            self.ungrouped_struct_fields_stream(stream, structs, vis);
+        }
+    }
+
+    fn ungrouped_struct_fields_stream(
+        &self,
+        stream: &mut TokenStream2,
+        structs: &mut Vec<TokenStream2>,
+        vis: &Option<Visibility>,
+    ) {
         if self.has_struct_field() {
             let name = &self.name;
             let ty = self.func_type_token_stream();
@@ -49,15 +93,20 @@ impl Widget {
             });
         }
 
-        self.properties.struct_fields_stream(stream, vis);
+        self.properties.struct_fields_stream(stream, structs, vis);
         if let Some(returned_widget) = &self.returned_widget {
-            returned_widget.struct_fields_stream(stream, vis);
+            returned_widget.struct_fields_stream(stream, structs, vis);
         }
     }
 }
 
 impl ConditionalWidget {
-    fn struct_fields_stream(&self, stream: &mut TokenStream2, vis: &Option<Visibility>) {
+    fn struct_fields_stream(
+        &self,
+        stream: &mut TokenStream2,
+        structs: &mut Vec<TokenStream2>,
+        vis: &Option<Visibility>,
+    ) {
         let name = &self.name;
         let gtk_import = crate::gtk_import();
 
@@ -78,20 +127,47 @@ impl ConditionalWidget {
         match &self.branches {
             ConditionalBranches::If(if_branches) => {
                 for branch in if_branches {
-                    branch.widget.struct_fields_stream(stream, vis);
+                    branch.widget.struct_fields_stream(stream, structs, vis);
                 }
             }
             ConditionalBranches::Match((_, _, match_arms)) => {
                 for arm in match_arms {
-                    arm.widget.struct_fields_stream(stream, vis);
+                    arm.widget.struct_fields_stream(stream, structs, vis);
                 }
             }
         }
     }
 }
 
-impl ReturnedWidget {
+impl ForLoop {
     fn struct_fields_stream(&self, stream: &mut TokenStream2, vis: &Option<Visibility>) {
+        let name = &self.name;
+        let ty = self.widget.func_type_token_stream();
+
+        stream.extend(quote_spanned! {
+            name.span() =>
+                #[allow(missing_docs)]
+                #vis #name: Vec<#ty>,
+        });
+
+        if self.watch {
+            let anchor = &self.anchor;
+            stream.extend(quote_spanned! {
+                name.span() =>
+                    #[allow(missing_docs)]
+                    #vis #anchor: Option<relm4::gtk::Widget>,
+            });
+        }
+    }
+}
+
+impl ReturnedWidget {
+    fn struct_fields_stream(
+        &self,
+        stream: &mut TokenStream2,
+        structs: &mut Vec<TokenStream2>,
+        vis: &Option<Visibility>,
+    ) {
         if let Some(ty) = &self.ty {
             let name = &self.name;
             stream.extend(quote! {
@@ -99,7 +175,20 @@ impl ReturnedWidget {
                 #vis #name: #ty,
             });
         }
-        self.properties.struct_fields_stream(stream, vis);
+        self.properties.struct_fields_stream(stream, structs, vis);
+    }
+}
+
+impl AssignProperty {
+    fn struct_fields_stream(&self, stream: &mut TokenStream2, vis: &Option<Visibility>) {
+        if let Some(bind) = &self.bind {
+            let handler_id = &bind.handler_id;
+            let gtk_import = crate::gtk_import();
+            stream.extend(quote! {
+                #[allow(missing_docs)]
+                #vis #handler_id: #gtk_import::glib::signal::SignalHandlerId,
+            });
+        }
     }
 }
 
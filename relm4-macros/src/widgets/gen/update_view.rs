@@ -1,13 +1,14 @@
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Span as Span2, TokenStream as TokenStream2};
 use quote::quote;
 use syn::{punctuated::Punctuated, token, Ident};
 
 use crate::widgets::{
-    AssignProperty, AssignPropertyAttr, ConditionalBranches, ConditionalWidget, MatchArm,
-    Properties, Property, PropertyName, PropertyType, ReturnedWidget, Widget, WidgetTemplateAttr,
+    AssignProperty, AssignPropertyAttr, ConditionalBranches, ConditionalWidget, ForLoop, MatchArm,
+    Properties, Property, PropertyName, PropertyType, ReturnedWidget, Widget,
 };
 
 use super::assign::AssignInfo;
+use super::util::if_branch::if_branches_stream;
 
 impl Property {
     fn update_view_stream(
@@ -38,6 +39,9 @@ impl Property {
             PropertyType::ConditionalWidget(cond_widget) => {
                 cond_widget.update_view_stream(stream, model_name);
             }
+            PropertyType::ForLoop(for_loop) => {
+                for_loop.update_view_stream(stream, &self.name, widget_name, template_path);
+            }
             PropertyType::SignalHandler(_) | PropertyType::ParseError(_) => (),
         }
     }
@@ -77,7 +81,7 @@ impl Widget {
         conditional_branch: bool,
     ) {
         let widget_name = &self.name;
-        let template_path = if self.template_attr == WidgetTemplateAttr::TemplateChild {
+        let template_path = if self.template_attr.is_template_child() {
             parent_widget_name.map(|parent_widget_name| {
                 self.func
                     .widget_template_path(parent_widget_name, &self.name)
@@ -103,19 +107,20 @@ impl ConditionalWidget {
     fn update_view_stream(&self, stream: &mut TokenStream2, model_name: &Ident) {
         let brach_stream = match &self.branches {
             ConditionalBranches::If(if_branches) => {
-                let mut stream = TokenStream2::new();
-
-                for (index, branch) in if_branches.iter().enumerate() {
-                    let mut inner_update_stream = TokenStream2::new();
-                    branch.widget.update_view_stream(
-                        &mut inner_update_stream,
-                        None,
-                        model_name,
-                        true,
-                    );
-                    branch.update_stream(&mut stream, &inner_update_stream, index);
-                }
-                stream
+                let inner_update_streams: Vec<TokenStream2> = if_branches
+                    .iter()
+                    .map(|branch| {
+                        let mut inner_update_stream = TokenStream2::new();
+                        branch.widget.update_view_stream(
+                            &mut inner_update_stream,
+                            None,
+                            model_name,
+                            true,
+                        );
+                        inner_update_stream
+                    })
+                    .collect();
+                if_branches_stream(if_branches, &inner_update_streams)
             }
             ConditionalBranches::Match((match_token, expr, match_arms)) => {
                 let mut inner_tokens = TokenStream2::new();
@@ -164,6 +169,48 @@ impl ConditionalWidget {
     }
 }
 
+impl ForLoop {
+    fn update_view_stream(
+        &self,
+        stream: &mut TokenStream2,
+        p_name: &PropertyName,
+        widget_name: &Ident,
+        template_path: Option<Punctuated<Ident, token::Dot>>,
+    ) {
+        if !self.watch {
+            return;
+        }
+
+        let name = &self.name;
+        let anchor = &self.anchor;
+        let container = Self::container(widget_name, template_path.as_ref());
+
+        // `update_view` always names its sender `sender`.
+        let sender_name = Ident::new("sender", Span2::call_site());
+        let loop_stream = self.loop_stream(widget_name, template_path, p_name, &sender_name);
+
+        // Rebuild all widgets of the loop and move them back
+        // in front of the widgets that follow the loop.
+        stream.extend(quote! {
+            {
+                use relm4::RelmContainerExt as _;
+                use relm4::RelmRemoveExt as _;
+
+                for widget in #name.drain(..) {
+                    #container.container_remove(&widget);
+                }
+                #loop_stream
+
+                let mut sibling = #anchor.clone();
+                for widget in &#name {
+                    #container.container_move_after(widget, sibling.as_ref());
+                    sibling = Some(relm4::gtk::prelude::Cast::upcast_ref::<relm4::gtk::Widget>(widget).clone());
+                }
+            }
+        });
+    }
+}
+
 impl ReturnedWidget {
     fn update_view_stream(
         &self,
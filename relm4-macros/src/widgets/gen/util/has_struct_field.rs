@@ -9,7 +9,7 @@ impl Widget {
                 self.name_assigned_by_user || self.properties.are_properties_updated()
             }
             WidgetTemplateAttr::Template => true,
-            WidgetTemplateAttr::TemplateChild => false,
+            WidgetTemplateAttr::TemplateChild { .. } | WidgetTemplateAttr::Slot => false,
         }
     }
 }
@@ -22,6 +22,8 @@ impl Properties {
                 &assign_prop.attr,
                 AssignPropertyAttr::Track { .. } | AssignPropertyAttr::Watch { .. }
             ),
+            // The container is needed to rebuild watched loops.
+            PropertyType::ForLoop(for_loop) => for_loop.watch,
             _ => false,
         })
     }
@@ -1,33 +1,120 @@
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{quote, ToTokens};
+use syn::{BinOp, Expr, Lifetime};
 
-use crate::widgets::{IfBranch, IfCondition};
+use crate::widgets::{parse_util, IfBranch, IfCondition};
 
 impl IfBranch {
-    pub(crate) fn update_stream(
-        &self,
-        stream: &mut TokenStream2,
-        inner_update_tokens: &TokenStream2,
-        index: usize,
-    ) {
+    fn body_stream(inner_update_tokens: &TokenStream2, index: usize) -> TokenStream2 {
         let index = index.to_string();
-        stream.extend(match &self.cond {
-            IfCondition::If(if_token, expr) => quote! {
-                #if_token #expr
-            },
-            IfCondition::ElseIf(else_token, if_token, expr) => quote! {
-                #else_token #if_token #expr
-            },
-            IfCondition::Else(else_token) => quote! {
-                #else_token
-            },
-        });
-        stream.extend(quote! {
+        quote! {
             {
                 let page_active: bool = (current_page == #index);
                 #inner_update_tokens
                 #index
             }
+        }
+    }
+}
+
+/// Generates the `if` expression that returns the name of the active branch.
+///
+/// `inner_update_tokens` contains the code that is executed inside each branch.
+pub(crate) fn if_branches_stream(
+    branches: &[IfBranch],
+    inner_update_tokens: &[TokenStream2],
+) -> TokenStream2 {
+    // Build the expression from the last branch to the first one
+    // so each branch can contain the remaining branches.
+    let mut rest: Option<TokenStream2> = None;
+
+    for (index, (branch, inner_update_tokens)) in
+        branches.iter().zip(inner_update_tokens).enumerate().rev()
+    {
+        let body = IfBranch::body_stream(inner_update_tokens, index);
+        let (if_token, expr) = match &branch.cond {
+            IfCondition::If(if_token, expr) | IfCondition::ElseIf(_, if_token, expr) => {
+                (if_token, expr)
+            }
+            IfCondition::Else(_) => {
+                rest = Some(body);
+                continue;
+            }
+        };
+
+        let conditions = let_chain(expr);
+        rest = Some(if conditions.len() > 1 {
+            // Let chains aren't stable on edition 2021, so they are
+            // turned into nested conditions inside of a labeled block.
+            // The remaining branches are reached by falling through.
+            let label = parse_util::unique_ident_from_parts(["if_let_chain"]);
+            let label = Lifetime::new(&format!("'{label}"), label.span());
+
+            let mut chain = quote! { break #label #body; };
+            for condition in conditions.iter().rev() {
+                chain = quote! { #if_token #(#condition)&&* { #chain } };
+            }
+
+            quote! {
+                {
+                    #label: {
+                        #chain
+                        #rest
+                    }
+                }
+            }
+        } else {
+            let else_stream = rest.map(|rest| {
+                let else_token = branches
+                    .get(index + 1)
+                    .and_then(|branch| branch.cond.else_token());
+                quote! { #else_token #rest }
+            });
+            quote! { #if_token #expr #body #else_stream }
         });
     }
+
+    rest.unwrap_or_default()
+}
+
+/// Splits a condition like `let Some(x) = y && x > 0` into the conditions
+/// of nested `if` expressions if it contains a `let` expression.
+///
+/// Consecutive conditions without `let` are kept together.
+fn let_chain(expr: &Expr) -> Vec<Vec<&Expr>> {
+    fn flatten<'a>(expr: &'a Expr, parts: &mut Vec<&'a Expr>) {
+        if let Expr::Binary(binary) = expr {
+            if let BinOp::And(_) = binary.op {
+                flatten(&binary.left, parts);
+                flatten(&binary.right, parts);
+                return;
+            }
+        }
+        parts.push(expr);
+    }
+
+    let mut parts = Vec::new();
+    flatten(expr, &mut parts);
+
+    let mut conditions: Vec<Vec<&Expr>> = Vec::new();
+    for part in parts {
+        match conditions.last_mut() {
+            Some(last) if !matches!(part, Expr::Let(_)) && !matches!(last[0], Expr::Let(_)) => {
+                last.push(part);
+            }
+            _ => conditions.push(vec![part]),
+        }
+    }
+    conditions
+}
+
+impl IfCondition {
+    fn else_token(&self) -> Option<TokenStream2> {
+        match self {
+            IfCondition::If(..) => None,
+            IfCondition::ElseIf(else_token, ..) | IfCondition::Else(else_token) => {
+                Some(else_token.to_token_stream())
+            }
+        }
+    }
 }
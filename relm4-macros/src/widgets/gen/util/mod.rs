@@ -1,5 +1,5 @@
 mod has_struct_field;
-mod if_branch;
+pub(super) mod if_branch;
 mod property_name;
 mod widget;
 mod widget_func;
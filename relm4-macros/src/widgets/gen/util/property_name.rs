@@ -1,6 +1,6 @@
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{quote, quote_spanned, ToTokens};
-use syn::Ident;
+use syn::{Error, Ident};
 
 use crate::widgets::gen::{assign::AssignInfo, PropertyName};
 
@@ -28,6 +28,27 @@ impl PropertyName {
         }
     }
 
+    /// Returns the name of the property that is set by a setter like `set_text`.
+    pub(crate) fn bound_property_name(&self) -> Result<String, Error> {
+        let ident = match self {
+            PropertyName::Ident(ident) => Some(ident),
+            PropertyName::Path(path) => path.segments.last().map(|segment| &segment.ident),
+            PropertyName::RelmContainerExtAssign(_) => None,
+        };
+
+        ident
+            .and_then(|ident| {
+                let name = ident.to_string();
+                name.strip_prefix("set_").map(|name| name.replace('_', "-"))
+            })
+            .ok_or_else(|| {
+                Error::new(
+                    self.span(),
+                    "Bound properties must be assigned with a setter like `set_text`.",
+                )
+            })
+    }
+
     pub(crate) fn assign_args_stream(&self, w_name: &Ident) -> Option<TokenStream2> {
         match self {
             PropertyName::RelmContainerExtAssign(_) | PropertyName::Ident(_) => None,
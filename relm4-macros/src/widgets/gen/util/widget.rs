@@ -1,4 +1,4 @@
-use crate::widgets::ViewWidgets;
+use crate::widgets::{ViewWidgets, WidgetTemplateAttr};
 
 impl ViewWidgets {
     /// Get a mutable reference to the root widget
@@ -11,4 +11,10 @@ impl ViewWidgets {
             root_widget.inner.name_assigned_by_user = true;
         }
     }
+
+    /// Returns `true` if the root widget is another widget template.
+    pub(crate) fn root_is_template(&self) -> bool {
+        self.get_root_widget()
+            .is_ok_and(|root_widget| root_widget.template_attr == WidgetTemplateAttr::Template)
+    }
 }
@@ -115,7 +115,12 @@ impl WidgetFunc {
     pub(crate) fn widget_template_init(&self) -> TokenStream2 {
         let widget_ty = &self.path;
         let args = if let Some(args) = &self.args {
-            args.into_token_stream()
+            if args.len() > 1 {
+                // Pass multiple arguments as tuple.
+                quote! { (#args) }
+            } else {
+                args.into_token_stream()
+            }
         } else {
             quote_spanned! { self.path.span() => () }
         };
@@ -1,7 +1,9 @@
 use proc_macro2::{Span as Span2, TokenStream as TokenStream2};
 use syn::punctuated::Punctuated;
-use syn::token::{Else, FatArrow, If, Match, Mut};
-use syn::{token, AngleBracketedGenericArguments, Expr, ExprClosure, Ident, Pat, Path, Type};
+use syn::token::{Else, FatArrow, For, If, In, Match, Mut};
+use syn::{
+    token, AngleBracketedGenericArguments, Expr, ExprClosure, Ident, LitStr, Pat, Path, Type,
+};
 
 use crate::args::Args;
 
@@ -28,6 +30,7 @@ enum PropertyType {
     SignalHandler(SignalHandler),
     Widget(Widget),
     ConditionalWidget(ConditionalWidget),
+    ForLoop(ForLoop),
     ParseError(ParseError),
 }
 
@@ -62,7 +65,26 @@ struct AssignProperty {
     /// Iterate through elements to generate tokens
     iterative: bool,
     block_signals: Vec<Ident>,
+    /// Block all signal handlers of the widget, either with `#[block_signal]`
+    /// or automatically for watched and tracked properties
+    block_all_signals: bool,
     chain: Option<Box<Expr>>,
+    bind: Option<Bind>,
+    expression: Option<ExpressionBind>,
+}
+
+/// Sends a message with the new value when the property changes.
+#[derive(Debug)]
+struct Bind {
+    message: Box<Expr>,
+    handler_id: Ident,
+}
+
+/// Binds the property to a `gtk::Expression` instead of assigning a value.
+#[derive(Debug)]
+struct ExpressionBind {
+    /// The object that is used as `this` when evaluating the expression.
+    this: Option<Box<Expr>>,
 }
 
 #[derive(Debug)]
@@ -135,6 +157,10 @@ pub(super) struct Widget {
     ref_token: Option<token::And>,
     deref_token: Option<token::Star>,
     returned_widget: Option<ReturnedWidget>,
+    /// Store the widget and its children in a separate struct with this field name.
+    group: Option<Ident>,
+    /// The widget is a slot of a template that users can fill with their own children.
+    slot: bool,
 }
 
 #[derive(Debug, PartialEq)]
@@ -142,13 +168,22 @@ enum WidgetAttr {
     None,
     Local,
     LocalRef,
+    /// Load the widget from a GtkBuilder UI file.
+    Builder(LitStr),
+    /// An object of the UI file of a parent widget.
+    BuilderChild,
 }
 
 #[derive(Debug, PartialEq)]
 enum WidgetTemplateAttr {
     None,
     Template,
-    TemplateChild,
+    TemplateChild {
+        /// Remove the children defined by the template before adding new ones.
+        replace: bool,
+    },
+    /// Fills a slot of the template, replacing its default children.
+    Slot,
 }
 
 #[derive(Debug)]
@@ -169,6 +204,22 @@ struct ConditionalWidget {
     branches: ConditionalBranches,
 }
 
+/// Widgets that are created for each element of an iterator.
+#[derive(Debug)]
+struct ForLoop {
+    /// Name of the vector that stores the widgets.
+    name: Ident,
+    /// Name of the widget that precedes the widgets of the loop in the container.
+    anchor: Ident,
+    /// Whether the widgets are rebuilt in `update_view`.
+    watch: bool,
+    for_token: For,
+    pattern: Pat,
+    in_token: In,
+    expr: Box<Expr>,
+    widget: Widget,
+}
+
 #[derive(Debug)]
 enum ConditionalBranches {
     If(Vec<IfBranch>),
@@ -202,15 +253,22 @@ enum Attr {
     LocalRef(Ident),
     Root(Ident),
     Iterate(Ident),
-    Watch(Ident, Option<Ident>),
+    Watch(Ident, Option<Ident>, Option<Box<Expr>>),
     Track(Ident, Option<Ident>, Option<Box<Expr>>),
-    BlockSignal(Ident, Vec<Ident>),
+    /// Handlers listed in parentheses, or [`None`] for all handlers.
+    BlockSignal(Ident, Option<Vec<Ident>>),
     Name(Ident, Ident),
     Transition(Ident, Ident),
     Wrap(Ident, Path),
     Chain(Ident, Box<Expr>),
+    Bind(Ident, Box<Expr>),
+    Expression(Ident, Option<Box<Expr>>),
     Template(Ident),
-    TemplateChild(Ident),
+    TemplateChild(Ident, Option<Ident>),
+    Builder(Ident, LitStr),
+    BuilderChild(Ident),
+    Group(Ident, Ident),
+    Slot(Ident),
 }
 
 struct Attrs {
@@ -5,14 +5,17 @@ use syn::spanned::Spanned;
 use syn::{Error, Expr, ExprCall, ExprField, Ident, Member, Result, Token};
 
 use crate::args::Args;
-use crate::widgets::parse_util::attr_twice_error;
-use crate::widgets::{AssignProperty, AssignPropertyAttr, Attr, Attrs};
+use crate::widgets::parse_util::{self, attr_twice_error};
+use crate::widgets::{AssignProperty, AssignPropertyAttr, Attr, Attrs, Bind, ExpressionBind};
 
 struct ProcessedAttrs {
     watch: AssignPropertyAttr,
     iterative: bool,
     block_signals: Vec<Ident>,
+    block_all_signals: bool,
     chain: Option<Box<Expr>>,
+    bind: Option<Bind>,
+    expression: Option<ExpressionBind>,
 }
 
 impl AssignProperty {
@@ -38,9 +41,19 @@ impl AssignProperty {
             watch,
             iterative,
             block_signals,
+            block_all_signals,
             chain,
+            bind,
+            expression,
         } = Self::process_attributes(&expr, attributes)?;
 
+        if optional_assign && expression.is_some() {
+            return Err(Error::new(
+                colon_span,
+                "Properties with the `expression` attribute can't be optional.",
+            ));
+        }
+
         Ok(Self {
             attr: watch,
             expr,
@@ -48,7 +61,10 @@ impl AssignProperty {
             optional_assign,
             iterative,
             block_signals,
+            block_all_signals,
             chain,
+            bind,
+            expression,
         })
     }
 
@@ -57,7 +73,11 @@ impl AssignProperty {
             let mut iterative = false;
             let mut watch = AssignPropertyAttr::None;
             let mut block_signals = Vec::with_capacity(0);
+            let mut block_all_signals = false;
+            let mut explicit_block_signals = false;
             let mut chain = None;
+            let mut bind = None;
+            let mut expression = None;
 
             for attr in attrs.inner {
                 let span = attr.span();
@@ -68,16 +88,31 @@ impl AssignProperty {
                         }
                         iterative = true;
                     }
-                    Attr::Watch(_, skip_init) => {
+                    Attr::Watch(_, skip_init, condition) => {
                         if watch == AssignPropertyAttr::None {
-                            watch = AssignPropertyAttr::Watch { skip_init }
+                            // A watch with a condition is updated like a track with a custom expression.
+                            watch = if let Some(condition) = condition {
+                                AssignPropertyAttr::Track {
+                                    track_expr: condition.to_token_stream(),
+                                    skip_init,
+                                    paste_model: false,
+                                }
+                            } else {
+                                AssignPropertyAttr::Watch { skip_init }
+                            };
                         } else {
                             return Err(attr_twice_error(span));
                         }
                     }
                     Attr::Track(_, skip_init, expr) => {
                         if watch == AssignPropertyAttr::None {
-                            watch = if let Some(expr) = expr {
+                            watch = if let Some(path) = expr.as_deref().and_then(nested_path) {
+                                AssignPropertyAttr::Track {
+                                    track_expr: generate_nested_tracker(path)?,
+                                    skip_init,
+                                    paste_model: false,
+                                }
+                            } else if let Some(expr) = expr {
                                 AssignPropertyAttr::Track {
                                     track_expr: expr.to_token_stream(),
                                     skip_init,
@@ -95,10 +130,15 @@ impl AssignProperty {
                         }
                     }
                     Attr::BlockSignal(_, idents) => {
-                        if block_signals.is_empty() {
+                        if explicit_block_signals {
+                            return Err(attr_twice_error(span));
+                        }
+                        explicit_block_signals = true;
+                        // Without arguments, all signal handlers of the widget are blocked.
+                        if let Some(idents) = idents {
                             block_signals = idents;
                         } else {
-                            return Err(attr_twice_error(span));
+                            block_all_signals = true;
                         }
                     }
                     Attr::Chain(_, expr) => {
@@ -108,26 +148,73 @@ impl AssignProperty {
                             return Err(attr_twice_error(span));
                         }
                     }
+                    Attr::Bind(_, message) => {
+                        if bind.is_none() {
+                            bind = Some(Bind {
+                                message,
+                                handler_id: parse_util::unique_ident_from_parts([
+                                    "bind_handler_id",
+                                ]),
+                            });
+                        } else {
+                            return Err(attr_twice_error(span));
+                        }
+                    }
+                    Attr::Expression(_, this) => {
+                        if expression.is_none() {
+                            expression = Some(ExpressionBind { this });
+                        } else {
+                            return Err(attr_twice_error(span));
+                        }
+                    }
                     _ => {
                         return Err(Error::new(
                             attr.span(),
-                            "Properties can only have `watch`, `track` or `iterative` as attribute.",
+                            "Properties can only have `watch`, `track`, `bind`, `expression` or `iterative` as attribute.",
                         ));
                     }
                 }
             }
+
+            // Expressions update the property by themselves.
+            if expression.is_some()
+                && (iterative || bind.is_some() || watch != AssignPropertyAttr::None)
+            {
+                return Err(Error::new(
+                    assign_expr.span(),
+                    "The `expression` attribute can't be combined with `watch`, `track`, `bind` or `iterate`.",
+                ));
+            }
+
+            // Bound properties are watched unless `track` is used.
+            if bind.is_some() && watch == AssignPropertyAttr::None {
+                watch = AssignPropertyAttr::Watch { skip_init: None };
+            }
+
+            // Updated properties block all signal handlers of their widget,
+            // unless `#[block_signal(...)]` selects the handlers.
+            if watch != AssignPropertyAttr::None && !explicit_block_signals {
+                block_all_signals = true;
+            }
+
             Ok(ProcessedAttrs {
                 watch,
                 iterative,
                 block_signals,
+                block_all_signals,
                 chain,
+                bind,
+                expression,
             })
         } else {
             Ok(ProcessedAttrs {
                 watch: AssignPropertyAttr::None,
                 iterative: false,
                 block_signals: Vec::with_capacity(0),
+                block_all_signals: false,
                 chain: None,
+                bind: None,
+                expression: None,
             })
         }
     }
@@ -143,6 +230,50 @@ fn expr_field_from_expr_call(call_expr: &ExprCall) -> Option<&ExprField> {
     }
 }
 
+/// Returns the field path of `#[track(nested = model.settings.theme)]`.
+fn nested_path(expression: &Expr) -> Option<&Expr> {
+    let Expr::Assign(assign) = expression else {
+        return None;
+    };
+    let Expr::Path(path) = &*assign.left else {
+        return None;
+    };
+    path.path.is_ident("nested").then_some(&*assign.right)
+}
+
+/// Generates a tracker for field paths into nested structs like `model.settings.theme`.
+///
+/// The tracker checks whether the field of the model that contains
+/// the nested struct was changed.
+fn generate_nested_tracker(expression: &Expr) -> Result<TokenStream2> {
+    let error = || {
+        Error::new(
+            expression.span(),
+            "Expected a field path like `nested = model.settings.theme`.",
+        )
+    };
+
+    let Expr::Field(expr_field) = expression else {
+        return Err(error());
+    };
+
+    let mut current = expr_field;
+    loop {
+        if !matches!(current.member, Member::Named(_)) {
+            return Err(error());
+        }
+        match &*current.base {
+            Expr::Field(base) => current = base,
+            Expr::Path(path) if path.path.get_ident().is_some() => break,
+            _ => return Err(error()),
+        }
+    }
+
+    let model = &current.base;
+    let ident = &current.member;
+    Ok(quote_spanned! { expr_field.span() => #model.changed(Self::#ident()) })
+}
+
 fn generate_tracker_from_expression(expression: &Expr) -> Result<TokenStream2> {
     let error_fn = move |span, msg: &str| {
         let error_msg =
@@ -26,15 +26,23 @@ impl Parse for Attrs {
                     } else if ident == "root" {
                         Attr::Root(ident.clone())
                     } else if ident == "watch" {
-                        Attr::Watch(ident.clone(), None)
+                        Attr::Watch(ident.clone(), None, None)
                     } else if ident == "track" {
                         Attr::Track(ident.clone(), None, None)
+                    } else if ident == "block_signal" {
+                        Attr::BlockSignal(ident.clone(), None)
                     } else if ident == "iterate" {
                         Attr::Iterate(ident.clone())
                     } else if ident == "template" {
                         Attr::Template(ident.clone())
                     } else if ident == "template_child" {
-                        Attr::TemplateChild(ident.clone())
+                        Attr::TemplateChild(ident.clone(), None)
+                    } else if ident == "builder_child" {
+                        Attr::BuilderChild(ident.clone())
+                    } else if ident == "slot" {
+                        Attr::Slot(ident.clone())
+                    } else if ident == "expression" {
+                        Attr::Expression(ident.clone(), None)
                     } else {
                         return Err(unexpected_attr_name(ident));
                     }
@@ -56,17 +64,20 @@ impl Parse for Attrs {
                             let ident = expect_ident_from_expr(&expr)?;
                             signal_idents.push(ident);
                         }
-                        Attr::BlockSignal(ident.clone(), signal_idents)
+                        Attr::BlockSignal(ident.clone(), Some(signal_idents))
                     } else if ident == "watch" {
-                        let expr = expect_one_nested_expr(&nested)?;
-                        if let Some(skip_init) = expr_to_skip_init_ident(expr) {
-                            Attr::Watch(ident.clone(), Some(skip_init))
-                        } else {
-                            return Err(Error::new(nested.span(), "Expected `skip_init`."));
-                        }
+                        let (skip_init, expr) = parse_skip_init_and_expr(&nested)?;
+                        Attr::Watch(ident.clone(), skip_init, expr.map(Box::new))
                     } else if ident == "track" {
-                        let (skip_init, expr) = parse_track(&nested)?;
+                        let (skip_init, expr) = parse_skip_init_and_expr(&nested)?;
                         Attr::Track(ident.clone(), skip_init, expr.map(Box::new))
+                    } else if ident == "template_child" {
+                        let expr = expect_one_nested_expr(&nested)?;
+                        let replace = expect_ident_from_expr(expr)?;
+                        if replace != "replace" {
+                            return Err(Error::new(replace.span(), "Expected `replace`."));
+                        }
+                        Attr::TemplateChild(ident.clone(), Some(replace))
                     } else if ident == "transition" {
                         let expr = expect_one_nested_expr(&nested)?;
                         let ident = expect_ident_from_expr(expr)?;
@@ -82,6 +93,16 @@ impl Parse for Attrs {
                     } else if ident == "chain" {
                         let expr = expect_one_nested_expr(&nested)?;
                         Attr::Chain(ident.clone(), Box::new(expr.clone()))
+                    } else if ident == "bind" {
+                        let expr = expect_one_nested_expr(&nested)?;
+                        Attr::Bind(ident.clone(), Box::new(expr.clone()))
+                    } else if ident == "expression" {
+                        let expr = expect_one_nested_expr(&nested)?;
+                        Attr::Expression(ident.clone(), Some(Box::new(expr.clone())))
+                    } else if ident == "group" {
+                        let expr = expect_one_nested_expr(&nested)?;
+                        let group = expect_ident_from_expr(expr)?;
+                        Attr::Group(ident.clone(), group)
                     } else {
                         return Err(unexpected_attr_name(ident));
                     }
@@ -106,6 +127,9 @@ impl Parse for Attrs {
                     } else if ident == "name" {
                         let string = expect_string_lit(&lit)?;
                         Attr::Name(ident.clone(), string.parse()?)
+                    } else if ident == "builder" {
+                        let string = expect_string_lit(&lit)?;
+                        Attr::Builder(ident.clone(), string.clone())
                     } else {
                         return Err(unexpected_attr_name(ident));
                     }
@@ -177,7 +201,10 @@ fn expect_one_nested_expr(nested: &Punctuated<Expr, token::Comma>) -> Result<&Ex
     }
 }
 
-fn parse_track(nested: &Punctuated<Expr, token::Comma>) -> Result<(Option<Ident>, Option<Expr>)> {
+/// Parses `skip_init`, an expression or both, like in `#[track(skip_init, expr)]`.
+fn parse_skip_init_and_expr(
+    nested: &Punctuated<Expr, token::Comma>,
+) -> Result<(Option<Ident>, Option<Expr>)> {
     let len = nested.len();
     if len == 1 {
         if let Some(skip_ident) = expr_to_skip_init_ident(&nested[0]) {
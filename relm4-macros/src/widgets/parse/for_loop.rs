@@ -0,0 +1,48 @@
+use syn::parse::ParseStream;
+use syn::{Error, Expr, Pat, Token};
+
+use crate::widgets::{parse_util, Attr, Attrs, ForLoop, ParseError, Widget};
+
+impl ForLoop {
+    pub(super) fn parse(input: ParseStream<'_>, attrs: Option<Attrs>) -> Result<Self, ParseError> {
+        let mut watch = false;
+        for attr in attrs.map(|attrs| attrs.inner).unwrap_or_default() {
+            match attr {
+                Attr::Watch(_, None, None) if !watch => watch = true,
+                _ => {
+                    return Err(Error::new(
+                        attr.span(),
+                        "Only `#[watch]` is allowed for `for` loops. Put other attributes on the widget inside the loop instead.",
+                    )
+                    .into());
+                }
+            }
+        }
+
+        let for_token = input.parse()?;
+        let pattern = Pat::parse_multi_with_leading_vert(input)?;
+        let in_token: Token![in] = input.parse()?;
+        let expr = Box::new(Expr::parse_without_eager_brace(input)?);
+
+        let braced = parse_util::braces(input)?;
+        let attributes = braced.parse().ok();
+        let widget = Widget::parse(&braced, attributes, None)?;
+
+        if !braced.is_empty() {
+            return Err(braced
+                .error("The body of a `for` loop must contain exactly one widget.")
+                .into());
+        }
+
+        Ok(Self {
+            name: parse_util::unique_ident_from_parts(["for_loop"]),
+            anchor: parse_util::unique_ident_from_parts(["for_loop", "anchor"]),
+            watch,
+            for_token,
+            pattern,
+            in_token,
+            expr,
+            widget,
+        })
+    }
+}
@@ -5,11 +5,11 @@ use syn::token::{And, Paren};
 use syn::{Expr, ExprCall, ExprLit, ExprPath, Ident, Lit, LitStr};
 
 use crate::args::Args;
-use crate::widgets::{parse_util, IfBranch, ParseError, Widget};
+use crate::widgets::{parse_util, IfBranch, IfCondition, ParseError, Widget};
 
 impl IfBranch {
     pub(super) fn parse(input: ParseStream<'_>, index: usize) -> Result<Self, ParseError> {
-        let cond = input.parse()?;
+        let cond: IfCondition = input.parse()?;
 
         let braced = parse_util::braces(input)?;
 
@@ -19,6 +19,7 @@ impl IfBranch {
         widget.ref_token = Some(And {
             spans: [Span2::mixed_site()],
         });
+        widget.watch_bindings(&cond.bindings());
 
         Ok(Self { cond, widget })
     }
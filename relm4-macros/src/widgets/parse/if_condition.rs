@@ -1,16 +1,23 @@
 use syn::parse::{Parse, ParseStream};
-use syn::{Result, Token};
+use syn::{Expr, Result, Token};
 
 use crate::widgets::IfCondition;
 
 impl Parse for IfCondition {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         if input.peek(Token![if]) {
-            Ok(Self::If(input.parse()?, input.parse()?))
+            Ok(Self::If(
+                input.parse()?,
+                Expr::parse_without_eager_brace(input)?,
+            ))
         } else if input.peek(Token![else]) {
             let else_token = input.parse()?;
             if input.peek(Token![if]) {
-                Ok(Self::ElseIf(else_token, input.parse()?, input.parse()?))
+                Ok(Self::ElseIf(
+                    else_token,
+                    input.parse()?,
+                    Expr::parse_without_eager_brace(input)?,
+                ))
             } else {
                 Ok(Self::Else(else_token))
             }
@@ -3,6 +3,7 @@ use syn::token::And;
 use syn::{token, Token};
 
 use crate::widgets::parse::if_branch::args_from_index;
+use crate::widgets::parse::pattern_bindings::pattern_bindings;
 use crate::widgets::{parse_util, MatchArm, ParseError, Widget};
 
 impl MatchArm {
@@ -31,6 +32,10 @@ impl MatchArm {
         let mut widget = Widget::parse(inner_tokens, attributes, Some(args))?;
         widget.ref_token = Some(And { spans: [ref_span] });
 
+        let mut bindings = Vec::new();
+        pattern_bindings(&pattern, &mut bindings);
+        widget.watch_bindings(&bindings);
+
         // Parse trailing commas
         if input.peek(Token![,]) {
             let _comma: Token![,] = input.parse()?;
@@ -2,9 +2,11 @@ mod assign_property;
 mod attributes;
 mod conditional_branches;
 mod conditional_widget;
+mod for_loop;
 mod if_branch;
 mod if_condition;
 mod match_arm;
+mod pattern_bindings;
 mod properties;
 mod property;
 mod property_name;
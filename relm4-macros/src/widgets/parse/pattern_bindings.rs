@@ -0,0 +1,143 @@
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::ToTokens;
+use syn::{BinOp, Expr, Ident, Lit, Pat};
+
+use crate::widgets::{AssignPropertyAttr, IfCondition, Properties, PropertyType, Widget};
+
+impl Widget {
+    /// Watches all properties without attribute that use variables
+    /// bound by the pattern of a conditional branch.
+    ///
+    /// These properties can't be initialized together with the widget
+    /// because the variables are only available inside the branch.
+    pub(super) fn watch_bindings(&mut self, bindings: &[Ident]) {
+        if bindings.is_empty() {
+            return;
+        }
+
+        self.properties.watch_bindings(bindings);
+        if let Some(returned_widget) = &mut self.returned_widget {
+            returned_widget.properties.watch_bindings(bindings);
+        }
+    }
+}
+
+impl Properties {
+    fn watch_bindings(&mut self, bindings: &[Ident]) {
+        for prop in &mut self.properties {
+            match &mut prop.ty {
+                PropertyType::Assign(assign) => {
+                    if assign.attr == AssignPropertyAttr::None {
+                        let mut tokens = assign.expr.to_token_stream();
+                        if let Some(args) = &assign.args {
+                            args.to_tokens(&mut tokens);
+                        }
+                        if let Some(chain) = &assign.chain {
+                            chain.to_tokens(&mut tokens);
+                        }
+
+                        if uses_bindings(tokens, bindings) {
+                            assign.attr = AssignPropertyAttr::Watch { skip_init: None };
+                        }
+                    }
+                }
+                PropertyType::Widget(widget) => widget.watch_bindings(bindings),
+                PropertyType::ConditionalWidget(_)
+                | PropertyType::ForLoop(_)
+                | PropertyType::SignalHandler(_)
+                | PropertyType::ParseError(_) => (),
+            }
+        }
+    }
+}
+
+impl IfCondition {
+    /// Returns the variables bound by `let` expressions in the condition.
+    pub(super) fn bindings(&self) -> Vec<Ident> {
+        fn let_bindings(expr: &Expr, bindings: &mut Vec<Ident>) {
+            match expr {
+                Expr::Let(expr_let) => pattern_bindings(&expr_let.pat, bindings),
+                Expr::Binary(binary) if matches!(binary.op, BinOp::And(_)) => {
+                    let_bindings(&binary.left, bindings);
+                    let_bindings(&binary.right, bindings);
+                }
+                _ => (),
+            }
+        }
+
+        let mut bindings = Vec::new();
+        if let Self::If(_, expr) | Self::ElseIf(_, _, expr) = self {
+            let_bindings(expr, &mut bindings);
+        }
+        bindings
+    }
+}
+
+/// Collects the variables bound by a pattern.
+pub(super) fn pattern_bindings(pat: &Pat, bindings: &mut Vec<Ident>) {
+    match pat {
+        Pat::Ident(pat_ident) => {
+            // Uppercase identifiers are usually constants or unit variants like `None`.
+            let is_binding = pat_ident
+                .ident
+                .to_string()
+                .starts_with(|c: char| c.is_lowercase() || c == '_');
+            if is_binding {
+                bindings.push(pat_ident.ident.clone());
+            }
+            if let Some((_, sub_pat)) = &pat_ident.subpat {
+                pattern_bindings(sub_pat, bindings);
+            }
+        }
+        Pat::Or(pat_or) => {
+            for case in &pat_or.cases {
+                pattern_bindings(case, bindings);
+            }
+        }
+        Pat::Paren(pat_paren) => pattern_bindings(&pat_paren.pat, bindings),
+        Pat::Reference(pat_ref) => pattern_bindings(&pat_ref.pat, bindings),
+        Pat::Slice(pat_slice) => {
+            for elem in &pat_slice.elems {
+                pattern_bindings(elem, bindings);
+            }
+        }
+        Pat::Struct(pat_struct) => {
+            for field in &pat_struct.fields {
+                pattern_bindings(&field.pat, bindings);
+            }
+        }
+        Pat::Tuple(pat_tuple) => {
+            for elem in &pat_tuple.elems {
+                pattern_bindings(elem, bindings);
+            }
+        }
+        Pat::TupleStruct(pat_tuple_struct) => {
+            for elem in &pat_tuple_struct.elems {
+                pattern_bindings(elem, bindings);
+            }
+        }
+        Pat::Type(pat_type) => pattern_bindings(&pat_type.pat, bindings),
+        _ => (),
+    }
+}
+
+/// Checks whether the tokens use any of the variables,
+/// including inline arguments of format strings like `"{name}"`.
+fn uses_bindings(tokens: TokenStream2, bindings: &[Ident]) -> bool {
+    tokens.into_iter().any(|tree| match tree {
+        TokenTree::Ident(ident) => bindings.contains(&ident),
+        TokenTree::Group(group) => uses_bindings(group.stream(), bindings),
+        TokenTree::Literal(literal) => {
+            if let Lit::Str(lit_str) = Lit::new(literal) {
+                let value = lit_str.value();
+                bindings.iter().any(|binding| {
+                    value.contains(&format!("{{{binding}}}"))
+                        || value.contains(&format!("{{{binding}:"))
+                })
+            } else {
+                false
+            }
+        }
+        TokenTree::Punct(_) => false,
+    })
+}
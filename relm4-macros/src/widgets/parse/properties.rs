@@ -54,7 +54,42 @@ impl Properties {
         }
 
         let properties = props.into_pairs().map(Pair::into_value).collect();
-        Properties { properties }
+        let mut properties = Properties { properties };
+        properties.block_all_signals();
+        properties
+    }
+
+    /// Blocks all signal handlers of the widget while properties
+    /// with `#[watch]`, `#[track]` or `#[block_signal]` are updated.
+    ///
+    /// This prevents programmatic updates from sending input messages
+    /// back to the component.
+    /// Properties with `#[block_signal(...)]` keep their explicit selection.
+    fn block_all_signals(&mut self) {
+        let blocks_all = self.properties.iter().any(
+            |prop| matches!(&prop.ty, PropertyType::Assign(assign) if assign.block_all_signals),
+        );
+        if !blocks_all {
+            return;
+        }
+
+        let mut handler_ids = Vec::new();
+        for prop in &mut self.properties {
+            if let PropertyType::SignalHandler(signal_handler) = &mut prop.ty {
+                let handler_id = signal_handler.handler_id.get_or_insert_with(|| {
+                    parse_util::unique_ident_from_parts(["signal_handler_id"])
+                });
+                handler_ids.push(handler_id.clone());
+            }
+        }
+
+        for prop in &mut self.properties {
+            if let PropertyType::Assign(assign) = &mut prop.ty {
+                if assign.block_all_signals {
+                    assign.block_signals.clone_from(&handler_ids);
+                }
+            }
+        }
     }
 }
 
@@ -3,8 +3,8 @@ use syn::spanned::Spanned;
 use syn::{token, Error, Ident, Token};
 
 use crate::widgets::{
-    parse_util, AssignProperty, Attrs, ConditionalWidget, ParseError, Property, PropertyName,
-    PropertyType, SignalHandler, Widget, WidgetFunc,
+    parse_util, AssignProperty, Attrs, ConditionalWidget, ForLoop, ParseError, Property,
+    PropertyName, PropertyType, SignalHandler, Widget, WidgetFunc,
 };
 
 impl Property {
@@ -42,6 +42,14 @@ impl Property {
             });
         }
 
+        // parse `for item in iter { WIDGET }`
+        if input.peek(Token![for]) {
+            return Ok(Property {
+                name: PropertyName::RelmContainerExtAssign(input.span()),
+                ty: PropertyType::ForLoop(ForLoop::parse(input, attributes.take())?),
+            });
+        }
+
         // Parse path, ident or function
         let func = WidgetFunc::parse(input)?;
 
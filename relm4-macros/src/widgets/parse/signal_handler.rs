@@ -1,5 +1,6 @@
 use syn::parse::ParseStream;
-use syn::{Expr, Result, Token};
+use syn::spanned::Spanned;
+use syn::{token, Error, Expr, ExprAsync, ExprClosure, Result, ReturnType, Token};
 
 use crate::widgets::{Args, ClosureSignalHandler, SignalHandler, SignalHandlerVariant};
 
@@ -8,7 +9,11 @@ impl SignalHandler {
         input: ParseStream<'_>,
         args: Option<Args<Expr>>,
     ) -> Result<Self> {
-        let inner = if args.is_some() || input.peek(Token![move]) || input.peek(Token![|]) {
+        let inner = if args.is_some()
+            || input.peek(Token![move])
+            || input.peek(Token![|])
+            || input.peek(Token![async])
+        {
             ClosureSignalHandler::parse_with_args(input, args).map(SignalHandlerVariant::Closure)
         } else {
             input.parse().map(SignalHandlerVariant::Expr)
@@ -30,7 +35,24 @@ impl ClosureSignalHandler {
         input: ParseStream<'_>,
         args: Option<Args<Expr>>,
     ) -> Result<Self> {
-        let closure = input.parse()?;
+        let is_async_block = input.peek(Token![async])
+            && (input.peek2(token::Brace)
+                || input.peek2(Token![move]) && input.peek3(token::Brace));
+
+        let closure: ExprClosure = if is_async_block {
+            // Async blocks are handled like async closures that ignore their argument.
+            let ExprAsync { block, .. } = input.parse()?;
+            syn::parse_quote_spanned! { block.span() => async move |_| #block }
+        } else {
+            input.parse()?
+        };
+
+        if closure.asyncness.is_some() && matches!(closure.output, ReturnType::Type(..)) {
+            return Err(Error::new(
+                closure.output.span(),
+                "Async signal handlers can't return a value",
+            ));
+        }
 
         Ok(Self { closure, args })
     }
@@ -58,6 +58,8 @@ impl TopLevelWidget {
                 ref_token: None,
                 deref_token: None,
                 returned_widget: None,
+                group: None,
+                slot: false,
             },
         };
 
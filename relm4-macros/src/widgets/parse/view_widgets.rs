@@ -1,5 +1,5 @@
 use syn::parse::{Parse, ParseStream, Result};
-use syn::{Error, Ident, Token};
+use syn::{Ident, Token};
 
 use crate::widgets::{TopLevelWidget, ViewWidgets};
 
@@ -14,12 +14,7 @@ impl Parse for ViewWidgets {
         // Parse colon between widgets and look for more
         while input.parse::<Token![,]>().is_ok() && !input.is_empty() {
             let widget = TopLevelWidget::parse(input);
-            if let Some(root_attr) = &widget.root_attr {
-                if root_exists {
-                    return Err(Error::new(root_attr.span(), "You cannot have two roots."));
-                }
-                root_exists = true;
-            }
+            root_exists |= widget.root_attr.is_some();
             top_level_widgets.push(widget);
         }
 
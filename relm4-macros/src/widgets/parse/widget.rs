@@ -19,6 +19,8 @@ type AttributeInfo = (
     Option<Ident>,
     Option<Path>,
     WidgetTemplateAttr,
+    Option<Ident>,
+    bool,
 );
 
 impl Widget {
@@ -27,15 +29,29 @@ impl Widget {
         attributes: Option<Attrs>,
         args: Option<Args<Expr>>,
     ) -> Result<Self, ParseError> {
-        let (attr, doc_attr, new_name, assign_wrapper, template_attr) =
+        let (attr, doc_attr, new_name, assign_wrapper, mut template_attr, group, mut slot) =
             Self::process_attributes(attributes)?;
         // Check if first token is `mut`
         let mutable = input.parse().ok();
 
+        // Named slots are declared by templates, unnamed slots refer to
+        // the slot of a template that is filled with new children.
+        if slot && new_name.is_none() && !input.peek2(Token![=]) {
+            if template_attr != WidgetTemplateAttr::None {
+                return Err(Error::new(
+                    input.span(),
+                    "The `slot` attribute can't be combined with `template` or `template_child`.",
+                )
+                .into());
+            }
+            template_attr = WidgetTemplateAttr::Slot;
+            slot = false;
+        }
+
         // Look for name = Widget syntax
         let name_opt: Option<Ident> = if input.peek2(Token![=]) {
-            if attr.is_local_attr() || template_attr == WidgetTemplateAttr::TemplateChild {
-                return Err(input.error("When using the `local`, `local_ref` or `template_child` attributes you cannot rename the existing local variable.").into());
+            if attr.is_local_attr() || template_attr.is_template_child() {
+                return Err(input.error("When using the `local`, `local_ref`, `builder`, `builder_child` or `template_child` attributes you cannot rename the existing local variable.").into());
             }
             let name = input.parse()?;
             let _token: Token![=] = input.parse()?;
@@ -55,15 +71,14 @@ impl Widget {
             name_set = true;
         }
 
-        if name_set && (attr.is_local_attr() || template_attr == WidgetTemplateAttr::TemplateChild)
-        {
+        if name_set && (attr.is_local_attr() || template_attr.is_template_child()) {
             return Err(Error::new(input.span(), "Widget name is specified more than once (attribute, assignment or local attribute).").into());
         }
 
         // Generate a name if no name was given.
         let (name, name_assigned_by_user) = if let Some(name) = name_opt.or(new_name) {
             (name, true)
-        } else if attr.is_local_attr() || template_attr == WidgetTemplateAttr::TemplateChild {
+        } else if attr.is_local_attr() || template_attr.is_template_child() {
             (Self::local_attr_name(&func)?, true)
         } else {
             (func.snake_case_name(), false)
@@ -92,6 +107,8 @@ impl Widget {
             ref_token,
             deref_token,
             returned_widget,
+            group,
+            slot,
         })
     }
 
@@ -100,9 +117,17 @@ impl Widget {
         func: WidgetFunc,
         attributes: Option<Attrs>,
     ) -> Result<Self, ParseError> {
-        let (attr, doc_attr, new_name, assign_wrapper, template_attr) =
+        let (attr, doc_attr, new_name, assign_wrapper, template_attr, group, slot) =
             Self::process_attributes(attributes)?;
 
+        if slot {
+            return Err(Error::new(
+                func.path.span(),
+                "Slots can't be used in container assignments.",
+            )
+            .into());
+        }
+
         if let Some(wrapper) = assign_wrapper {
             return Err(Error::new(
                 wrapper.span(),
@@ -119,7 +144,7 @@ impl Widget {
         };
 
         // Make sure that the name is only defined one.
-        if attr.is_local_attr() || template_attr == WidgetTemplateAttr::TemplateChild {
+        if attr.is_local_attr() || template_attr.is_template_child() {
             if let Some(name) = &new_name {
                 return Err(Error::new(name.span(), "Widget name is specified more than once (attribute, assignment or local attribute).").into());
             }
@@ -128,7 +153,7 @@ impl Widget {
         // Generate a name
         let (name, name_assigned_by_user) = if let Some(name) = new_name {
             (name, true)
-        } else if attr.is_local_attr() || template_attr == WidgetTemplateAttr::TemplateChild {
+        } else if attr.is_local_attr() || template_attr.is_template_child() {
             (Self::local_attr_name(&func)?, true)
         } else {
             (func.snake_case_name(), false)
@@ -152,6 +177,8 @@ impl Widget {
             ref_token,
             deref_token: None,
             returned_widget: None,
+            group,
+            slot: false,
         })
     }
 
@@ -163,7 +190,7 @@ impl Widget {
         if template_attr != &WidgetTemplateAttr::Template {
             for prop in &props.properties {
                 if let PropertyType::Widget(widget) = &prop.ty {
-                    if widget.template_attr == WidgetTemplateAttr::TemplateChild {
+                    if widget.template_attr.is_template_child() {
                         return Err(ParseError::Generic(
                             Error::new(
                                 widget.name.span(),
@@ -185,6 +212,8 @@ impl Widget {
             let mut name = None;
             let mut assign_wrapper = None;
             let mut template_attr = WidgetTemplateAttr::None;
+            let mut group = None;
+            let mut slot = false;
 
             for attr in attrs.inner {
                 let span = attr.span();
@@ -203,6 +232,20 @@ impl Widget {
                             return Err(attr_twice_error(span).into());
                         }
                     }
+                    Attr::Builder(_, file) => {
+                        if widget_attr == WidgetAttr::None {
+                            widget_attr = WidgetAttr::Builder(file);
+                        } else {
+                            return Err(attr_twice_error(span).into());
+                        }
+                    }
+                    Attr::BuilderChild(_) => {
+                        if widget_attr == WidgetAttr::None {
+                            widget_attr = WidgetAttr::BuilderChild;
+                        } else {
+                            return Err(attr_twice_error(span).into());
+                        }
+                    }
                     Attr::Doc(tokens) => {
                         if let Some(doc_tokens) = &mut doc_attr {
                             doc_tokens.extend(tokens);
@@ -228,24 +271,54 @@ impl Widget {
                         }
                         template_attr = WidgetTemplateAttr::Template;
                     }
-                    Attr::TemplateChild(_) => {
+                    Attr::TemplateChild(_, replace) => {
                         if template_attr != WidgetTemplateAttr::None {
                             return Err(attr_twice_error(span).into());
                         }
-                        template_attr = WidgetTemplateAttr::TemplateChild;
+                        template_attr = WidgetTemplateAttr::TemplateChild {
+                            replace: replace.is_some(),
+                        };
+                    }
+                    Attr::Group(_, group_name) => {
+                        if group.is_some() {
+                            return Err(attr_twice_error(span).into());
+                        }
+                        group = Some(group_name);
+                    }
+                    Attr::Slot(_) => {
+                        if slot {
+                            return Err(attr_twice_error(span).into());
+                        }
+                        slot = true;
                     }
                     _ => {
                         return Err(Error::new(
                             attr.span(),
-                            "Widgets can only have docs and `local`, `local_ref`, `wrap`, `name`, `template`, `template_child` or `root` as attribute.",
+                            "Widgets can only have docs and `local`, `local_ref`, `wrap`, `name`, `template`, `template_child`, `builder`, `builder_child`, `group`, `slot` or `root` as attribute.",
                         ).into());
                     }
                 }
             }
 
-            Ok((widget_attr, doc_attr, name, assign_wrapper, template_attr))
+            Ok((
+                widget_attr,
+                doc_attr,
+                name,
+                assign_wrapper,
+                template_attr,
+                group,
+                slot,
+            ))
         } else {
-            Ok((WidgetAttr::None, None, None, None, WidgetTemplateAttr::None))
+            Ok((
+                WidgetAttr::None,
+                None,
+                None,
+                None,
+                WidgetTemplateAttr::None,
+                None,
+                false,
+            ))
         }
     }
 
@@ -257,7 +330,7 @@ impl Widget {
         } else {
             Err(Error::new(
                 func.path.span(),
-                "Expected identifier due to the `local`, `local_ref` or `template_child` attribute.",
+                "Expected identifier due to the `local`, `local_ref`, `builder`, `builder_child`, `template_child` or `slot` attribute.",
             )
             .into())
         }
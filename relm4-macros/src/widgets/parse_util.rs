@@ -6,7 +6,7 @@ use syn::spanned::Spanned;
 use syn::{braced, bracketed, parenthesized, Error, Ident, Path};
 
 use super::{ParseError, PropertyName};
-use crate::widgets::{AssignPropertyAttr, WidgetAttr, WidgetFunc};
+use crate::widgets::{AssignPropertyAttr, WidgetAttr, WidgetFunc, WidgetTemplateAttr};
 
 pub(super) fn attr_twice_error(span: Span2) -> Error {
     Error::new(span, "Cannot use the same attribute twice.")
@@ -62,8 +62,18 @@ impl WidgetFunc {
 }
 
 impl WidgetAttr {
+    /// Returns `true` if the widget refers to an existing variable or object by its name.
     pub(super) fn is_local_attr(&self) -> bool {
-        matches!(self, Self::Local | Self::LocalRef)
+        matches!(
+            self,
+            Self::Local | Self::LocalRef | Self::Builder(_) | Self::BuilderChild
+        )
+    }
+}
+
+impl WidgetTemplateAttr {
+    pub(super) fn is_template_child(&self) -> bool {
+        matches!(self, Self::TemplateChild { .. } | Self::Slot)
     }
 }
 
@@ -86,6 +96,21 @@ pub(crate) fn string_to_snake_case(string: &str) -> Ident {
     unique_ident_from_parts([string])
 }
 
+/// Returns the name of the struct that stores the widgets of a group,
+/// for example `HeaderBarWidgets` for the group `header_bar`.
+pub(super) fn group_struct_name(group: &Ident) -> Ident {
+    let mut name = String::new();
+    for part in group.to_string().split('_') {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            name.extend(first.to_uppercase());
+            name.push_str(chars.as_str());
+        }
+    }
+
+    Ident::new(&format!("{name}Widgets"), group.span())
+}
+
 pub(crate) fn unique_ident_from_parts<I, T>(parts: I) -> Ident
 where
     I: IntoIterator<Item = T>,
@@ -11,14 +11,20 @@ impl Attr {
             | Self::LocalRef(ident)
             | Self::Root(ident)
             | Self::Iterate(ident)
-            | Self::Watch(ident, _)
+            | Self::Watch(ident, _, _)
             | Self::Track(ident, _, _)
             | Self::BlockSignal(ident, _)
             | Self::Name(ident, _)
             | Self::Transition(ident, _)
             | Self::Chain(ident, _)
+            | Self::Bind(ident, _)
+            | Self::Expression(ident, _)
             | Self::Template(ident)
-            | Self::TemplateChild(ident)
+            | Self::TemplateChild(ident, _)
+            | Self::Builder(ident, _)
+            | Self::BuilderChild(ident)
+            | Self::Slot(ident)
+            | Self::Group(ident, _)
             | Self::Wrap(ident, _) => ident.span(),
         }
     }
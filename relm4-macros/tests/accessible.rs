@@ -0,0 +1,68 @@
+use gtk::prelude::{ButtonExt, OrientableExt, RangeExt};
+use relm4::{gtk, ComponentParts, ComponentSender, RelmAccessibleExt, SimpleComponent};
+
+#[derive(Default)]
+struct App {
+    volume: f64,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    SetVolume(f64),
+}
+
+#[relm4_macros::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = AppMsg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+
+                #[name = "volume_label"]
+                gtk::Label {
+                    set_label: "Volume",
+                },
+                #[name = "volume_hint"]
+                gtk::Label {
+                    set_label: "Adjusts the volume of all sounds",
+                },
+                gtk::Scale {
+                    set_range: (0.0, 100.0),
+                    set_accessible_labelled_by: &volume_label,
+                    set_accessible_described_by: &volume_hint,
+                    #[watch]
+                    set_accessible_description: &format!("{} percent", model.volume),
+                    connect_value_changed[sender] => move |scale| {
+                        sender.input(AppMsg::SetVolume(scale.value()));
+                    },
+                },
+                gtk::Button {
+                    set_icon_name: "audio-volume-muted",
+                    set_accessible_label: "Mute",
+                },
+            }
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self::default();
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            AppMsg::SetVolume(volume) => self.volume = volume,
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::time::Duration;
+
+use gtk::prelude::{BoxExt, ButtonExt, OrientableExt};
+use relm4::{gtk, ComponentParts, ComponentSender, SimpleComponent};
+
+#[derive(Default)]
+struct App {
+    counter: u8,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    Increment,
+    Decrement,
+}
+
+#[relm4_macros::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = AppMsg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+
+                gtk::Button {
+                    set_label: "Increment",
+                    connect_clicked[sender] => async move |_| {
+                        gtk::glib::timeout_future(Duration::from_millis(100)).await;
+                        sender.input(AppMsg::Increment);
+                    },
+                },
+                gtk::Button {
+                    set_label: "Decrement",
+                    connect_clicked[input = sender.input_sender().clone()] => async move {
+                        input.emit(AppMsg::Decrement);
+                    },
+                },
+            }
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self::default();
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            AppMsg::Increment => self.counter = self.counter.wrapping_add(1),
+            AppMsg::Decrement => self.counter = self.counter.wrapping_sub(1),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use gtk::prelude::{EditableExt, OrientableExt};
+use relm4::{gtk, ComponentParts, ComponentSender, SimpleComponent};
+
+#[derive(Default)]
+struct App {
+    name: String,
+    enabled: bool,
+    volume: f64,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    SetName(String),
+    SetEnabled(bool),
+    SetVolume(f64),
+}
+
+#[relm4_macros::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = AppMsg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+
+                gtk::Entry {
+                    #[bind(AppMsg::SetName)]
+                    set_text: &model.name,
+                },
+                gtk::Switch {
+                    #[bind(AppMsg::SetEnabled)]
+                    set_active: model.enabled,
+                },
+                gtk::SpinButton::with_range(0.0, 100.0, 1.0) {
+                    #[bind(AppMsg::SetVolume)]
+                    set_value: model.volume,
+                },
+            }
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self::default();
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            AppMsg::SetName(name) => self.name = name,
+            AppMsg::SetEnabled(enabled) => self.enabled = enabled,
+            AppMsg::SetVolume(volume) => self.volume = volume,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use gtk::prelude::{ButtonExt, LabelExt};
+use relm4::{gtk, ComponentParts, ComponentSender, RelmConstraintExt, SimpleComponent};
+
+#[derive(Default)]
+struct App;
+
+#[relm4_macros::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = ();
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            gtk::Box {
+                set_layout_manager: Some(gtk::ConstraintLayout::new()),
+
+                #[name = "label"]
+                gtk::Label {
+                    set_label: "Name",
+                },
+                #[name = "button"]
+                gtk::Button {
+                    set_label: "Save",
+                },
+
+                // Constraints are added after the children.
+                add_constraint: relm4_macros::constraint!(label.start == super.start + 6.0),
+                add_constraint: relm4_macros::constraint!(button.start == label.end + 12.0),
+                add_constraint: relm4_macros::constraint!(button.end <= super.end - 6.0, strong),
+                add_constraint: relm4_macros::constraint!(button.width >= 80.0, weak),
+                add_constraint: relm4_macros::constraint!(label.center_y == button.center_y),
+            }
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        root: Self::Root,
+        _sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self;
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+}
@@ -0,0 +1,57 @@
+use gtk::prelude::{GObjectPropertyExpressionExt, OrientableExt, StaticType};
+use relm4::binding::{BoolBinding, StringBinding};
+use relm4::{gtk, ComponentParts, ComponentSender, SimpleComponent};
+
+struct App {
+    title: StringBinding,
+    visible: BoolBinding,
+}
+
+#[relm4_macros::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = ();
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+
+                gtk::Label {
+                    #[expression]
+                    set_label: model.title,
+                    #[expression]
+                    set_visible: model.visible,
+                },
+                gtk::Label {
+                    #[expression(model.title)]
+                    set_tooltip_text: gtk::PropertyExpression::new(
+                        StringBinding::static_type(),
+                        None::<gtk::Expression>,
+                        "value",
+                    ),
+                },
+                gtk::Label {
+                    #[expression]
+                    set_label: model.title.property_expression("value"),
+                },
+            }
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        root: Self::Root,
+        _sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self {
+            title: StringBinding::new("Title"),
+            visible: BoolBinding::new(true),
+        };
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+}
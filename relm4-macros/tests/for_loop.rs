@@ -0,0 +1,62 @@
+use gtk::prelude::{ButtonExt, OrientableExt};
+use relm4::{gtk, ComponentParts, ComponentSender, SimpleComponent};
+
+#[derive(Default)]
+struct App {
+    tags: Vec<String>,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    Add,
+    Remove(usize),
+}
+
+#[relm4_macros::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = AppMsg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+
+                #[watch]
+                for (index, tag) in model.tags.iter().enumerate() {
+                    gtk::Button {
+                        set_label: tag,
+                        connect_clicked => AppMsg::Remove(index),
+                    }
+                },
+
+                gtk::Button {
+                    set_label: "Add",
+                    connect_clicked => AppMsg::Add,
+                },
+            }
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self::default();
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            AppMsg::Add => self.tags.push(format!("Tag {}", self.tags.len())),
+            AppMsg::Remove(index) => {
+                self.tags.remove(index);
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use gtk::prelude::{BoxExt, IsA, OrientableExt};
+use relm4::{gtk, ComponentParts, ComponentSender, SimpleComponent, WidgetTemplate};
+
+#[relm4_macros::widget_template]
+impl<T: IsA<gtk::Widget> + Default> WidgetTemplate for LabeledRow<T> {
+    type Init = (&'static str, i32);
+
+    view! {
+        gtk::Box {
+            set_spacing: init.1,
+
+            gtk::Label {
+                set_label: init.0,
+            },
+
+            #[name = "content"]
+            T {}
+        }
+    }
+}
+
+#[relm4_macros::widget_template]
+impl<const SPACING: i32> WidgetTemplate for Column<SPACING> {
+    view! {
+        gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+            set_spacing: SPACING,
+        }
+    }
+}
+
+#[derive(Default)]
+struct App {
+    enabled: bool,
+}
+
+#[relm4_macros::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = ();
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            #[template]
+            Column::<5> {
+                #[template]
+                LabeledRow::<gtk::Switch>("Enabled", 10) {
+                    #[template_child]
+                    content {
+                        #[watch]
+                        set_active: model.enabled,
+                    }
+                },
+
+                #[template]
+                LabeledRow::<gtk::Entry>("Name", 10) {},
+            }
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        root: Self::Root,
+        _sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self::default();
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, _msg: Self::Input, _sender: ComponentSender<Self>) {
+        self.enabled = !self.enabled;
+    }
+}
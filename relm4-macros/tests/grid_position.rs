@@ -0,0 +1,22 @@
+use relm4::factory::positions::GridPosition;
+use relm4::factory::{DynamicIndex, Position};
+
+#[derive(relm4_macros::GridPosition)]
+#[grid(columns = 4)]
+struct RowMajor;
+
+const ROWS: usize = 2;
+
+#[derive(relm4_macros::GridPosition)]
+#[grid(rows = ROWS)]
+struct ColumnMajor<T> {
+    _value: T,
+}
+
+fn assert_impls<T: Position<GridPosition, DynamicIndex>>() {}
+
+#[test]
+fn grid_position() {
+    assert_impls::<RowMajor>();
+    assert_impls::<ColumnMajor<u8>>();
+}
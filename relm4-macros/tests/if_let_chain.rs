@@ -0,0 +1,55 @@
+use gtk::prelude::OrientableExt;
+use relm4::{gtk, ComponentParts, ComponentSender, SimpleComponent};
+
+#[derive(Default)]
+struct App {
+    name: Option<String>,
+    count: u8,
+}
+
+#[relm4_macros::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = ();
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+
+                if let Some(name) = &model.name && model.count > 0 && !name.is_empty() {
+                    gtk::Label {
+                        #[watch]
+                        set_label: &format!("{name}: {}", model.count),
+                    }
+                } else if let Some(name) = &model.name {
+                    gtk::Label {
+                        #[watch]
+                        set_label: name,
+                    }
+                } else {
+                    gtk::Label {
+                        set_label: "Nobody",
+                    }
+                }
+            }
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        root: Self::Root,
+        _sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self::default();
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, _msg: Self::Input, _sender: ComponentSender<Self>) {
+        self.count = self.count.wrapping_add(1);
+    }
+}
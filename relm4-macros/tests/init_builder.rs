@@ -0,0 +1,37 @@
+use relm4::component::InitBuilder;
+
+#[derive(relm4_macros::InitBuilder)]
+pub struct AppInit {
+    /// The title of the window.
+    title: String,
+    #[init(default = 400)]
+    width: i32,
+    icon: Option<String>,
+}
+
+#[derive(relm4_macros::InitBuilder)]
+struct GenericInit<T: Default> {
+    value: T,
+    #[init(default = vec![1, 2])]
+    items: Vec<u8>,
+}
+
+#[test]
+fn init_builder() {
+    let init: AppInit = AppInit::builder().title("Relm4").into();
+    assert_eq!(init.title, "Relm4");
+    assert_eq!(init.width, 400);
+    assert_eq!(init.icon, None);
+
+    let init = AppInit::builder()
+        .width(200)
+        .icon(String::from("relm4-symbolic"))
+        .build();
+    assert_eq!(init.title, "");
+    assert_eq!(init.width, 200);
+    assert_eq!(init.icon.as_deref(), Some("relm4-symbolic"));
+
+    let init = GenericInit::<u8>::builder().value(5).build();
+    assert_eq!(init.value, 5);
+    assert_eq!(init.items, [1, 2]);
+}
@@ -0,0 +1,54 @@
+use relm4::{gtk, ComponentParts, ComponentSender, SimpleComponent};
+
+#[derive(Debug)]
+enum State {
+    Loading,
+    Loaded { title: String, count: usize },
+    Error(String),
+}
+
+struct App {
+    state: State,
+}
+
+#[relm4_macros::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = State;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            match &model.state {
+                State::Loading => gtk::Spinner {
+                    set_spinning: true,
+                },
+                State::Loaded { title, count } => gtk::Label {
+                    set_label: &format!("{title} ({count})"),
+                },
+                State::Error(msg) => gtk::Label {
+                    set_label: msg,
+                    set_selectable: true,
+                },
+            }
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        root: Self::Root,
+        _sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self {
+            state: State::Loading,
+        };
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, state: Self::Input, _sender: ComponentSender<Self>) {
+        self.state = state;
+    }
+}
@@ -0,0 +1,49 @@
+use gtk::prelude::{ButtonExt, OrientableExt};
+use relm4::{gtk, Component, ComponentParts, ComponentSender, SimpleComponent};
+
+#[derive(Default)]
+struct Page {
+    title: String,
+}
+
+#[relm4_macros::component]
+impl SimpleComponent for Page {
+    type Init = String;
+    type Input = ();
+    type Output = ();
+
+    view! {
+        #[root]
+        title = gtk::Label {
+            #[watch]
+            set_label: &model.title,
+        },
+        #[root]
+        content = gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+
+            gtk::Button {
+                set_label: "Save",
+            },
+        }
+    }
+
+    fn init(
+        title: Self::Init,
+        root: Self::Root,
+        _sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self { title };
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+}
+
+fn assert_root<C: Component<Root = (gtk::Label, gtk::Box)>>() {}
+
+#[test]
+fn multiple_roots() {
+    assert_root::<Page>();
+}
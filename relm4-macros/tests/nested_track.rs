@@ -0,0 +1,81 @@
+use gtk::prelude::{LabelExt, OrientableExt};
+use relm4::{gtk, ComponentParts, ComponentSender, SimpleComponent};
+
+#[derive(Default)]
+struct Settings {
+    dark: bool,
+    font: String,
+}
+
+/// A model that implements the methods generated by the tracker crate by hand.
+#[derive(Default)]
+struct App {
+    settings: Settings,
+    tracker: u8,
+}
+
+impl App {
+    const fn settings() -> u8 {
+        1
+    }
+
+    fn changed(&self, mask: u8) -> bool {
+        self.tracker & mask != 0
+    }
+
+    fn get_mut_settings(&mut self) -> &mut Settings {
+        self.tracker |= Self::settings();
+        &mut self.settings
+    }
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    ToggleDark,
+}
+
+#[relm4_macros::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = AppMsg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+
+                gtk::Label {
+                    #[track(nested = model.settings.dark)]
+                    set_label: if model.settings.dark { "Dark" } else { "Light" },
+                },
+                gtk::Label {
+                    #[track(nested = model.settings.font)]
+                    set_label: &model.settings.font,
+                },
+            }
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        root: Self::Root,
+        _sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self::default();
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        self.tracker = 0;
+        match msg {
+            AppMsg::ToggleDark => {
+                let settings = self.get_mut_settings();
+                settings.dark = !settings.dark;
+            }
+        }
+    }
+}
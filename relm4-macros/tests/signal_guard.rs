@@ -0,0 +1,73 @@
+use gtk::prelude::{CheckButtonExt, EditableExt, OrientableExt, RangeExt};
+use relm4::{gtk, ComponentParts, ComponentSender, SimpleComponent};
+
+#[derive(Default)]
+struct App {
+    text: String,
+    value: f64,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    SetText(String),
+    SetValue(f64),
+}
+
+#[relm4_macros::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = AppMsg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+
+                gtk::Entry {
+                    #[watch]
+                    set_text: &model.text,
+                    connect_changed[sender] => move |entry| {
+                        sender.input(AppMsg::SetText(entry.text().into()));
+                    },
+                },
+                gtk::Scale {
+                    set_range: (0.0, 100.0),
+                    #[watch]
+                    #[block_signal(value_handler)]
+                    set_value: model.value,
+                    connect_value_changed[sender] => move |scale| {
+                        sender.input(AppMsg::SetValue(scale.value()));
+                    } @value_handler,
+                },
+                gtk::CheckButton {
+                    #[watch]
+                    #[block_signal()]
+                    set_active: model.value > 50.0,
+                    connect_toggled[sender] => move |_| {
+                        sender.input(AppMsg::SetValue(0.0));
+                    },
+                },
+            }
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self::default();
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            AppMsg::SetText(text) => self.text = text,
+            AppMsg::SetValue(value) => self.value = value,
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use gtk::prelude::{ButtonExt, GtkWindowExt};
+use relm4::{gtk, ComponentParts, ComponentSender, SimpleComponent};
+
+#[derive(Default, relm4_macros::Tracked)]
+struct App {
+    counter: u8,
+    title: String,
+    #[do_not_track]
+    clicks: usize,
+    tracker: u8,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    Increment,
+}
+
+#[relm4_macros::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = AppMsg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            #[track(model.changed(App::title()))]
+            set_title: Some(&model.title),
+
+            gtk::Button {
+                #[track(model.changed(App::counter()))]
+                set_label: &model.counter.to_string(),
+                connect_clicked => AppMsg::Increment,
+            }
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        root: Self::Root,
+        _sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self::default();
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        self.reset();
+        match msg {
+            AppMsg::Increment => {
+                self.clicks += 1;
+                self.update_counter(|counter| *counter = counter.wrapping_add(1));
+            }
+        }
+    }
+}
+
+#[derive(relm4_macros::Tracked)]
+struct Generic<T: PartialEq> {
+    value: T,
+    #[no_eq]
+    other: T,
+    tracker: u16,
+}
+
+#[test]
+fn tracked() {
+    let mut app = App::default();
+    assert!(!app.changed(App::track_all()));
+
+    app.set_counter(0);
+    assert!(!app.changed(App::counter()));
+
+    app.set_counter(1);
+    assert!(app.changed(App::counter()));
+    assert!(!app.changed(App::title()));
+    assert_eq!(*app.get_counter(), 1);
+
+    app.get_mut_title().push('a');
+    assert!(app.changed(App::title()));
+
+    app.reset();
+    assert!(!app.changed(App::track_all()));
+
+    app.mark_all_changed();
+    assert!(app.changed(App::counter() | App::title()));
+
+    let mut generic = Generic {
+        value: 1,
+        other: 1,
+        tracker: 0,
+    };
+    generic.set_value(1);
+    generic.set_other(1);
+    assert!(!generic.changed(Generic::<i32>::value()));
+    assert!(generic.changed(Generic::<i32>::other()));
+}
@@ -0,0 +1,64 @@
+use gtk::prelude::{ButtonExt, LabelExt, OrientableExt};
+use relm4::{gtk, ComponentParts, ComponentSender, SimpleComponent};
+
+#[derive(Default)]
+struct App {
+    counter: u8,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    Increment,
+}
+
+#[relm4_macros::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = AppMsg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+
+                #[builder = "ui_files/header.ui"]
+                header -> gtk::Box {
+                    #[builder_child]
+                    title -> gtk::Label {
+                        #[watch]
+                        set_label: &format!("Counter: {}", model.counter),
+                    },
+
+                    #[builder_child]
+                    increment_button -> gtk::Button {
+                        connect_clicked => AppMsg::Increment,
+                    },
+
+                    // Regular widgets can be added as well.
+                    gtk::Label {
+                        set_label: "Not part of the UI file",
+                    },
+                },
+            }
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self::default();
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            AppMsg::Increment => self.counter = self.counter.wrapping_add(1),
+        }
+    }
+}
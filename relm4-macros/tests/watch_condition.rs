@@ -0,0 +1,62 @@
+use gtk::prelude::{OrientableExt, TextViewExt};
+use relm4::{gtk, ComponentParts, ComponentSender, SimpleComponent};
+
+#[derive(Default)]
+struct App {
+    buffer: gtk::TextBuffer,
+    buffer_changed: bool,
+    counter: u8,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    NewBuffer,
+    Increment,
+}
+
+#[relm4_macros::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = AppMsg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+
+                gtk::TextView {
+                    #[watch(model.buffer_changed)]
+                    set_buffer: Some(&model.buffer),
+                },
+                gtk::Label {
+                    #[watch(skip_init, model.counter % 2 == 0)]
+                    set_label: &model.counter.to_string(),
+                },
+            }
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        root: Self::Root,
+        _sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self::default();
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        self.buffer_changed = false;
+        match msg {
+            AppMsg::NewBuffer => {
+                self.buffer = gtk::TextBuffer::default();
+                self.buffer_changed = true;
+            }
+            AppMsg::Increment => self.counter = self.counter.wrapping_add(1),
+        }
+    }
+}
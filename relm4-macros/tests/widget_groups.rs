@@ -0,0 +1,56 @@
+use gtk::prelude::{BoxExt, ButtonExt, GtkWindowExt, WidgetExt};
+use relm4::{gtk, ComponentParts, ComponentSender, SimpleComponent};
+
+#[derive(Default)]
+struct App {
+    title: String,
+}
+
+#[relm4_macros::component]
+impl SimpleComponent for App {
+    type Init = String;
+    type Input = ();
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            #[wrap(Some)]
+            #[group(header_bar)]
+            set_titlebar = &gtk::Box {
+                #[name = "title"]
+                gtk::Label {
+                    #[watch]
+                    set_label: &model.title,
+                },
+                #[name = "save_button"]
+                gtk::Button {
+                    set_label: "Save",
+                },
+            },
+        }
+    }
+
+    fn init(
+        title: Self::Init,
+        root: Self::Root,
+        _sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self { title };
+
+        let widgets = view_output!();
+        set_sensitive(&widgets.header_bar, false);
+
+        ComponentParts { model, widgets }
+    }
+}
+
+fn set_sensitive(header_bar: &HeaderBarWidgets, sensitive: bool) {
+    header_bar.save_button.set_sensitive(sensitive);
+}
+
+fn assert_debug_impl<T: std::fmt::Debug>() {}
+
+#[test]
+fn widget_groups() {
+    assert_debug_impl::<HeaderBarWidgets>();
+}
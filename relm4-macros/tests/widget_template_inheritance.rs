@@ -0,0 +1,78 @@
+use gtk::prelude::{BoxExt, ButtonExt, LabelExt, OrientableExt, WidgetExt};
+use relm4::{gtk, ComponentParts, ComponentSender, RelmWidgetExt, SimpleComponent, WidgetTemplate};
+
+#[relm4_macros::widget_template]
+impl WidgetTemplate for BasePage {
+    view! {
+        gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+            set_spacing: 5,
+
+            #[name = "title"]
+            gtk::Label {
+                set_label: "Untitled",
+            },
+
+            #[name = "content"]
+            gtk::Box {},
+        }
+    }
+}
+
+#[relm4_macros::widget_template]
+impl WidgetTemplate for SettingsPage {
+    view! {
+        #[name = "page"]
+        #[template]
+        BasePage {
+            #[template_child]
+            title {
+                set_label: "Settings",
+            },
+
+            #[template_child]
+            content {
+                #[name = "save_button"]
+                gtk::Button {
+                    set_label: "Save",
+                },
+            },
+        }
+    }
+}
+
+#[derive(Default)]
+struct App;
+
+#[relm4_macros::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = ();
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            #[template]
+            SettingsPage {
+                set_margin_all: 5,
+
+                #[template_child]
+                save_button {
+                    set_sensitive: false,
+                },
+            }
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        root: Self::Root,
+        _sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self;
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+}
@@ -0,0 +1,83 @@
+use gtk::prelude::{BoxExt, ButtonExt, OrientableExt};
+use relm4::{gtk, ComponentParts, ComponentSender, SimpleComponent, WidgetTemplate};
+
+#[relm4_macros::widget_template]
+impl WidgetTemplate for Card {
+    view! {
+        gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+
+            #[name = "header"]
+            gtk::Box {
+                gtk::Label {
+                    set_label: "Default header",
+                },
+            },
+
+            #[name = "content"]
+            gtk::Frame {
+                gtk::Label {
+                    set_label: "Default content",
+                },
+            },
+
+            #[name = "footer"]
+            gtk::Box {
+                set_spacing: 5,
+            },
+        }
+    }
+}
+
+#[derive(Default)]
+struct App;
+
+#[relm4_macros::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = ();
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            #[template]
+            Card {
+                // Replace the default header.
+                #[template_child(replace)]
+                header {
+                    gtk::Label {
+                        set_label: "Custom header",
+                    },
+                },
+
+                // Replace the child of a container with a single child.
+                #[template_child(replace)]
+                content {
+                    gtk::Button {
+                        set_label: "Custom content",
+                    },
+                },
+
+                // Extend the footer.
+                #[template_child]
+                footer {
+                    gtk::Button {
+                        set_label: "Ok",
+                    },
+                },
+            }
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        root: Self::Root,
+        _sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self;
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+}
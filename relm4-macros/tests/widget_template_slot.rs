@@ -0,0 +1,73 @@
+use gtk::prelude::{BoxExt, ButtonExt, OrientableExt};
+use relm4::{gtk, ComponentParts, ComponentSender, SimpleComponent, WidgetTemplate};
+
+#[relm4_macros::widget_template]
+impl WidgetTemplate for Card {
+    view! {
+        gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+
+            gtk::Label {
+                set_label: "Card",
+            },
+
+            #[slot]
+            #[name = "content"]
+            gtk::Box {
+                gtk::Label {
+                    set_label: "Default content",
+                },
+            },
+
+            #[slot]
+            actions = gtk::Box {
+                set_spacing: 5,
+            },
+        }
+    }
+}
+
+#[derive(Default)]
+struct App;
+
+#[relm4_macros::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = ();
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            #[template]
+            #[name = "card"]
+            Card {
+                #[slot]
+                content {
+                    gtk::Button {
+                        set_label: "Custom content",
+                    },
+                },
+
+                #[slot]
+                actions {
+                    gtk::Button {
+                        set_label: "Ok",
+                    },
+                },
+            }
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        root: Self::Root,
+        _sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self;
+
+        let widgets = view_output!();
+        let _content: &gtk::Box = widgets.card.content_slot();
+
+        ComponentParts { model, widgets }
+    }
+}
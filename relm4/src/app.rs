@@ -2,9 +2,16 @@ use gtk::glib;
 use gtk::prelude::{ApplicationExt, ApplicationExtManual, Cast, GtkApplicationExt, IsA, WidgetExt};
 use std::fmt::Debug;
 
-use crate::component::{AsyncComponent, AsyncComponentBuilder, AsyncComponentController};
-use crate::runtime_util::shutdown_all;
-use crate::{Component, ComponentBuilder, ComponentController, MessageBroker, RUNTIME};
+use crate::blocking_pool::BLOCKING_POOL;
+use crate::component::{
+    add_global_middleware, AsyncComponent, AsyncComponentBuilder, AsyncComponentController,
+    MessageInfo,
+};
+use crate::runtime_util::{shutdown_all, wait_for_shutdown_hooks};
+use crate::{
+    BlockingPool, Component, ComponentBuilder, ComponentController, MessageBroker, RUNTIME,
+};
+use once_cell::sync::Lazy;
 
 use std::cell::Cell;
 
@@ -63,6 +70,31 @@ impl<M: Debug + 'static> RelmApp<M> {
         self
     }
 
+    /// Installs a middleware that observes the input messages of all components
+    /// and may veto them by returning `false`.
+    ///
+    /// See [`add_global_middleware`] for more information.
+    #[must_use]
+    pub fn with_middleware<F>(self, middleware: F) -> Self
+    where
+        F: Fn(&MessageInfo<'_>) -> bool + Send + Sync + 'static,
+    {
+        add_global_middleware(middleware);
+        self
+    }
+
+    /// Configures the thread pool that runs blocking commands.
+    ///
+    /// This must be called before any component is launched.
+    /// Otherwise, the configuration is ignored and a warning is emitted.
+    #[must_use]
+    pub fn with_blocking_pool(self, pool: BlockingPool) -> Self {
+        if Lazy::get(&RUNTIME).is_some() || BLOCKING_POOL.set(pool).is_err() {
+            tracing::warn!("The blocking pool was already configured or is running");
+        }
+        self
+    }
+
     /// Add command line arguments to run with.
     #[must_use]
     pub fn with_args(mut self, args: Vec<String>) -> Self {
@@ -197,6 +229,7 @@ impl<M: Debug + 'static> RelmApp<M> {
         // Make sure everything is shut down
         shutdown_all();
         glib::MainContext::ref_thread_default().iteration(true);
+        wait_for_shutdown_hooks();
     }
 
     /// Runs the application, returns once the application is closed.
@@ -252,5 +285,6 @@ impl<M: Debug + 'static> RelmApp<M> {
         // Make sure everything is shut down
         shutdown_all();
         glib::MainContext::ref_thread_default().iteration(true);
+        wait_for_shutdown_hooks();
     }
 }
@@ -18,6 +18,7 @@ pub use bindings::*;
 
 use std::ops::{Deref, DerefMut};
 
+use gtk::prelude::{GObjectPropertyExpressionExt, IsExpression};
 use gtk::{glib, prelude::IsA};
 
 /// A trait that allows type-safe bindings between to the primary properties of two objects.
@@ -116,3 +117,39 @@ pub trait Binding: Clone + IsA<glib::Object> {
     /// Set the value of the primary property.
     fn set(&self, value: Self::Target);
 }
+
+/// A trait for values that can be turned into a [`gtk::Expression`].
+///
+/// This is used by the `#[expression]` attribute of the `view!` macro
+/// to bind the properties of widgets to model fields.
+/// For a [`Binding`], the expression evaluates to the primary property.
+pub trait ToExpression {
+    /// Create a [`gtk::Expression`] from the value.
+    fn to_expression(&self) -> gtk::Expression;
+}
+
+impl<B: Binding> ToExpression for B {
+    fn to_expression(&self) -> gtk::Expression {
+        self.property_expression(Self::property_name()).upcast()
+    }
+}
+
+macro_rules! to_expression {
+    ($($ty:ty),+) => {
+        $(
+            impl ToExpression for $ty {
+                fn to_expression(&self) -> gtk::Expression {
+                    self.upcast_ref().clone()
+                }
+            }
+        )+
+    };
+}
+
+to_expression!(
+    gtk::Expression,
+    gtk::ClosureExpression,
+    gtk::ConstantExpression,
+    gtk::ObjectExpression,
+    gtk::PropertyExpression
+);
@@ -0,0 +1,121 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use once_cell::sync::OnceCell;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// The configuration of the blocking pool, set with [`RelmApp::with_blocking_pool`](crate::RelmApp::with_blocking_pool).
+pub(crate) static BLOCKING_POOL: OnceCell<BlockingPool> = OnceCell::new();
+
+/// Decides when blocking commands of a component are started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlockingQueuePolicy {
+    /// All blocking commands share one queue and are started as soon as a thread is available.
+    #[default]
+    Shared,
+    /// Each component runs at most this many blocking commands at the same time.
+    ///
+    /// Additional commands wait until a previous command of the same component finished.
+    /// This prevents a single component from occupying the whole pool.
+    PerComponent(usize),
+}
+
+/// Configures the thread pool that runs blocking commands,
+/// like [`ComponentSender::spawn_command`](crate::ComponentSender::spawn_command)
+/// and [`spawn_blocking`](crate::spawn_blocking).
+///
+/// ```ignore
+/// let app = RelmApp::new("relm4.example")
+///     .with_blocking_pool(
+///         BlockingPool::default()
+///             .max_threads(8)
+///             .thread_name("thumbnailer")
+///             .queue_policy(BlockingQueuePolicy::PerComponent(2)),
+///     );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockingPool {
+    pub(crate) max_threads: Option<usize>,
+    pub(crate) thread_name: Option<String>,
+    pub(crate) keep_alive: Option<Duration>,
+    pub(crate) queue_policy: BlockingQueuePolicy,
+}
+
+impl Default for BlockingPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BlockingPool {
+    /// Creates the default configuration.
+    ///
+    /// By default, the pool uses up to [`RELM_BLOCKING_THREADS`](crate::RELM_BLOCKING_THREADS)
+    /// threads and all components share one queue.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            max_threads: None,
+            thread_name: None,
+            keep_alive: None,
+            queue_policy: BlockingQueuePolicy::Shared,
+        }
+    }
+
+    /// Sets the maximum number of threads of the pool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threads` is zero.
+    #[must_use]
+    pub fn max_threads(mut self, threads: usize) -> Self {
+        assert!(threads > 0, "the blocking pool needs at least one thread");
+        self.max_threads = Some(threads);
+        self
+    }
+
+    /// Sets the name of the threads, which is shown in debuggers and profilers.
+    #[must_use]
+    pub fn thread_name(mut self, name: impl Into<String>) -> Self {
+        self.thread_name = Some(name.into());
+        self
+    }
+
+    /// Sets how long idle threads are kept before they are stopped.
+    #[must_use]
+    pub const fn keep_alive(mut self, duration: Duration) -> Self {
+        self.keep_alive = Some(duration);
+        self
+    }
+
+    /// Sets the queue policy for blocking commands of components.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`BlockingQueuePolicy::PerComponent`] has a limit of zero.
+    #[must_use]
+    pub fn queue_policy(mut self, policy: BlockingQueuePolicy) -> Self {
+        assert!(
+            policy != BlockingQueuePolicy::PerComponent(0),
+            "components need to be able to run at least one blocking command"
+        );
+        self.queue_policy = policy;
+        self
+    }
+}
+
+/// Creates the permits that limit the blocking commands of a new component.
+pub(crate) fn component_permits() -> Option<Arc<Semaphore>> {
+    match BLOCKING_POOL.get()?.queue_policy {
+        BlockingQueuePolicy::Shared => None,
+        BlockingQueuePolicy::PerComponent(limit) => Some(Arc::new(Semaphore::new(limit))),
+    }
+}
+
+/// Waits until the component may start another blocking command.
+pub(crate) async fn acquire_permit(
+    permits: Option<Arc<Semaphore>>,
+) -> Option<OwnedSemaphorePermit> {
+    // The semaphore is never closed.
+    permits?.acquire_owned().await.ok()
+}
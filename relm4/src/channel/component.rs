@@ -3,13 +3,60 @@
 
 //! Contains various flavors of channels to send messages between components and workers.
 
-use std::fmt::Debug;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Debug};
 use std::future::Future;
-use std::sync::Arc;
+use std::mem::{self, Discriminant};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
+use futures::future::{BoxFuture, Either};
+use futures::{Stream, StreamExt};
+use gtk::glib;
+use gtk::prelude::{IsA, ObjectExt};
+
+use tokio::sync::Semaphore;
+
+use crate::blocking_pool::{acquire_permit, component_permits};
 use crate::component::AsyncComponent;
 use crate::factory::{AsyncFactoryComponent, FactoryComponent};
-use crate::{Component, Sender, ShutdownReceiver};
+use crate::{
+    Backoff, Component, JoinHandle, Progress, ProgressSender, Retry, Sender, ShutdownReceiver,
+};
+
+/// Decides what happens to commands started with
+/// [`ComponentSender::command_limited`] once the limit is reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandOverflow {
+    /// Start the command as soon as a previous command with the same key finished.
+    Queue,
+    /// Drop the command.
+    Drop,
+}
+
+/// A command started with [`ComponentSender::command_limited`]
+/// that isn't spawned until a slot of its key is free.
+type LimitedCommand<CommandOutput> =
+    Box<dyn FnOnce(Sender<CommandOutput>) -> BoxFuture<'static, ()> + Send>;
+
+/// The running and queued commands of one key of [`ComponentSender::command_limited`].
+struct CommandLimit<CommandOutput> {
+    limit: usize,
+    running: usize,
+    queue: VecDeque<LimitedCommand<CommandOutput>>,
+}
+
+impl<CommandOutput> Debug for CommandLimit<CommandOutput> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CommandLimit")
+            .field("limit", &self.limit)
+            .field("running", &self.running)
+            .field("queued", &self.queue.len())
+            .finish()
+    }
+}
+
+type CommandLimits<CommandOutput> = Arc<Mutex<HashMap<String, CommandLimit<CommandOutput>>>>;
 
 // Contains senders used by components and factories internally.
 #[derive(Debug)]
@@ -20,11 +67,25 @@ where
 {
     /// Emits component inputs.
     input: Sender<Input>,
+    /// Emits component inputs that are handled before regular inputs.
+    priority_input: Sender<Input>,
     /// Emits component outputs.
     output: Sender<Output>,
+    /// Replaces `output` for factory elements that can be moved to another factory.
+    output_target: Option<Arc<Mutex<Sender<Output>>>>,
     /// Emits command outputs.
     command: Sender<CommandOutput>,
     shutdown: ShutdownReceiver,
+    /// The latest generation of debounced inputs per message variant.
+    debounced: Arc<Mutex<HashMap<Discriminant<Input>, u64>>>,
+    /// Message variants that are currently throttled with their pending trailing message.
+    throttled: Arc<Mutex<HashMap<Discriminant<Input>, Option<Input>>>>,
+    /// Running commands that were started with a key.
+    keyed_commands: Mutex<HashMap<String, JoinHandle<()>>>,
+    /// Limits the blocking commands of the component that run at the same time.
+    blocking_permits: Option<Arc<Semaphore>>,
+    /// Limits the commands with the same key that run at the same time.
+    command_limits: CommandLimits<CommandOutput>,
 }
 
 impl<Input, Output, CommandOutput> ComponentSenderInner<Input, Output, CommandOutput>
@@ -59,6 +120,17 @@ where
         &self.command
     }
 
+    /// Retrieve the sender for priority input messages.
+    #[must_use]
+    fn priority_input_sender(&self) -> &Sender<Input> {
+        &self.priority_input
+    }
+
+    /// Emit an input to the component that is handled before regular inputs.
+    fn priority_input(&self, message: Input) {
+        self.priority_input.send(message).expect("The runtime of the component was shutdown. Maybe you accidentally dropped a controller?");
+    }
+
     /// Emit an input to the component.
     fn input(&self, message: Input) {
         // Input messages should always be safe to send
@@ -66,10 +138,80 @@ where
         self.input.send(message).expect("The runtime of the component was shutdown. Maybe you accidentally dropped a controller?");
     }
 
+    /// Emit an input to the component after `delay` has passed without
+    /// another debounced input of the same variant.
+    fn input_debounced(&self, message: Input, delay: Duration)
+    where
+        Input: 'static,
+    {
+        let key = mem::discriminant(&message);
+        let generation = {
+            let mut debounced = self.debounced.lock().unwrap();
+            let generation = debounced.entry(key).or_default();
+            *generation += 1;
+            *generation
+        };
+
+        let input = self.input.clone();
+        let debounced = self.debounced.clone();
+        let emit = async move {
+            glib::timeout_future(delay).await;
+            // Only the latest message of this variant is emitted.
+            let latest = debounced.lock().unwrap().get(&key) == Some(&generation);
+            if latest {
+                input.send(message).ok();
+            }
+        };
+        crate::spawn_local(self.shutdown.clone().register(emit).drop_on_shutdown());
+    }
+
+    /// Emit an input to the component at most once per `interval`
+    /// for each message variant.
+    fn input_throttled(&self, message: Input, interval: Duration)
+    where
+        Input: 'static,
+    {
+        let key = mem::discriminant(&message);
+        {
+            let mut throttled = self.throttled.lock().unwrap();
+            if let Some(pending) = throttled.get_mut(&key) {
+                // Keep the latest message and emit it at the end of the interval.
+                *pending = Some(message);
+                return;
+            }
+            throttled.insert(key, None);
+        }
+
+        self.input(message);
+
+        let input = self.input.clone();
+        let throttled = self.throttled.clone();
+        let release = async move {
+            loop {
+                glib::timeout_future(interval).await;
+                let mut throttled = throttled.lock().unwrap();
+                if let Some(message) = throttled.get_mut(&key).and_then(Option::take) {
+                    // Emitting the trailing message starts a new interval.
+                    input.send(message).ok();
+                } else {
+                    throttled.remove(&key);
+                    break;
+                }
+            }
+        };
+        crate::spawn_local(self.shutdown.clone().register(release).drop_on_shutdown());
+    }
+
     /// This is not public because factories can unwrap the result
     /// because they keep the output receiver alive internally.
     fn output(&self, message: Output) -> Result<(), Output> {
-        self.output.send(message)
+        if let Some(target) = &self.output_target {
+            // Don't hold the lock while sending.
+            let output = target.lock().unwrap().clone();
+            output.send(message)
+        } else {
+            self.output.send(message)
+        }
     }
 
     /// Spawns an asynchronous command.
@@ -87,6 +229,238 @@ where
         });
     }
 
+    /// Spawns an asynchronous command that will be dropped
+    /// as soon as the component is shut down.
+    fn cancellable_command<Cmd, Fut>(&self, cmd: Cmd)
+    where
+        Cmd: FnOnce(Sender<CommandOutput>) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send,
+    {
+        self.command(move |out, shutdown| shutdown.register(cmd(out)).drop_on_shutdown());
+    }
+
+    /// Spawns a command on the main thread that will be dropped
+    /// as soon as the component is shut down.
+    fn local_command<Cmd, Fut>(&self, cmd: Cmd)
+    where
+        Cmd: FnOnce(Sender<CommandOutput>) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        let future = cmd(self.command.clone());
+        crate::spawn_local(self.shutdown.clone().register(future).drop_on_shutdown());
+    }
+
+    /// Spawns a future on the main thread that will be dropped
+    /// as soon as the component is shut down.
+    fn oneshot_local_command<Fut>(&self, future: Fut)
+    where
+        Fut: Future<Output = CommandOutput> + 'static,
+    {
+        self.local_command(move |out| async move {
+            out.send(future.await).ok();
+        });
+    }
+
+    /// Spawns a cancellable command that can report its progress.
+    fn command_with_progress<Cmd, Fut, F>(&self, map: F, cmd: Cmd)
+    where
+        Cmd: FnOnce(Sender<CommandOutput>, ProgressSender) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send,
+        F: Fn(Progress) -> CommandOutput + Send + Sync + 'static,
+    {
+        let progress = ProgressSender::new(&self.command, map);
+        self.cancellable_command(move |out| cmd(out, progress));
+    }
+
+    /// Runs a fallible operation until it succeeds or no attempts are left.
+    fn command_with_retry<Op, Fut, T, E, F>(&self, backoff: Backoff, mut operation: Op, report: F)
+    where
+        Op: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<T, E>> + Send,
+        F: Fn(Retry<T, E>) -> CommandOutput + Send + 'static,
+    {
+        self.cancellable_command(move |out| async move {
+            let mut attempt = 0;
+            loop {
+                attempt += 1;
+                match operation().await {
+                    Ok(value) => {
+                        out.emit(report(Retry::Succeeded(value)));
+                        return;
+                    }
+                    Err(error) => {
+                        let retry_in = backoff.delay(attempt);
+                        let failure = Retry::Failed {
+                            error,
+                            attempt,
+                            retry_in,
+                        };
+                        if out.send(report(failure)).is_err() {
+                            return;
+                        }
+                        match retry_in {
+                            Some(delay) => tokio::time::sleep(delay).await,
+                            None => return,
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    /// Periodically sends a command output until the component is shut down.
+    fn command_interval<F>(&self, period: Duration, f: F)
+    where
+        F: FnMut() -> CommandOutput + 'static,
+    {
+        // Track the deadlines instead of sleeping for the full period to avoid drift.
+        let mut next = Instant::now();
+        self.command_schedule(
+            move || {
+                next += period;
+                Some(next.saturating_duration_since(Instant::now()))
+            },
+            f,
+        );
+    }
+
+    /// Sends a command output whenever the delay returned by `schedule` has elapsed.
+    fn command_schedule<S, F>(&self, mut schedule: S, mut f: F)
+    where
+        S: FnMut() -> Option<Duration> + 'static,
+        F: FnMut() -> CommandOutput + 'static,
+    {
+        self.local_command(move |out| async move {
+            while let Some(delay) = schedule() {
+                glib::timeout_future(delay).await;
+                if out.send(f()).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    /// Spawns an asynchronous command that cancels the previous command with the same key.
+    fn command_with_key<Cmd, Fut>(&self, key: String, cmd: Cmd)
+    where
+        Cmd: FnOnce(Sender<CommandOutput>) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send,
+    {
+        let sender = self.command.clone();
+        let shutdown = self.shutdown.clone();
+        let handle = crate::spawn(async move {
+            shutdown.register(cmd(sender)).drop_on_shutdown().await;
+        });
+
+        let mut keyed_commands = self.keyed_commands.lock().unwrap();
+        keyed_commands.retain(|_, handle| !handle.is_finished());
+        if let Some(previous) = keyed_commands.insert(key, handle) {
+            previous.abort();
+        }
+    }
+
+    /// Spawns a cancellable command unless too many commands with the same key are running.
+    fn command_limited<Cmd, Fut>(
+        &self,
+        key: String,
+        limit: usize,
+        overflow: CommandOverflow,
+        cmd: Cmd,
+    ) where
+        Cmd: FnOnce(Sender<CommandOutput>) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send,
+    {
+        assert!(
+            limit > 0,
+            "The limit of concurrent commands must not be zero"
+        );
+
+        let cmd: LimitedCommand<CommandOutput> = Box::new(move |out| {
+            Box::pin(async move {
+                cmd(out).await;
+            })
+        });
+        {
+            let mut limits = self.command_limits.lock().unwrap();
+            let entry = limits.entry(key.clone()).or_insert_with(|| CommandLimit {
+                limit,
+                running: 0,
+                queue: VecDeque::new(),
+            });
+
+            if entry.running >= entry.limit {
+                match overflow {
+                    // Queued commands are only spawned once a slot is free.
+                    CommandOverflow::Queue => entry.queue.push_back(cmd),
+                    CommandOverflow::Drop => {
+                        tracing::debug!("Dropped command because the limit was reached");
+                    }
+                }
+                return;
+            }
+            entry.running += 1;
+        }
+
+        spawn_limited(
+            self.command_limits.clone(),
+            key,
+            self.command.clone(),
+            self.shutdown.clone(),
+            cmd,
+        );
+    }
+
+    /// Cancels the command with the given key.
+    fn cancel(&self, key: &str) {
+        if let Some(handle) = self.keyed_commands.lock().unwrap().remove(key) {
+            handle.abort();
+        }
+    }
+
+    /// Forwards the items of a stream as inputs until the stream
+    /// ends or the component is shut down.
+    fn forward_stream<S, F>(&self, stream: S, f: F)
+    where
+        Input: 'static,
+        S: Stream + 'static,
+        F: Fn(S::Item) -> Input + 'static,
+    {
+        let input = self.input.clone();
+        let forward = async move {
+            futures::pin_mut!(stream);
+            while let Some(item) = stream.next().await {
+                if input.send(f(item)).is_err() {
+                    break;
+                }
+            }
+        };
+        crate::spawn_local(self.shutdown.clone().register(forward).drop_on_shutdown());
+    }
+
+    /// Emits an input whenever a property of a GObject changes
+    /// until the component is shut down.
+    fn watch_property<O, F>(&self, object: &O, property: &str, f: F)
+    where
+        Input: 'static,
+        O: IsA<glib::Object>,
+        F: Fn(&O) -> Input + 'static,
+    {
+        let input = self.input.clone();
+        let handler = object.connect_notify_local(Some(property), move |object, _| {
+            input.send(f(object)).ok();
+        });
+
+        // Disconnect the handler at shutdown unless the object was already dropped.
+        let object = object.downgrade();
+        let shutdown = self.shutdown.clone();
+        crate::spawn_local(async move {
+            shutdown.wait().await;
+            if let Some(object) = object.upgrade() {
+                object.disconnect(handler);
+            }
+        });
+    }
+
     /// Spawns a synchronous command.
     ///
     /// This is particularly useful for CPU-intensive background jobs that
@@ -99,7 +473,20 @@ where
         Cmd: FnOnce(Sender<CommandOutput>) + Send + 'static,
     {
         let sender = self.command.clone();
-        crate::spawn_blocking(move || cmd(sender));
+        match self.blocking_permits.clone() {
+            None => {
+                crate::spawn_blocking(move || cmd(sender));
+            }
+            Some(permits) => {
+                crate::spawn(async move {
+                    let permit = acquire_permit(Some(permits)).await;
+                    crate::spawn_blocking(move || {
+                        cmd(sender);
+                        drop(permit);
+                    });
+                });
+            }
+        }
     }
 
     /// Spawns a future that will be dropped as soon as the factory component is shut down.
@@ -124,11 +511,64 @@ where
     where
         Cmd: FnOnce() -> CommandOutput + Send + 'static,
     {
-        let handle = crate::spawn_blocking(cmd);
-        self.oneshot_command(async move { handle.await.unwrap() })
+        if self.blocking_permits.is_none() {
+            let handle = crate::spawn_blocking(cmd);
+            self.oneshot_command(async move { handle.await.unwrap() })
+        } else {
+            let permits = self.blocking_permits.clone();
+            self.oneshot_command(async move {
+                let permit = acquire_permit(permits).await;
+                let handle = crate::spawn_blocking(move || {
+                    let output = cmd();
+                    drop(permit);
+                    output
+                });
+                handle.await.unwrap()
+            })
+        }
     }
 }
 
+/// Runs a command of [`ComponentSender::command_limited`] until it finishes or the
+/// component is shut down and starts the next queued command with the same key afterwards.
+fn spawn_limited<CommandOutput: Send + 'static>(
+    limits: CommandLimits<CommandOutput>,
+    key: String,
+    sender: Sender<CommandOutput>,
+    shutdown: ShutdownReceiver,
+    cmd: LimitedCommand<CommandOutput>,
+) {
+    let future = cmd(sender.clone());
+    let attached = shutdown.clone().register(future);
+    crate::spawn(async move {
+        if let Either::Left(()) = attached.wait().await {
+            // Queued commands are cancelled together with the component.
+            limits.lock().unwrap().remove(&key);
+            return;
+        }
+
+        let next = {
+            let mut limits = limits.lock().unwrap();
+            let Some(entry) = limits.get_mut(&key) else {
+                return;
+            };
+            let next = entry.queue.pop_front();
+            if next.is_none() {
+                entry.running -= 1;
+                if entry.running == 0 {
+                    // Allows starting the key with a different limit again.
+                    limits.remove(&key);
+                }
+            }
+            next
+        };
+
+        if let Some(next) = next {
+            spawn_limited(limits, key, sender, shutdown, next);
+        }
+    });
+}
+
 macro_rules! sender_impl {
     ($name:ident, $trait:ident) => {
         /// Contains senders to send and receive messages from a [`Component`].
@@ -140,6 +580,7 @@ macro_rules! sender_impl {
         impl<C: $trait> $name<C> {
             pub(crate) fn new(
                 input: Sender<C::Input>,
+                priority_input: Sender<C::Input>,
                 output: Sender<C::Output>,
                 command: Sender<C::CommandOutput>,
                 shutdown: ShutdownReceiver,
@@ -147,9 +588,16 @@ macro_rules! sender_impl {
                 Self {
                     shared: Arc::new(ComponentSenderInner {
                         input,
+                        priority_input,
                         output,
+                        output_target: None,
                         command,
                         shutdown,
+                        debounced: Arc::default(),
+                        throttled: Arc::default(),
+                        keyed_commands: Mutex::default(),
+                        blocking_permits: component_permits(),
+                        command_limits: Arc::default(),
                     }),
                 }
             }
@@ -163,6 +611,24 @@ macro_rules! sender_impl {
                 self.shared.input_sender()
             }
 
+            /// Retrieve the sender for priority input messages.
+            ///
+            /// Messages sent with this sender are handled before all pending regular inputs,
+            /// which is useful for urgent messages like "cancel" while the component
+            /// is busy processing a large backlog of messages.
+            /// Factory components handle them like regular inputs.
+            #[must_use]
+            pub fn priority_input_sender(&self) -> &Sender<C::Input> {
+                self.shared.priority_input_sender()
+            }
+
+            /// Emit an input to the component that is handled before all pending regular inputs.
+            ///
+            /// See [`Self::priority_input_sender()`] for more information.
+            pub fn priority_input(&self, message: C::Input) {
+                self.shared.priority_input(message);
+            }
+
             /// Retrieve the sender for output messages.
             ///
             /// Useful to forward outputs from another component. If you just need to send output messages,
@@ -186,6 +652,36 @@ macro_rules! sender_impl {
                 self.shared.input(message);
             }
 
+            /// Emit an input to the component once `delay` has passed
+            /// without another debounced input of the same enum variant.
+            ///
+            /// Earlier messages of the same variant that are still waiting are discarded,
+            /// so only the latest one reaches the update function.
+            /// This is useful for inputs like search text changes that
+            /// should only be handled once the user stopped typing.
+            ///
+            /// Must be called from the main thread.
+            ///
+            /// ```ignore
+            /// sender.input_debounced(Msg::Search(text), Duration::from_millis(300));
+            /// ```
+            pub fn input_debounced(&self, message: C::Input, delay: Duration) {
+                self.shared.input_debounced(message, delay);
+            }
+
+            /// Emit an input to the component at most once per `interval`
+            /// for each enum variant.
+            ///
+            /// The first message is emitted immediately.
+            /// Messages of the same variant that arrive during the interval
+            /// are discarded except for the latest one, which is emitted
+            /// once the interval has passed.
+            ///
+            /// Must be called from the main thread.
+            pub fn input_throttled(&self, message: C::Input, interval: Duration) {
+                self.shared.input_throttled(message, interval);
+            }
+
             /// Emit an output to the component.
             ///
             /// Returns [`Err`] if all receivers were dropped,
@@ -207,6 +703,254 @@ macro_rules! sender_impl {
                 self.shared.command(cmd)
             }
 
+            /// Spawns an asynchronous command that will be dropped as soon as the component
+            /// is shut down, for example when a factory element is removed.
+            ///
+            /// Unlike [`Self::oneshot_command()`], the command can send any number of messages.
+            /// This is a simpler version of [`Self::command()`] for commands that should
+            /// never outlive the component.
+            pub fn cancellable_command<Cmd, Fut>(&self, cmd: Cmd)
+            where
+                Cmd: FnOnce(Sender<C::CommandOutput>) -> Fut + Send + 'static,
+                Fut: Future<Output = ()> + Send,
+            {
+                self.shared.cancellable_command(cmd)
+            }
+
+            /// Spawns a command on the main thread that will be dropped as soon as
+            /// the component is shut down.
+            ///
+            /// Unlike [`Self::cancellable_command()`], the future doesn't need to be [`Send`],
+            /// which allows using the asynchronous APIs of gtk-rs.
+            /// Don't run blocking or CPU-intensive work here, because it would freeze the UI.
+            /// Must be called from the main thread.
+            pub fn local_command<Cmd, Fut>(&self, cmd: Cmd)
+            where
+                Cmd: FnOnce(Sender<C::CommandOutput>) -> Fut + 'static,
+                Fut: Future<Output = ()> + 'static,
+            {
+                self.shared.local_command(cmd)
+            }
+
+            /// Spawns a future on the main thread that will be dropped as soon as
+            /// the component is shut down.
+            /// Its result is sent to the component as command output.
+            ///
+            /// This is the same as [`Self::oneshot_command()`], but the future doesn't need
+            /// to be [`Send`].
+            /// Must be called from the main thread.
+            ///
+            /// ```ignore
+            /// let file = gtk::gio::File::for_path(path);
+            /// sender.oneshot_local_command(async move {
+            ///     CmdOut::Loaded(file.load_contents_future().await)
+            /// });
+            /// ```
+            pub fn oneshot_local_command<Fut>(&self, future: Fut)
+            where
+                Fut: Future<Output = C::CommandOutput> + 'static,
+            {
+                self.shared.oneshot_local_command(future)
+            }
+
+            /// Spawns a command that will be dropped as soon as the component is shut down
+            /// and receives a [`ProgressSender`] to report its progress.
+            ///
+            /// Progress updates are converted into command outputs with `map`.
+            ///
+            /// ```ignore
+            /// sender.command_with_progress(CmdOut::Progress, move |out, progress| async move {
+            ///     for (index, file) in files.iter().enumerate() {
+            ///         progress.update_with_message(index as u64, files.len() as u64, file.name());
+            ///         file.upload().await;
+            ///     }
+            ///     out.emit(CmdOut::Finished);
+            /// });
+            /// ```
+            pub fn command_with_progress<Cmd, Fut, F>(&self, map: F, cmd: Cmd)
+            where
+                Cmd: FnOnce(Sender<C::CommandOutput>, ProgressSender) -> Fut + Send + 'static,
+                Fut: Future<Output = ()> + Send,
+                F: Fn(Progress) -> C::CommandOutput + Send + Sync + 'static,
+            {
+                self.shared.command_with_progress(map, cmd)
+            }
+
+            /// Spawns a command that retries a fallible operation with exponential backoff.
+            ///
+            /// Every failed attempt and the final result are passed to `report`,
+            /// which turns them into command outputs.
+            /// The command is dropped as soon as the component is shut down.
+            ///
+            /// ```ignore
+            /// sender.command_with_retry(
+            ///     Backoff::default().max_attempts(3),
+            ///     move || fetch_feed(url.clone()),
+            ///     |retry| match retry {
+            ///         Retry::Succeeded(feed) => CmdOut::Loaded(feed),
+            ///         Retry::Failed { error, retry_in, .. } => CmdOut::Error(error, retry_in),
+            ///     },
+            /// );
+            /// ```
+            pub fn command_with_retry<Op, Fut, T, E, F>(
+                &self,
+                backoff: Backoff,
+                operation: Op,
+                report: F,
+            ) where
+                Op: FnMut() -> Fut + Send + 'static,
+                Fut: Future<Output = Result<T, E>> + Send,
+                F: Fn(Retry<T, E>) -> C::CommandOutput + Send + 'static,
+            {
+                self.shared.command_with_retry(backoff, operation, report)
+            }
+
+            /// Sends the output of `f` as command output every `period`
+            /// until the component is shut down.
+            ///
+            /// The timer runs on the main loop and is cancelled automatically, so there's
+            /// no need to keep track of a [`glib::SourceId`] for periodic refreshes.
+            /// Must be called from the main thread.
+            ///
+            /// ```ignore
+            /// sender.command_interval(Duration::from_secs(30), || CmdOut::Refresh);
+            /// ```
+            pub fn command_interval<F>(&self, period: Duration, f: F)
+            where
+                F: FnMut() -> C::CommandOutput + 'static,
+            {
+                self.shared.command_interval(period, f)
+            }
+
+            /// Sends the output of `f` as command output on a custom schedule
+            /// until the component is shut down.
+            ///
+            /// `schedule` is called before each tick and returns the delay until the
+            /// tick or [`None`] to stop.
+            /// This allows cron-like timers, for example at the start of every hour.
+            /// Must be called from the main thread.
+            ///
+            /// ```ignore
+            /// sender.command_schedule(
+            ///     || {
+            ///         let now = glib::DateTime::now_local().ok()?;
+            ///         let elapsed = now.minute() * 60 + now.second();
+            ///         Some(Duration::from_secs((3600 - elapsed) as u64))
+            ///     },
+            ///     || CmdOut::HourlyReport,
+            /// );
+            /// ```
+            pub fn command_schedule<S, F>(&self, schedule: S, f: F)
+            where
+                S: FnMut() -> Option<Duration> + 'static,
+                F: FnMut() -> C::CommandOutput + 'static,
+            {
+                self.shared.command_schedule(schedule, f)
+            }
+
+            /// Spawns a command that will be dropped as soon as the component is shut down,
+            /// but runs at most `limit` commands with the same key at the same time.
+            ///
+            /// Depending on `overflow`, additional commands are either queued
+            /// or dropped while the limit is reached.
+            /// Queued commands are only spawned once a running command has finished.
+            /// This is useful when an event can start many commands at once,
+            /// for example fetching images while scrolling.
+            ///
+            /// The limit is shared by all commands with the same key, so while commands
+            /// with this key are running or queued, `limit` is ignored and the limit
+            /// of the command that started them applies.
+            /// A new limit takes effect once all of them have finished.
+            ///
+            /// ```ignore
+            /// sender.command_limited("thumbnail", 4, CommandOverflow::Queue, move |out| async move {
+            ///     let image = fetch(&url).await;
+            ///     out.emit(CmdOut::Thumbnail(url, image));
+            /// });
+            /// ```
+            ///
+            /// # Panics
+            ///
+            /// Panics if `limit` is zero.
+            pub fn command_limited<Cmd, Fut>(
+                &self,
+                key: impl Into<String>,
+                limit: usize,
+                overflow: CommandOverflow,
+                cmd: Cmd,
+            ) where
+                Cmd: FnOnce(Sender<C::CommandOutput>) -> Fut + Send + 'static,
+                Fut: Future<Output = ()> + Send,
+            {
+                self.shared
+                    .command_limited(key.into(), limit, overflow, cmd)
+            }
+
+            /// Spawns an asynchronous command that is identified by a key.
+            ///
+            /// Starting a new command with the same key cancels the previous one,
+            /// so stale commands stop running and don't send new outputs.
+            /// This is useful for type-ahead searches.
+            /// Outputs that a command sent before it was cancelled are still
+            /// handled by the component, so include something like the query
+            /// in the output if stale results need to be ignored.
+            /// Like [`Self::cancellable_command()`], the command is also cancelled
+            /// when the component is shut down.
+            ///
+            /// ```ignore
+            /// sender.command_with_key("search", move |out| async move {
+            ///     let results = search(&query).await;
+            ///     out.send(CmdOut::Results(results)).ok();
+            /// });
+            /// ```
+            pub fn command_with_key<Cmd, Fut>(&self, key: impl Into<String>, cmd: Cmd)
+            where
+                Cmd: FnOnce(Sender<C::CommandOutput>) -> Fut + Send + 'static,
+                Fut: Future<Output = ()> + Send,
+            {
+                self.shared.command_with_key(key.into(), cmd)
+            }
+
+            /// Cancels the command that was started with [`Self::command_with_key()`].
+            ///
+            /// Does nothing if no command with this key is running.
+            pub fn cancel(&self, key: &str) {
+                self.shared.cancel(key);
+            }
+
+            /// Forwards the items of a [`Stream`] as inputs to the component
+            /// until the stream ends or the component is shut down.
+            ///
+            /// This is useful for bridging event sources such as WebSockets
+            /// or file system notifications to the component.
+            /// The stream is polled on the main thread, so it doesn't need to be [`Send`].
+            pub fn forward_stream<S, F>(&self, stream: S, f: F)
+            where
+                S: Stream + 'static,
+                F: Fn(S::Item) -> C::Input + 'static,
+            {
+                self.shared.forward_stream(stream, f)
+            }
+
+            /// Emits an input whenever the `property` of a GObject changes.
+            ///
+            /// The signal handler is disconnected automatically when the component is shut down,
+            /// so neither the object nor the component leak.
+            /// Must be called from the main thread.
+            ///
+            /// ```ignore
+            /// sender.watch_property(&settings, "dark-mode", |settings| {
+            ///     Msg::DarkMode(settings.property("dark-mode"))
+            /// });
+            /// ```
+            pub fn watch_property<O, F>(&self, object: &O, property: &str, f: F)
+            where
+                O: IsA<glib::Object>,
+                F: Fn(&O) -> C::Input + 'static,
+            {
+                self.shared.watch_property(object, property, f)
+            }
+
             /// Spawns a synchronous command.
             ///
             /// This is particularly useful for CPU-intensive background jobs that
@@ -256,3 +1000,284 @@ sender_impl!(ComponentSender, Component);
 sender_impl!(AsyncComponentSender, AsyncComponent);
 sender_impl!(FactorySender, FactoryComponent);
 sender_impl!(AsyncFactorySender, AsyncFactoryComponent);
+
+impl<C: FactoryComponent> FactorySender<C> {
+    /// Creates a sender that emits outputs through the current sender of `output_target`,
+    /// so the element can be moved to another factory.
+    pub(crate) fn with_output_target(
+        input: Sender<C::Input>,
+        priority_input: Sender<C::Input>,
+        output_target: Arc<Mutex<Sender<C::Output>>>,
+        command: Sender<C::CommandOutput>,
+        shutdown: ShutdownReceiver,
+    ) -> Self {
+        let output = output_target.lock().unwrap().clone();
+        let mut sender = Self::new(input, priority_input, output, command, shutdown);
+        Arc::get_mut(&mut sender.shared)
+            .expect("The sender was just created")
+            .output_target = Some(output_target);
+        sender
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use gtk::glib::{self, MainContext};
+
+    use crate::blocking_pool::component_permits;
+    use crate::shutdown::{self, ShutdownSender};
+
+    use super::{CommandOverflow, ComponentSenderInner};
+
+    #[derive(Debug, PartialEq)]
+    enum Msg {
+        First(u8),
+        Second(u8),
+    }
+
+    struct TestSender {
+        inner: ComponentSenderInner<Msg, (), u8>,
+        input: flume::Receiver<Msg>,
+        command: flume::Receiver<u8>,
+        // Keeps the commands running.
+        _shutdown: ShutdownSender,
+    }
+
+    fn sender() -> TestSender {
+        let (input, input_receiver) = crate::channel();
+        let (priority_input, _) = crate::channel();
+        let (output, _) = crate::channel();
+        let (command, command_receiver) = crate::channel();
+        let (shutdown_sender, shutdown) = shutdown::channel();
+
+        TestSender {
+            inner: ComponentSenderInner {
+                input,
+                priority_input,
+                output,
+                output_target: None,
+                command,
+                shutdown,
+                debounced: Default::default(),
+                throttled: Default::default(),
+                keyed_commands: Default::default(),
+                blocking_permits: component_permits(),
+                command_limits: Default::default(),
+            },
+            input: input_receiver.0,
+            command: command_receiver.0,
+            _shutdown: shutdown_sender,
+        }
+    }
+
+    /// Runs the main loop for `duration`.
+    fn run_for(duration: Duration) {
+        MainContext::default().block_on(glib::timeout_future(duration));
+    }
+
+    fn received<T>(receiver: &flume::Receiver<T>) -> Vec<T> {
+        receiver.try_iter().collect()
+    }
+
+    /// Waits for the commands running in the background.
+    fn wait_for_commands() {
+        std::thread::sleep(Duration::from_millis(150));
+    }
+
+    #[gtk::test]
+    fn input_debounced() {
+        let sender = sender();
+        let delay = Duration::from_millis(50);
+
+        sender.inner.input_debounced(Msg::First(1), delay);
+        sender.inner.input_debounced(Msg::Second(2), delay);
+        sender.inner.input_debounced(Msg::First(3), delay);
+        assert!(received(&sender.input).is_empty());
+
+        // Only the latest message of each variant is emitted.
+        run_for(Duration::from_millis(150));
+        assert_eq!(received(&sender.input), [Msg::Second(2), Msg::First(3)]);
+    }
+
+    #[gtk::test]
+    fn input_throttled() {
+        let sender = sender();
+        let interval = Duration::from_millis(50);
+
+        // The first message of each variant is emitted immediately.
+        sender.inner.input_throttled(Msg::First(1), interval);
+        sender.inner.input_throttled(Msg::First(2), interval);
+        sender.inner.input_throttled(Msg::First(3), interval);
+        sender.inner.input_throttled(Msg::Second(4), interval);
+        assert_eq!(received(&sender.input), [Msg::First(1), Msg::Second(4)]);
+
+        // The latest message is emitted once the interval has passed.
+        run_for(Duration::from_millis(75));
+        assert_eq!(received(&sender.input), [Msg::First(3)]);
+
+        // Without further messages, the throttling ends after another interval.
+        run_for(Duration::from_millis(100));
+        assert!(received(&sender.input).is_empty());
+        sender.inner.input_throttled(Msg::First(5), interval);
+        assert_eq!(received(&sender.input), [Msg::First(5)]);
+    }
+
+    #[test]
+    fn command_with_key() {
+        let sender = sender();
+
+        // Starting a command with the same key cancels the previous one.
+        sender
+            .inner
+            .command_with_key("search".into(), |out| async move {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                out.emit(1);
+            });
+        sender
+            .inner
+            .command_with_key("search".into(), |out| async move {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                out.emit(2);
+            });
+
+        // Other keys are not affected.
+        sender
+            .inner
+            .command_with_key("other".into(), |out| async move {
+                out.emit(3);
+            });
+
+        wait_for_commands();
+        let mut outputs = received(&sender.command);
+        outputs.sort_unstable();
+        assert_eq!(outputs, [2, 3]);
+    }
+
+    #[test]
+    fn cancel_command() {
+        let sender = sender();
+
+        sender
+            .inner
+            .command_with_key("search".into(), |out| async move {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                out.emit(1);
+            });
+        sender.inner.cancel("search");
+        // Unknown keys are ignored.
+        sender.inner.cancel("other");
+
+        wait_for_commands();
+        assert!(received(&sender.command).is_empty());
+    }
+
+    /// Starts a limited command that emits `value` once the gate opens.
+    fn limited_command(
+        sender: &TestSender,
+        limit: usize,
+        overflow: CommandOverflow,
+        value: u8,
+        gate: &flume::Receiver<()>,
+        started: &Arc<AtomicUsize>,
+    ) {
+        let gate = gate.clone();
+        let started = started.clone();
+        sender
+            .inner
+            .command_limited("key".into(), limit, overflow, move |out| {
+                started.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    gate.recv_async().await.ok();
+                    out.emit(value);
+                }
+            });
+    }
+
+    #[test]
+    fn command_limited_queue() {
+        let sender = sender();
+        let (gate, gate_receiver) = flume::unbounded();
+        let started = Arc::new(AtomicUsize::new(0));
+
+        for value in 0..4 {
+            limited_command(
+                &sender,
+                2,
+                CommandOverflow::Queue,
+                value,
+                &gate_receiver,
+                &started,
+            );
+        }
+        wait_for_commands();
+        assert_eq!(started.load(Ordering::SeqCst), 2);
+
+        // A finished command starts the next queued one.
+        gate.send(()).unwrap();
+        wait_for_commands();
+        assert_eq!(started.load(Ordering::SeqCst), 3);
+        assert_eq!(received(&sender.command).len(), 1);
+
+        for _ in 0..3 {
+            gate.send(()).unwrap();
+        }
+        wait_for_commands();
+        let mut outputs = received(&sender.command);
+        outputs.sort_unstable();
+        assert_eq!(outputs, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn command_limited_drop() {
+        let sender = sender();
+        let (gate, gate_receiver) = flume::unbounded();
+        let started = Arc::new(AtomicUsize::new(0));
+
+        limited_command(
+            &sender,
+            1,
+            CommandOverflow::Drop,
+            0,
+            &gate_receiver,
+            &started,
+        );
+        limited_command(
+            &sender,
+            1,
+            CommandOverflow::Drop,
+            1,
+            &gate_receiver,
+            &started,
+        );
+        wait_for_commands();
+        assert_eq!(started.load(Ordering::SeqCst), 1);
+
+        gate.send(()).unwrap();
+        wait_for_commands();
+        assert_eq!(received(&sender.command), [0]);
+
+        // A new limit applies once all commands of the key have finished.
+        limited_command(
+            &sender,
+            2,
+            CommandOverflow::Drop,
+            2,
+            &gate_receiver,
+            &started,
+        );
+        limited_command(
+            &sender,
+            2,
+            CommandOverflow::Drop,
+            3,
+            &gate_receiver,
+            &started,
+        );
+        wait_for_commands();
+        assert_eq!(started.load(Ordering::SeqCst), 3);
+    }
+}
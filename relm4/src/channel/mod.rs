@@ -1,8 +1,16 @@
 mod component;
+mod progress;
+mod request;
+mod retry;
 /// Cancellation mechanism used by Relm4.
 pub mod shutdown;
 
-pub use component::{AsyncComponentSender, AsyncFactorySender, ComponentSender, FactorySender};
+pub use component::{
+    AsyncComponentSender, AsyncFactorySender, CommandOverflow, ComponentSender, FactorySender,
+};
+pub use progress::{Progress, ProgressSender};
+pub use request::Responder;
+pub use retry::{Backoff, Retry};
 
 // Copyright 2022 System76 <info@system76.com>
 // SPDX-License-Identifier: MIT or Apache-2.0
@@ -19,6 +27,48 @@ pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
     (Sender(tx), Receiver(rx))
 }
 
+/// Create a bounded channel that holds at most `capacity` messages.
+///
+/// Once the channel is full, [`Sender::send`] blocks until there's capacity again,
+/// [`Sender::send_async`] waits asynchronously and [`Sender::try_send`] returns an error.
+/// Never block the main thread on a channel whose messages are
+/// received on the main thread, because that would cause a deadlock.
+#[must_use]
+pub fn bounded_channel<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    let (tx, rx) = flume::bounded(capacity);
+    (Sender(tx), Receiver(rx))
+}
+
+/// An error returned by [`Sender::try_send`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrySendError<T> {
+    /// The channel is full.
+    Full(T),
+    /// All receivers were dropped.
+    Disconnected(T),
+}
+
+impl<T> TrySendError<T> {
+    /// Returns the message that couldn't be sent.
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Full(message) | Self::Disconnected(message) => message,
+        }
+    }
+}
+
+impl<T> fmt::Display for TrySendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full(_) => f.write_str("sending on a full channel"),
+            Self::Disconnected(_) => f.write_str("sending on a closed channel"),
+        }
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for TrySendError<T> {}
+
 /// A Relm4 sender sends messages to a component or worker.
 pub struct Sender<T>(pub(crate) flume::Sender<T>);
 
@@ -46,6 +96,25 @@ impl<T> Sender<T> {
     pub fn send(&self, message: T) -> Result<(), T> {
         self.0.send(message).map_err(|e| e.into_inner())
     }
+
+    /// Sends a message through the channel without blocking.
+    ///
+    /// Returns [`TrySendError::Full`] if the channel is bounded and full.
+    pub fn try_send(&self, message: T) -> Result<(), TrySendError<T>> {
+        self.0.try_send(message).map_err(|e| match e {
+            flume::TrySendError::Full(message) => TrySendError::Full(message),
+            flume::TrySendError::Disconnected(message) => TrySendError::Disconnected(message),
+        })
+    }
+
+    /// Sends a message through the channel and waits asynchronously
+    /// until there's capacity if the channel is bounded.
+    ///
+    /// If all receivers where dropped, [`Err`] is returned
+    /// with the content of the message.
+    pub async fn send_async(&self, message: T) -> Result<(), T> {
+        self.0.send_async(message).await.map_err(|e| e.into_inner())
+    }
 }
 
 impl<T> Clone for Sender<T> {
@@ -0,0 +1,111 @@
+use std::fmt;
+use std::sync::Arc;
+
+use super::Sender;
+
+/// The progress of a long-running operation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Progress {
+    /// The number of finished steps.
+    pub current: u64,
+    /// The total number of steps or [`None`] if it's unknown.
+    pub total: Option<u64>,
+    /// A description of the current step.
+    pub message: Option<String>,
+}
+
+impl Progress {
+    /// Returns the finished fraction between `0.0` and `1.0`
+    /// or [`None`] if the total is unknown.
+    #[must_use]
+    pub fn fraction(&self) -> Option<f64> {
+        match self.total? {
+            0 => Some(1.0),
+            total => Some((self.current as f64 / total as f64).clamp(0.0, 1.0)),
+        }
+    }
+}
+
+/// Reports the [`Progress`] of a long-running operation, usually from a command.
+///
+/// A [`ProgressSender`] can be created from any [`Sender`], which makes it possible
+/// to report progress to the component running the command or to another
+/// component like the progress bar of `relm4-components`.
+/// See also [`ComponentSender::command_with_progress`](crate::ComponentSender::command_with_progress).
+#[derive(Clone)]
+pub struct ProgressSender {
+    send: Arc<dyn Fn(Progress) + Send + Sync>,
+}
+
+impl fmt::Debug for ProgressSender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProgressSender")
+            .field("send", &"<send fn>")
+            .finish()
+    }
+}
+
+impl From<Sender<Progress>> for ProgressSender {
+    fn from(sender: Sender<Progress>) -> Self {
+        Self::new(&sender, |progress| progress)
+    }
+}
+
+impl ProgressSender {
+    /// Creates a [`ProgressSender`] that converts progress updates
+    /// into messages for the given sender.
+    pub fn new<M, F>(sender: &Sender<M>, map: F) -> Self
+    where
+        M: Send + 'static,
+        F: Fn(Progress) -> M + Send + Sync + 'static,
+    {
+        let sender = sender.clone();
+        Self {
+            send: Arc::new(move |progress| {
+                // The receiver might not care about the progress anymore.
+                sender.send(map(progress)).ok();
+            }),
+        }
+    }
+
+    /// Reports a progress update.
+    pub fn report(&self, progress: Progress) {
+        (self.send)(progress);
+    }
+
+    /// Reports that `current` of `total` steps are finished.
+    pub fn update(&self, current: u64, total: u64) {
+        self.report(Progress {
+            current,
+            total: Some(total),
+            message: None,
+        });
+    }
+
+    /// Reports that `current` of `total` steps are finished together with a description.
+    pub fn update_with_message(&self, current: u64, total: u64, message: impl Into<String>) {
+        self.report(Progress {
+            current,
+            total: Some(total),
+            message: Some(message.into()),
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Progress, ProgressSender};
+
+    #[test]
+    fn progress() {
+        let (sender, receiver) = crate::channel();
+        let progress = ProgressSender::from(sender);
+
+        progress.update_with_message(1, 4, "Downloading");
+        let update = receiver.0.try_recv().unwrap();
+        assert_eq!(update.fraction(), Some(0.25));
+        assert_eq!(update.message.as_deref(), Some("Downloading"));
+
+        assert_eq!(Progress::default().fraction(), None);
+    }
+}
@@ -0,0 +1,95 @@
+use std::fmt;
+
+use tokio::sync::oneshot;
+
+use super::Sender;
+
+/// Answers a request that was sent with [`Sender::request`].
+///
+/// Add a [`Responder`] to an input message and call [`Responder::respond`]
+/// in the update function to send the reply back to the requester.
+///
+/// ```ignore
+/// #[derive(Debug)]
+/// enum ListMsg {
+///     GetSelection(Responder<Option<usize>>),
+/// }
+///
+/// // In the update function of the child.
+/// match message {
+///     ListMsg::GetSelection(responder) => responder.respond(self.selection),
+/// }
+///
+/// // In the parent.
+/// let selection = list.sender().request(ListMsg::GetSelection).await;
+/// ```
+pub struct Responder<R> {
+    sender: oneshot::Sender<R>,
+}
+
+impl<R> fmt::Debug for Responder<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Responder")
+            .field("closed", &self.sender.is_closed())
+            .finish()
+    }
+}
+
+impl<R> Responder<R> {
+    /// Sends the reply to the requester.
+    ///
+    /// The reply is discarded if the requester stopped waiting.
+    pub fn respond(self, reply: R) {
+        if self.sender.send(reply).is_err() {
+            tracing::debug!("Requester stopped waiting for a reply");
+        }
+    }
+
+    /// Returns `true` if the requester stopped waiting for the reply.
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+}
+
+impl<T> Sender<T> {
+    /// Sends a request and waits for the reply.
+    ///
+    /// `message` creates the message that carries the [`Responder`],
+    /// which is usually the variant of an enum.
+    ///
+    /// Returns [`None`] if the receiver was dropped or
+    /// if the responder was dropped without replying.
+    pub async fn request<R, F>(&self, message: F) -> Option<R>
+    where
+        F: FnOnce(Responder<R>) -> T,
+    {
+        let (sender, receiver) = oneshot::channel();
+        self.send(message(Responder { sender })).ok()?;
+        receiver.await.ok()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Responder;
+
+    enum Msg {
+        Double(u8, Responder<u8>),
+    }
+
+    #[test]
+    fn request() {
+        let (sender, receiver) = crate::channel::<Msg>();
+
+        std::thread::spawn(move || {
+            while let Some(Msg::Double(value, responder)) = receiver.recv_sync() {
+                responder.respond(value * 2);
+            }
+        });
+
+        let reply =
+            futures::executor::block_on(sender.request(|responder| Msg::Double(21, responder)));
+        assert_eq!(reply, Some(42));
+    }
+}
@@ -0,0 +1,150 @@
+use std::time::Duration;
+
+/// Configures how often and how fast a failed command is retried.
+///
+/// The delay before the first retry is [`Backoff::initial_delay`]
+/// and is multiplied by [`Backoff::factor`] after every failed attempt,
+/// but never exceeds [`Backoff::max_delay`].
+/// Used by [`ComponentSender::command_with_retry`](crate::ComponentSender::command_with_retry).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Backoff {
+    initial_delay: Duration,
+    factor: f64,
+    max_delay: Duration,
+    max_attempts: u32,
+}
+
+impl Default for Backoff {
+    /// Makes up to 5 attempts, starting with a delay of 500 milliseconds
+    /// that doubles after each failure.
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(500),
+            factor: 2.0,
+            max_delay: Duration::from_secs(30),
+            max_attempts: 5,
+        }
+    }
+}
+
+impl Backoff {
+    /// Creates the default backoff with a custom delay before the first retry.
+    #[must_use]
+    pub fn new(initial_delay: Duration) -> Self {
+        Self {
+            initial_delay,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the delay before the first retry.
+    #[must_use]
+    pub const fn initial_delay(mut self, delay: Duration) -> Self {
+        self.initial_delay = delay;
+        self
+    }
+
+    /// Sets the factor the delay is multiplied with after every failed attempt.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is smaller than `1.0` or not finite.
+    #[must_use]
+    pub fn factor(mut self, factor: f64) -> Self {
+        assert!(
+            factor >= 1.0 && factor.is_finite(),
+            "The backoff factor must be a finite number of at least 1.0"
+        );
+        self.factor = factor;
+        self
+    }
+
+    /// Sets the upper limit of the delay between two attempts.
+    #[must_use]
+    pub const fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Sets the number of attempts, including the first one,
+    /// before giving up.
+    #[must_use]
+    pub const fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts;
+        self
+    }
+
+    /// Returns the delay after the given failed attempt, starting at 1,
+    /// or [`None`] if no attempts are left.
+    #[must_use]
+    pub fn delay(&self, attempt: u32) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        let exponent = i32::try_from(attempt.saturating_sub(1)).unwrap_or(i32::MAX);
+        let delay = self.initial_delay.as_secs_f64() * self.factor.powi(exponent);
+        Some(Duration::from_secs_f64(
+            delay.min(self.max_delay.as_secs_f64()),
+        ))
+    }
+}
+
+/// Reports the progress of a command started with
+/// [`ComponentSender::command_with_retry`](crate::ComponentSender::command_with_retry).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Retry<T, E> {
+    /// The operation succeeded.
+    Succeeded(T),
+    /// An attempt failed.
+    Failed {
+        /// The error of the attempt.
+        error: E,
+        /// The number of the failed attempt, starting at 1.
+        attempt: u32,
+        /// The delay until the next attempt or [`None`] if the command gave up.
+        retry_in: Option<Duration>,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::Backoff;
+
+    #[test]
+    fn backoff() {
+        let backoff = Backoff::new(Duration::from_secs(1))
+            .max_delay(Duration::from_secs(3))
+            .max_attempts(4);
+
+        assert_eq!(backoff.delay(1), Some(Duration::from_secs(1)));
+        assert_eq!(backoff.delay(2), Some(Duration::from_secs(2)));
+        assert_eq!(backoff.delay(3), Some(Duration::from_secs(3)));
+        assert_eq!(backoff.delay(4), None);
+    }
+
+    #[test]
+    fn backoff_large_factor() {
+        let backoff = Backoff::new(Duration::from_secs(1))
+            .factor(f64::MAX)
+            .max_delay(Duration::from_secs(3))
+            .max_attempts(u32::MAX);
+
+        assert_eq!(backoff.delay(1), Some(Duration::from_secs(1)));
+        assert_eq!(backoff.delay(1000), Some(Duration::from_secs(3)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn backoff_negative_factor() {
+        let _ = Backoff::default().factor(-2.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn backoff_nan_factor() {
+        let _ = Backoff::default().factor(f64::NAN);
+    }
+}
@@ -5,14 +5,19 @@
 use super::super::MessageBroker;
 use super::{AsyncComponent, AsyncComponentParts, AsyncConnector};
 use crate::channel::AsyncComponentSender;
+use crate::component::middleware::MiddlewareChain;
+use crate::component::{InitBuilder, Middleware};
+use crate::runtime_util::{run_shutdown_hook, schedule_post_init};
 use crate::{
     late_initialization, GuardedReceiver, Receiver, RelmContainerExt, RelmWidgetExt,
     RuntimeSenders, Sender,
 };
+use futures::future::{Fuse, FutureExt};
 use gtk::glib;
 use gtk::prelude::{GtkWindowExt, NativeDialogExt};
 use std::any;
 use std::marker::PhantomData;
+use std::time::Duration;
 use tracing::info_span;
 
 /// A component that is ready for docking and launch.
@@ -21,6 +26,8 @@ pub struct AsyncComponentBuilder<C: AsyncComponent> {
     /// The root widget of the component.
     pub root: C::Root,
     priority: glib::Priority,
+    init_timeout: Option<Duration>,
+    middleware: MiddlewareChain<C::Input>,
 
     pub(super) component: PhantomData<C>,
 }
@@ -31,6 +38,8 @@ impl<C: AsyncComponent> Default for AsyncComponentBuilder<C> {
         Self {
             root: C::init_root(),
             priority: glib::Priority::default(),
+            init_timeout: None,
+            middleware: MiddlewareChain::default(),
             component: PhantomData,
         }
     }
@@ -60,6 +69,28 @@ impl<C: AsyncComponent> AsyncComponentBuilder<C> {
         self.priority = priority;
         self
     }
+
+    /// Cancel the [`init()`](AsyncComponent::init) future if it
+    /// doesn't complete within `timeout`.
+    ///
+    /// Once the timeout is exceeded, the future is dropped, the loading widgets are removed and
+    /// [`init_timed_out()`](AsyncComponent::init_timed_out) is called to show
+    /// fallback widgets and notify the parent component.
+    /// The component doesn't process any messages afterwards.
+    #[must_use]
+    pub fn init_timeout(mut self, timeout: Duration) -> Self {
+        self.init_timeout = Some(timeout);
+        self
+    }
+
+    /// Install a [`Middleware`] that intercepts input messages before they reach the component.
+    ///
+    /// Multiple middlewares run in the order they were installed.
+    #[must_use]
+    pub fn middleware<M: Middleware<C::Input>>(mut self, middleware: M) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
 }
 
 impl<C: AsyncComponent> AsyncComponentBuilder<C>
@@ -141,6 +172,16 @@ impl<C: AsyncComponent> AsyncComponentBuilder<C> {
         self.launch_with_input_channel(payload, input_sender, input_receiver)
     }
 
+    /// Similar to [`launch()`](AsyncComponentBuilder::launch) but creates the init value
+    /// with a builder that is configured by `init`.
+    pub fn launch_with<F>(self, init: F) -> AsyncConnector<C>
+    where
+        C::Init: InitBuilder,
+        F: FnOnce(<C::Init as InitBuilder>::Builder) -> <C::Init as InitBuilder>::Builder,
+    {
+        self.launch(init(C::Init::builder()).into())
+    }
+
     /// Similar to [`launch()`](AsyncComponentBuilder::launch) but also initializes a [`MessageBroker`].
     ///
     /// # Panics
@@ -165,7 +206,13 @@ impl<C: AsyncComponent> AsyncComponentBuilder<C> {
         input_sender: Sender<C::Input>,
         input_receiver: Receiver<C::Input>,
     ) -> AsyncConnector<C> {
-        let Self { root, priority, .. } = self;
+        let Self {
+            root,
+            priority,
+            init_timeout,
+            middleware,
+            ..
+        } = self;
         let temp_widgets = C::init_loading_widgets(root.clone());
 
         let RuntimeSenders {
@@ -179,9 +226,13 @@ impl<C: AsyncComponent> AsyncComponentBuilder<C> {
             mut shutdown_event,
         } = RuntimeSenders::<C::Output, C::CommandOutput>::new();
 
+        // Inputs that are handled before regular inputs.
+        let (priority_sender, priority_receiver) = crate::channel::<C::Input>();
+
         // Encapsulates the senders used by component methods.
         let component_sender = AsyncComponentSender::new(
             input_sender.clone(),
+            priority_sender,
             output_sender.clone(),
             cmd_sender,
             shutdown_recipient,
@@ -193,13 +244,75 @@ impl<C: AsyncComponent> AsyncComponentBuilder<C> {
         // `Self::CommandOutput` messages. It will spawn commands as requested by
         // updates, and send `Self::Output` messages externally.
         crate::spawn_local_with_priority(priority, async move {
-            let mut state = C::init(payload, rt_root.clone(), component_sender.clone()).await;
+            let init = C::init(payload, rt_root.clone(), component_sender.clone()).fuse();
+            let timeout = async move {
+                if let Some(timeout) = init_timeout {
+                    glib::timeout_future(timeout).await;
+                } else {
+                    futures::future::pending::<()>().await;
+                }
+            }
+            .fuse();
+            futures::pin_mut!(init, timeout);
+
+            let mut state = futures::select!(
+                state = init => state,
+
+                // Cancel the initialization, replace the loading widgets with
+                // the fallback widgets and keep them until the component is destroyed.
+                _ = timeout => {
+                    init.set(Fuse::terminated());
+                    drop(temp_widgets);
+                    let fallback_widgets = C::init_timed_out(rt_root.clone(), component_sender.clone());
+                    (&mut shutdown_event).await;
+                    drop(fallback_widgets);
+                    shutdown_notifier.shutdown();
+                    return;
+                }
+
+                // Don't wait for the initialization if the component was already destroyed.
+                _ = shutdown_event => {
+                    init.set(Fuse::terminated());
+                    drop(temp_widgets);
+                    C::init_cancelled(rt_root.clone(), component_sender.clone());
+                    shutdown_notifier.shutdown();
+                    return;
+                }
+            );
             drop(temp_widgets);
 
+            // Notifies the runtime once the root widget is mapped.
+            let (post_init_sender, post_init_receiver) = crate::channel::<()>();
+            schedule_post_init(C::post_init_widget(&rt_root), post_init_sender);
+
+            let pending_priority = priority_receiver.0.clone();
             let mut cmd = GuardedReceiver::new(cmd_receiver);
             let mut input = GuardedReceiver::new(input_receiver);
+            let mut priority_input = GuardedReceiver::new(priority_receiver);
+            let mut post_init = GuardedReceiver::new(post_init_receiver);
 
             loop {
+                // Handle all pending priority inputs before anything else.
+                while let Ok(message) = pending_priority.try_recv() {
+                    let AsyncComponentParts { model, widgets } = &mut state;
+
+                    let Some(message) = middleware.process(any::type_name::<C>(), message) else {
+                        continue;
+                    };
+
+                    let span = info_span!(
+                        "update_with_view",
+                        input=?message,
+                        component=any::type_name::<C>(),
+                        id=model.id(),
+                    );
+                    let _enter = span.enter();
+
+                    model
+                        .update_with_view(widgets, message, component_sender.clone(), &rt_root)
+                        .await;
+                }
+
                 futures::select!(
                     // Performs the model update, checking if the update requested a command.
                     // Runs that command asynchronously in the background using tokio.
@@ -209,6 +322,31 @@ impl<C: AsyncComponent> AsyncComponentBuilder<C> {
                             widgets,
                         } = &mut state;
 
+                        let Some(message) = middleware.process(any::type_name::<C>(), message) else {
+                            continue;
+                        };
+
+                        let span = info_span!(
+                            "update_with_view",
+                            input=?message,
+                            component=any::type_name::<C>(),
+                            id=model.id(),
+                        );
+                        let _enter = span.enter();
+
+                        model.update_with_view(widgets, message, component_sender.clone(), &rt_root).await;
+                    }
+
+                    message = priority_input => {
+                        let AsyncComponentParts {
+                            model,
+                            widgets,
+                        } = &mut state;
+
+                        let Some(message) = middleware.process(any::type_name::<C>(), message) else {
+                            continue;
+                        };
+
                         let span = info_span!(
                             "update_with_view",
                             input=?message,
@@ -238,6 +376,16 @@ impl<C: AsyncComponent> AsyncComponentBuilder<C> {
                         model.update_cmd_with_view(widgets, message, component_sender.clone(), &rt_root).await;
                     }
 
+                    // Runs the deferred initialization.
+                    _ = post_init => {
+                        let AsyncComponentParts {
+                            model,
+                            widgets,
+                        } = &mut state;
+
+                        model.post_init(widgets, component_sender.clone(), &rt_root).await;
+                    }
+
                     // Triggered when the component is destroyed
                     _ = shutdown_event => {
                         let AsyncComponentParts {
@@ -245,7 +393,12 @@ impl<C: AsyncComponent> AsyncComponentBuilder<C> {
                             widgets,
                         } = &mut state;
 
-                        model.shutdown(widgets, output_sender);
+                        model.shutdown(widgets, output_sender.clone());
+                        run_shutdown_hook(
+                            model.shutdown_async(widgets, output_sender),
+                            C::shutdown_deadline(),
+                            any::type_name::<C>(),
+                        ).await;
 
                         shutdown_notifier.shutdown();
 
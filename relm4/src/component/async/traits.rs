@@ -6,9 +6,11 @@
 #![allow(unused_qualifications)]
 
 use std::fmt::Debug;
+use std::time::Duration;
 
 use crate::channel::{AsyncComponentSender, Sender};
 use crate::loading_widgets::LoadingWidgets;
+use crate::runtime_util::DEFAULT_SHUTDOWN_DEADLINE;
 
 use super::{AsyncComponentBuilder, AsyncComponentParts};
 
@@ -62,6 +64,34 @@ pub trait AsyncComponent: Sized + 'static {
         sender: AsyncComponentSender<Self>,
     ) -> impl std::future::Future<Output = AsyncComponentParts<Self>>;
 
+    /// Called when the [`init()`](AsyncComponent::init) future was cancelled
+    /// because it exceeded the timeout set with
+    /// [`AsyncComponentBuilder::init_timeout`].
+    ///
+    /// The returned widgets are shown instead of the loading widgets
+    /// until the component is destroyed.
+    /// Use the sender to notify the parent component, for example to retry.
+    ///
+    /// This method does nothing by default.
+    #[allow(unused)]
+    #[must_use]
+    fn init_timed_out(
+        root: Self::Root,
+        sender: AsyncComponentSender<Self>,
+    ) -> Option<LoadingWidgets> {
+        None
+    }
+
+    /// Called when the component was destroyed before the
+    /// [`init()`](AsyncComponent::init) future completed.
+    ///
+    /// The future was already dropped at this point, so this is the
+    /// place to clean up resources that were handed to it.
+    ///
+    /// This method does nothing by default.
+    #[allow(unused)]
+    fn init_cancelled(root: Self::Root, sender: AsyncComponentSender<Self>) {}
+
     /// Processes inputs received by the component.
     #[allow(unused)]
     fn update(
@@ -140,12 +170,65 @@ pub trait AsyncComponent: Sized + 'static {
         }
     }
 
+    /// Deferred initialization that runs once after [`init`](Self::init).
+    ///
+    /// This method is called once the widget returned by
+    /// [`post_init_widget`](Self::post_init_widget) is mapped.
+    /// See [`Component::post_init`](crate::Component::post_init) for more information.
+    ///
+    /// This method does nothing by default.
+    #[allow(unused)]
+    fn post_init(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        sender: AsyncComponentSender<Self>,
+        root: &Self::Root,
+    ) -> impl std::future::Future<Output = ()> {
+        async {}
+    }
+
+    /// The widget whose `map` signal triggers [`post_init`](Self::post_init).
+    ///
+    /// See [`Component::post_init_widget`](crate::Component::post_init_widget)
+    /// for more information.
+    #[allow(unused)]
+    #[must_use]
+    fn post_init_widget(root: &Self::Root) -> Option<gtk::Widget> {
+        None
+    }
+
     /// Last method called before a component is shut down.
     ///
     /// This method is guaranteed to be called even when the entire application is shut down.
     #[allow(unused)]
     fn shutdown(&mut self, widgets: &mut Self::Widgets, output: Sender<Self::Output>) {}
 
+    /// Asynchronous cleanup that runs after [`shutdown`](Self::shutdown).
+    ///
+    /// The runtime awaits the returned future before the component is destroyed,
+    /// which is useful to flush network writes or close files.
+    /// Once the application quits, Relm4 waits for all pending shutdown futures.
+    /// Futures that take longer than [`shutdown_deadline`](Self::shutdown_deadline)
+    /// are cancelled.
+    ///
+    /// This method does nothing by default.
+    #[allow(unused)]
+    fn shutdown_async(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        output: Sender<Self::Output>,
+    ) -> impl std::future::Future<Output = ()> {
+        async {}
+    }
+
+    /// The maximum time the runtime waits for [`shutdown_async`](Self::shutdown_async).
+    ///
+    /// Defaults to five seconds.
+    #[must_use]
+    fn shutdown_deadline() -> Duration {
+        DEFAULT_SHUTDOWN_DEADLINE
+    }
+
     /// An identifier for the component used for debug logging.
     ///
     /// The default implementation of this method uses the address of the component, but
@@ -157,6 +240,45 @@ pub trait AsyncComponent: Sized + 'static {
 }
 
 /// Asynchronous variant of [`SimpleComponent`](crate::SimpleComponent).
+///
+/// Only [`init_root`](Self::init_root) and [`init`](Self::init) are required.
+/// All other methods, such as [`update`](Self::update)
+/// and [`update_view`](Self::update_view), default to doing nothing.
+/// The command output is `()`, so commands can be started from the sender
+/// for their side effects, but their results can't be handled by the component.
+/// All types that implement [`SimpleAsyncComponent`] implement [`AsyncComponent`] automatically.
+///
+/// ```ignore
+/// struct Counter(u8);
+///
+/// impl SimpleAsyncComponent for Counter {
+///     type Init = u8;
+///     type Input = ();
+///     type Output = ();
+///     type Root = gtk::Label;
+///     type Widgets = gtk::Label;
+///
+///     fn init_root() -> Self::Root {
+///         gtk::Label::default()
+///     }
+///
+///     async fn init(
+///         init: Self::Init,
+///         root: Self::Root,
+///         _sender: AsyncComponentSender<Self>,
+///     ) -> AsyncComponentParts<Self> {
+///         AsyncComponentParts { model: Self(init), widgets: root }
+///     }
+///
+///     async fn update(&mut self, _message: (), _sender: AsyncComponentSender<Self>) {
+///         self.0 = self.0.wrapping_add(1);
+///     }
+///
+///     fn update_view(&self, label: &mut Self::Widgets, _sender: AsyncComponentSender<Self>) {
+///         label.set_label(&self.0.to_string());
+///     }
+/// }
+/// ```
 pub trait SimpleAsyncComponent: Sized + 'static {
     /// The message type that the component accepts as inputs.
     type Input: Debug + 'static;
@@ -194,6 +316,26 @@ pub trait SimpleAsyncComponent: Sized + 'static {
         sender: AsyncComponentSender<Self>,
     ) -> impl std::future::Future<Output = AsyncComponentParts<Self>>;
 
+    /// Called when the [`init()`](AsyncComponent::init) future was cancelled
+    /// because it exceeded its timeout.
+    ///
+    /// See [`AsyncComponent::init_timed_out`] for more information.
+    #[allow(unused)]
+    #[must_use]
+    fn init_timed_out(
+        root: Self::Root,
+        sender: AsyncComponentSender<Self>,
+    ) -> Option<LoadingWidgets> {
+        None
+    }
+
+    /// Called when the component was destroyed before the
+    /// [`init()`](SimpleAsyncComponent::init) future completed.
+    ///
+    /// See [`AsyncComponent::init_cancelled`] for more information.
+    #[allow(unused)]
+    fn init_cancelled(root: Self::Root, sender: AsyncComponentSender<Self>) {}
+
     /// Processes inputs received by the component.
     #[allow(unused)]
     fn update(
@@ -218,11 +360,52 @@ pub trait SimpleAsyncComponent: Sized + 'static {
     #[allow(unused)]
     fn update_view(&self, widgets: &mut Self::Widgets, sender: AsyncComponentSender<Self>) {}
 
+    /// Deferred initialization that runs once after [`init`](Self::init).
+    ///
+    /// See [`AsyncComponent::post_init`] for more information.
+    #[allow(unused)]
+    fn post_init(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        sender: AsyncComponentSender<Self>,
+    ) -> impl std::future::Future<Output = ()> {
+        async {}
+    }
+
+    /// The widget whose `map` signal triggers [`post_init`](Self::post_init).
+    ///
+    /// See [`AsyncComponent::post_init_widget`] for more information.
+    #[allow(unused)]
+    #[must_use]
+    fn post_init_widget(root: &Self::Root) -> Option<gtk::Widget> {
+        None
+    }
+
     /// Last method called before a component is shut down.
     ///
     /// This method is guaranteed to be called even when the entire application is shut down.
     #[allow(unused)]
     fn shutdown(&mut self, widgets: &mut Self::Widgets, output: Sender<Self::Output>) {}
+
+    /// Asynchronous cleanup that runs after [`shutdown`](Self::shutdown).
+    ///
+    /// See [`AsyncComponent::shutdown_async`] for more information.
+    #[allow(unused)]
+    fn shutdown_async(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        output: Sender<Self::Output>,
+    ) -> impl std::future::Future<Output = ()> {
+        async {}
+    }
+
+    /// The maximum time the runtime waits for [`shutdown_async`](Self::shutdown_async).
+    ///
+    /// Defaults to five seconds.
+    #[must_use]
+    fn shutdown_deadline() -> Duration {
+        DEFAULT_SHUTDOWN_DEADLINE
+    }
 }
 
 impl<C> AsyncComponent for C
@@ -253,6 +436,17 @@ where
         C::init(init, root, sender).await
     }
 
+    fn init_timed_out(
+        root: Self::Root,
+        sender: AsyncComponentSender<Self>,
+    ) -> Option<LoadingWidgets> {
+        C::init_timed_out(root, sender)
+    }
+
+    fn init_cancelled(root: Self::Root, sender: AsyncComponentSender<Self>) {
+        C::init_cancelled(root, sender);
+    }
+
     async fn update(
         &mut self,
         message: Self::Input,
@@ -266,9 +460,34 @@ where
         C::update_view(self, widgets, sender);
     }
 
+    async fn post_init(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        sender: AsyncComponentSender<Self>,
+        _root: &Self::Root,
+    ) {
+        C::post_init(self, widgets, sender).await;
+    }
+
+    fn post_init_widget(root: &Self::Root) -> Option<gtk::Widget> {
+        C::post_init_widget(root)
+    }
+
     fn shutdown(&mut self, widgets: &mut Self::Widgets, output: Sender<Self::Output>) {
         self.shutdown(widgets, output);
     }
+
+    fn shutdown_async(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        output: Sender<Self::Output>,
+    ) -> impl std::future::Future<Output = ()> {
+        C::shutdown_async(self, widgets, output)
+    }
+
+    fn shutdown_deadline() -> Duration {
+        C::shutdown_deadline()
+    }
 }
 
 /// An empty, non-interactive component as a placeholder for tests.
@@ -0,0 +1,239 @@
+use std::fmt::{self, Debug};
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::{Component, ComponentSender};
+
+/// A side effect that is returned from a pure update function and executed afterwards.
+///
+/// Implement this trait for an enum of all side effects of a component,
+/// like navigating or playing a sound, and return [`Effects`] from a
+/// pure function instead of executing them directly in `update`.
+/// This allows unit tests to assert on the returned effects.
+///
+/// The runtime doesn't execute effects on its own and the signature of
+/// `update` doesn't change. Instead, `update` calls the pure function
+/// and runs the returned effects with [`Effects::run()`].
+///
+/// ```ignore
+/// #[derive(Debug, PartialEq)]
+/// enum AppEffect {
+///     Navigate(Page),
+///     PlaySound(Sound),
+/// }
+///
+/// impl Effect<App> for AppEffect {
+///     fn run(self, sender: &ComponentSender<App>) {
+///         match self {
+///             Self::Navigate(page) => sender.output(AppOutput::Navigate(page)).unwrap(),
+///             Self::PlaySound(sound) => sound::play(sound),
+///         }
+///     }
+/// }
+///
+/// impl App {
+///     fn reduce(&mut self, message: AppMsg) -> Effects<AppEffect> {
+///         match message {
+///             AppMsg::Open(page) => Effects::from(AppEffect::Navigate(page)),
+///             AppMsg::Error => Effects::from(AppEffect::PlaySound(Sound::Error)),
+///         }
+///     }
+/// }
+///
+/// impl Component for App {
+///     // ...
+///
+///     fn update(&mut self, message: AppMsg, sender: ComponentSender<Self>, _root: &Self::Root) {
+///         self.reduce(message).run(&sender);
+///     }
+/// }
+/// ```
+pub trait Effect<C: Component> {
+    /// Executes the side effect.
+    fn run(self, sender: &ComponentSender<C>);
+}
+
+/// A future that resolves to a command output of a component.
+pub type EffectFuture<C> = Pin<Box<dyn Future<Output = <C as Component>::CommandOutput> + Send>>;
+
+/// Common side effects of components.
+pub enum ComponentEffect<C: Component> {
+    /// Emit an input to the component itself.
+    Input(C::Input),
+    /// Emit an output to the parent of the component.
+    Output(C::Output),
+    /// Run a future in the background and send its result as command output to the component.
+    Command(EffectFuture<C>),
+}
+
+impl<C: Component> ComponentEffect<C> {
+    /// Creates a [`ComponentEffect::Command`] from a future.
+    #[must_use]
+    pub fn command<F>(future: F) -> Self
+    where
+        F: Future<Output = C::CommandOutput> + Send + 'static,
+    {
+        Self::Command(Box::pin(future))
+    }
+}
+
+impl<C: Component> Effect<C> for ComponentEffect<C> {
+    fn run(self, sender: &ComponentSender<C>) {
+        match self {
+            Self::Input(message) => sender.input(message),
+            Self::Output(message) => {
+                if sender.output(message).is_err() {
+                    tracing::warn!("Receiver of component outputs was dropped");
+                }
+            }
+            Self::Command(future) => sender.oneshot_command(future),
+        }
+    }
+}
+
+impl<C: Component> Debug for ComponentEffect<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Input(message) => f.debug_tuple("Input").field(message).finish(),
+            Self::Output(message) => f.debug_tuple("Output").field(message).finish(),
+            Self::Command(_) => f.debug_tuple("Command").field(&"<future>").finish(),
+        }
+    }
+}
+
+/// A list of side effects returned by a pure update function.
+///
+/// Use [`Effects::run()`] inside of `update` to execute all side effects in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Effects<E> {
+    effects: Vec<E>,
+}
+
+impl<E> Default for Effects<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> Effects<E> {
+    /// Creates an empty list of effects.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            effects: Vec::new(),
+        }
+    }
+
+    /// Adds an effect to the end of the list.
+    pub fn push(&mut self, effect: E) {
+        self.effects.push(effect);
+    }
+
+    /// Adds an effect to the end of the list and returns the list.
+    #[must_use]
+    pub fn with(mut self, effect: E) -> Self {
+        self.push(effect);
+        self
+    }
+
+    /// Returns the effects as a slice, which is useful for assertions in tests.
+    #[must_use]
+    pub fn as_slice(&self) -> &[E] {
+        &self.effects
+    }
+
+    /// Returns `true` if there are no effects.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Returns the number of effects.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.effects.len()
+    }
+
+    /// Executes all effects in order.
+    pub fn run<C>(self, sender: &ComponentSender<C>)
+    where
+        C: Component,
+        E: Effect<C>,
+    {
+        for effect in self.effects {
+            effect.run(sender);
+        }
+    }
+}
+
+impl<E> From<E> for Effects<E> {
+    fn from(effect: E) -> Self {
+        Self {
+            effects: vec![effect],
+        }
+    }
+}
+
+impl<E> From<Vec<E>> for Effects<E> {
+    fn from(effects: Vec<E>) -> Self {
+        Self { effects }
+    }
+}
+
+impl<E> FromIterator<E> for Effects<E> {
+    fn from_iter<T: IntoIterator<Item = E>>(iter: T) -> Self {
+        Self {
+            effects: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<E> Extend<E> for Effects<E> {
+    fn extend<T: IntoIterator<Item = E>>(&mut self, iter: T) {
+        self.effects.extend(iter);
+    }
+}
+
+impl<E> IntoIterator for Effects<E> {
+    type Item = E;
+    type IntoIter = std::vec::IntoIter<E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.effects.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Effects;
+
+    #[derive(Debug, PartialEq)]
+    enum Sound {
+        Click,
+        Error,
+    }
+
+    fn reduce(value: &mut i32, delta: i32) -> Effects<Sound> {
+        *value += delta;
+        let mut effects = Effects::from(Sound::Click);
+        if *value < 0 {
+            effects.push(Sound::Error);
+        }
+        effects
+    }
+
+    #[test]
+    fn effects() {
+        let mut value = 0;
+        assert_eq!(reduce(&mut value, 1).as_slice(), &[Sound::Click]);
+        assert_eq!(
+            reduce(&mut value, -2).as_slice(),
+            &[Sound::Click, Sound::Error]
+        );
+
+        let effects: Effects<i32> = (0..3).collect();
+        assert_eq!(effects.len(), 3);
+        assert_eq!(effects.into_iter().sum::<i32>(), 3);
+        assert!(Effects::<i32>::new().is_empty());
+    }
+}
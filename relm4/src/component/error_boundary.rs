@@ -0,0 +1,338 @@
+//! Contains a wrapper that isolates panics of a component.
+
+use std::any::Any;
+use std::fmt::{self, Debug};
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+use futures::FutureExt;
+use gtk::prelude::{Cast, WidgetExt};
+
+use crate::shutdown::{self, ShutdownSender};
+use crate::{Component, ComponentParts, ComponentSender, Sender};
+
+type FallbackFn = dyn Fn(&str) -> gtk::Widget;
+
+/// Initialization parameters of an [`ErrorBoundary`].
+pub struct ErrorBoundaryInit<C: Component> {
+    init: C::Init,
+    fallback: Box<FallbackFn>,
+}
+
+impl<C: Component> Debug for ErrorBoundaryInit<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ErrorBoundaryInit")
+            .field("init", &"<Init>")
+            .field("fallback", &"<fallback fn>")
+            .finish()
+    }
+}
+
+impl<C: Component> ErrorBoundaryInit<C> {
+    /// Creates the initialization parameters from the
+    /// initialization parameters of the wrapped component.
+    ///
+    /// By default, the fallback widget is a label that shows the panic message.
+    #[must_use]
+    pub fn new(init: C::Init) -> Self {
+        Self {
+            init,
+            fallback: Box::new(|message| {
+                let label = gtk::Label::new(Some(message));
+                label.add_css_class("error");
+                label.set_wrap(true);
+                label.upcast()
+            }),
+        }
+    }
+
+    /// Sets the widget that is shown instead of the wrapped component after it panicked.
+    ///
+    /// The function receives the panic message.
+    #[must_use]
+    pub fn fallback<F>(mut self, fallback: F) -> Self
+    where
+        F: Fn(&str) -> gtk::Widget + 'static,
+    {
+        self.fallback = Box::new(fallback);
+        self
+    }
+}
+
+/// Messages sent by an [`ErrorBoundary`] to its parent.
+#[derive(Debug)]
+pub enum ErrorBoundaryOutput<Output> {
+    /// An output of the wrapped component.
+    Output(Output),
+    /// The wrapped component panicked with the given message.
+    Panicked(String),
+}
+
+/// A component that wraps another component and catches panics
+/// inside of its [`init`](Component::init), update, view and shutdown functions.
+///
+/// If the wrapped component panics, it is dropped and a fallback widget is shown instead.
+/// The parent is notified with [`ErrorBoundaryOutput::Panicked`] and all further
+/// inputs are ignored.
+/// This prevents plugins or other untrusted code from taking down the whole application.
+///
+/// Commands of the wrapped component are cancelled after a panic,
+/// but panics inside of commands themselves are not caught.
+///
+/// ```ignore
+/// let plugin = ErrorBoundary::<PluginComponent>::builder()
+///     .launch(ErrorBoundaryInit::new(plugin_init))
+///     .forward(sender.input_sender(), |msg| match msg {
+///         ErrorBoundaryOutput::Output(output) => AppMsg::Plugin(output),
+///         ErrorBoundaryOutput::Panicked(message) => AppMsg::PluginCrashed(message),
+///     });
+/// ```
+pub struct ErrorBoundary<C: Component> {
+    inner: Option<ComponentParts<C>>,
+    inner_root: C::Root,
+    inner_sender: ComponentSender<C>,
+    inner_shutdown: Option<ShutdownSender>,
+    fallback: Box<FallbackFn>,
+}
+
+impl<C: Component> Debug for ErrorBoundary<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ErrorBoundary")
+            .field("panicked", &self.inner.is_none())
+            .field("inner_root", &self.inner_root)
+            .field("inner_sender", &self.inner_sender)
+            .field("fallback", &"<fallback fn>")
+            .finish()
+    }
+}
+
+impl<C> ErrorBoundary<C>
+where
+    C: Component,
+    C::Root: AsRef<gtk::Widget>,
+{
+    /// Returns `true` if the wrapped component panicked.
+    #[must_use]
+    pub const fn has_panicked(&self) -> bool {
+        self.inner.is_none()
+    }
+
+    /// Runs `f` with the wrapped component and replaces it
+    /// with the fallback widget if it panics.
+    fn guarded<F>(&mut self, sender: &ComponentSender<Self>, root: &gtk::Box, f: F)
+    where
+        F: FnOnce(&mut ComponentParts<C>, ComponentSender<C>, &C::Root),
+    {
+        let Some(inner) = &mut self.inner else {
+            return;
+        };
+
+        let inner_sender = self.inner_sender.clone();
+        let inner_root = &self.inner_root;
+        let result = panic::catch_unwind(AssertUnwindSafe(|| f(inner, inner_sender, inner_root)));
+
+        if let Err(payload) = result {
+            self.panicked(sender, root, &*payload);
+        }
+    }
+
+    fn panicked(
+        &mut self,
+        sender: &ComponentSender<Self>,
+        root: &gtk::Box,
+        payload: &(dyn Any + Send),
+    ) {
+        let message = panic_message(payload);
+        tracing::error!(
+            "Component {} panicked: {message}",
+            std::any::type_name::<C>()
+        );
+
+        // The state of the wrapped component can't be trusted anymore.
+        self.inner = None;
+        if let Some(shutdown) = self.inner_shutdown.take() {
+            shutdown.shutdown();
+        }
+
+        root.remove(self.inner_root.as_ref());
+        root.append(&(self.fallback)(&message));
+
+        sender.output(ErrorBoundaryOutput::Panicked(message)).ok();
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Unknown panic".to_owned()
+    }
+}
+
+impl<C> Component for ErrorBoundary<C>
+where
+    C: Component,
+    C::Root: AsRef<gtk::Widget>,
+{
+    type CommandOutput = C::CommandOutput;
+    type Input = C::Input;
+    type Output = ErrorBoundaryOutput<C::Output>;
+    type Init = ErrorBoundaryInit<C>;
+    type Root = gtk::Box;
+    type Widgets = ();
+
+    fn init_root() -> Self::Root {
+        gtk::Box::default()
+    }
+
+    fn init(
+        init: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let ErrorBoundaryInit { init, fallback } = init;
+
+        // Forward outputs of the wrapped component to the parent.
+        let (output_sender, output_receiver) = crate::channel();
+        let parent_sender = sender.output_sender().clone();
+        crate::spawn_local(async move {
+            while let Some(output) = output_receiver.recv().await {
+                if parent_sender
+                    .send(ErrorBoundaryOutput::Output(output))
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        });
+
+        // Allows cancelling the commands of the wrapped component after a panic.
+        let (inner_shutdown, shutdown_receiver) = shutdown::channel();
+        let inner_sender = ComponentSender::new(
+            sender.input_sender().clone(),
+            sender.priority_input_sender().clone(),
+            output_sender,
+            sender.command_sender().clone(),
+            shutdown_receiver,
+        );
+
+        let inner_root = C::init_root();
+        root.append(inner_root.as_ref());
+
+        let mut model = Self {
+            inner: None,
+            inner_root,
+            inner_sender,
+            inner_shutdown: Some(inner_shutdown),
+            fallback,
+        };
+
+        let inner_root = model.inner_root.clone();
+        let inner_sender = model.inner_sender.clone();
+        match panic::catch_unwind(AssertUnwindSafe(|| C::init(init, inner_root, inner_sender))) {
+            Ok(inner) => model.inner = Some(inner),
+            Err(payload) => model.panicked(&sender, &root, &*payload),
+        }
+
+        ComponentParts { model, widgets: () }
+    }
+
+    fn update_with_view(
+        &mut self,
+        _widgets: &mut Self::Widgets,
+        message: Self::Input,
+        sender: ComponentSender<Self>,
+        root: &Self::Root,
+    ) {
+        self.guarded(&sender, root, |inner, inner_sender, inner_root| {
+            let ComponentParts { model, widgets } = inner;
+            model.update_with_view(widgets, message, inner_sender, inner_root);
+        });
+    }
+
+    fn update_cmd_with_view(
+        &mut self,
+        _widgets: &mut Self::Widgets,
+        message: Self::CommandOutput,
+        sender: ComponentSender<Self>,
+        root: &Self::Root,
+    ) {
+        self.guarded(&sender, root, |inner, inner_sender, inner_root| {
+            let ComponentParts { model, widgets } = inner;
+            model.update_cmd_with_view(widgets, message, inner_sender, inner_root);
+        });
+    }
+
+    fn post_init(
+        &mut self,
+        _widgets: &mut Self::Widgets,
+        sender: ComponentSender<Self>,
+        root: &Self::Root,
+    ) {
+        self.guarded(&sender, root, |inner, inner_sender, inner_root| {
+            let ComponentParts { model, widgets } = inner;
+            model.post_init(widgets, inner_sender, inner_root);
+        });
+    }
+
+    fn post_init_widget(root: &Self::Root) -> Option<gtk::Widget> {
+        // The root of the wrapped component is mapped together with the box.
+        Some(root.clone().upcast())
+    }
+
+    fn shutdown(&mut self, _widgets: &mut Self::Widgets, _output: Sender<Self::Output>) {
+        if let Some(ComponentParts { model, widgets }) = &mut self.inner {
+            let output = self.inner_sender.output_sender().clone();
+            if panic::catch_unwind(AssertUnwindSafe(|| model.shutdown(widgets, output))).is_err() {
+                tracing::error!(
+                    "Component {} panicked during shutdown",
+                    std::any::type_name::<C>()
+                );
+            }
+        }
+    }
+
+    fn shutdown_async(
+        &mut self,
+        _widgets: &mut Self::Widgets,
+        _output: Sender<Self::Output>,
+    ) -> impl Future<Output = ()> + 'static {
+        let output = self.inner_sender.output_sender().clone();
+        let hook = self
+            .inner
+            .as_mut()
+            .and_then(|ComponentParts { model, widgets }| {
+                panic::catch_unwind(AssertUnwindSafe(|| model.shutdown_async(widgets, output))).ok()
+            });
+        let panicked = self.inner.is_some() && hook.is_none();
+
+        // Commands of the wrapped component keep running until its cleanup has finished.
+        let inner_shutdown = self.inner_shutdown.take();
+
+        async move {
+            let panicked = if let Some(hook) = hook {
+                AssertUnwindSafe(hook).catch_unwind().await.is_err()
+            } else {
+                panicked
+            };
+
+            if panicked {
+                tracing::error!(
+                    "Component {} panicked during shutdown",
+                    std::any::type_name::<C>()
+                );
+            }
+
+            if let Some(shutdown) = inner_shutdown {
+                shutdown.shutdown();
+            }
+        }
+    }
+
+    fn shutdown_deadline() -> Duration {
+        C::shutdown_deadline()
+    }
+}
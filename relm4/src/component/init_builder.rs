@@ -0,0 +1,16 @@
+/// Init types of components that can be created with a builder.
+///
+/// This trait is usually implemented with the `InitBuilder` derive macro.
+/// Use [`ComponentBuilder::launch_with`](crate::ComponentBuilder::launch_with)
+/// to configure the builder while launching the component,
+/// so only the parameters that differ from their defaults need to be set.
+pub trait InitBuilder: Sized {
+    /// The builder that creates the init type.
+    type Builder: Default + Into<Self>;
+
+    /// Returns a builder with default values for all parameters.
+    #[must_use]
+    fn builder() -> Self::Builder {
+        Self::Builder::default()
+    }
+}
@@ -0,0 +1,177 @@
+use std::fmt::Debug;
+use std::sync::{Arc, RwLock};
+
+use once_cell::sync::Lazy;
+
+use crate::Sender;
+
+type SubscriberFn<T> = Arc<dyn Fn(&T) -> bool + 'static + Send + Sync>;
+
+/// A typed topic that components can subscribe to and publish messages on
+/// without knowing each other.
+///
+/// Unlike [`MessageBroker`](super::MessageBroker), which forwards messages to exactly one component,
+/// a [`MessageBus`] delivers each published message to all of its subscribers.
+/// This removes the need to relay messages through every intermediate parent
+/// in deeply nested component trees.
+///
+/// Each topic is a separate static variable with its own message type.
+/// Subscribers are removed automatically as soon as their receiver is dropped,
+/// for example after the subscribed component was shut down.
+///
+/// ```
+/// use relm4::component::MessageBus;
+///
+/// #[derive(Debug, Clone)]
+/// struct ThemeChanged {
+///     dark: bool,
+/// }
+///
+/// static THEME: MessageBus<ThemeChanged> = MessageBus::new();
+///
+/// // Subscribe with the input sender of a component.
+/// let (sender, receiver) = relm4::channel();
+/// THEME.subscribe(&sender, |msg| msg.dark);
+///
+/// // Publish from anywhere in the application.
+/// THEME.publish(ThemeChanged { dark: true });
+/// assert!(receiver.recv_sync().unwrap());
+/// ```
+pub struct MessageBus<T> {
+    subscribers: Lazy<RwLock<Vec<SubscriberFn<T>>>>,
+}
+
+impl<T> Debug for MessageBus<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MessageBus")
+            .field("subscribers", &self.subscribers.try_read().map(|s| s.len()))
+            .finish()
+    }
+}
+
+impl<T> Default for MessageBus<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> MessageBus<T> {
+    /// Creates a new [`MessageBus`] without subscribers.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            subscribers: Lazy::new(RwLock::default),
+        }
+    }
+
+    /// Subscribe to this topic.
+    ///
+    /// Every published message is converted by `f` and sent to `sender`.
+    pub fn subscribe<Msg, F>(&self, sender: &Sender<Msg>, f: F)
+    where
+        F: Fn(&T) -> Msg + 'static + Send + Sync,
+        Msg: Send + 'static,
+    {
+        let sender = sender.clone();
+        self.subscribers
+            .write()
+            .unwrap()
+            .push(Arc::new(move |message: &T| sender.send(f(message)).is_ok()));
+    }
+
+    /// An alternative version of [`subscribe()`](Self::subscribe()) that only sends a message if
+    /// the closure returns [`Some`].
+    pub fn subscribe_optional<Msg, F>(&self, sender: &Sender<Msg>, f: F)
+    where
+        F: Fn(&T) -> Option<Msg> + 'static + Send + Sync,
+        Msg: Send + 'static,
+    {
+        let sender = sender.clone();
+        self.subscribers
+            .write()
+            .unwrap()
+            .push(Arc::new(move |message: &T| {
+                if let Some(msg) = f(message) {
+                    sender.send(msg).is_ok()
+                } else {
+                    true
+                }
+            }));
+    }
+
+    /// Publish a message to all subscribers of this topic.
+    ///
+    /// The subscribers run without holding the lock of this topic,
+    /// so they may subscribe or publish messages themselves.
+    pub fn publish(&self, message: T) {
+        let subscribers = self.subscribers.read().unwrap().clone();
+        let closed: Vec<SubscriberFn<T>> = subscribers
+            .into_iter()
+            .filter(|subscriber| !subscriber(&message))
+            .collect();
+
+        // Remove all subscribers which had their receivers dropped.
+        if !closed.is_empty() {
+            self.subscribers
+                .write()
+                .unwrap()
+                .retain(|subscriber| !closed.iter().any(|closed| Arc::ptr_eq(subscriber, closed)));
+        }
+    }
+
+    /// Returns the number of subscribers.
+    ///
+    /// Subscribers whose receivers were dropped are only removed
+    /// during the next call to [`publish()`](Self::publish()).
+    #[must_use]
+    pub fn subscriber_count(&self) -> usize {
+        self.subscribers.read().unwrap().len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MessageBus;
+
+    static BUS: MessageBus<u8> = MessageBus::new();
+
+    #[test]
+    fn message_bus() {
+        let (first_sender, first_receiver) = crate::channel();
+        let (second_sender, second_receiver) = crate::channel();
+
+        BUS.subscribe(&first_sender, |value| *value);
+        BUS.subscribe_optional(&second_sender, |value| (*value > 1).then_some(*value * 2));
+
+        BUS.publish(1);
+        BUS.publish(2);
+        assert_eq!(first_receiver.recv_sync().unwrap(), 1);
+        assert_eq!(first_receiver.recv_sync().unwrap(), 2);
+        assert_eq!(second_receiver.recv_sync().unwrap(), 4);
+
+        // Dropped receivers are unsubscribed on the next message.
+        drop(first_receiver);
+        BUS.publish(3);
+        assert_eq!(BUS.subscriber_count(), 1);
+        assert_eq!(second_receiver.recv_sync().unwrap(), 6);
+    }
+
+    static NESTED_BUS: MessageBus<u8> = MessageBus::new();
+
+    #[test]
+    fn message_bus_reentrant() {
+        let (sender, receiver) = crate::channel();
+
+        NESTED_BUS.subscribe(&sender, |value| {
+            // Publishing from a subscriber must not deadlock.
+            if *value == 0 {
+                NESTED_BUS.publish(1);
+            }
+            (*value, NESTED_BUS.subscriber_count())
+        });
+
+        NESTED_BUS.publish(0);
+        assert_eq!(receiver.recv_sync().unwrap(), (1, 1));
+        assert_eq!(receiver.recv_sync().unwrap(), (0, 1));
+    }
+}
@@ -0,0 +1,115 @@
+use std::fmt::{self, Debug};
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+
+/// Intercepts the input messages of a component before they reach its update function.
+///
+/// A middleware can observe a message, transform it or veto it by returning [`None`].
+/// This is useful for logging, analytics, permission checks or capturing messages for replays.
+/// Middleware is installed with [`ComponentBuilder::middleware`](crate::ComponentBuilder::middleware)
+/// or [`AsyncComponentBuilder::middleware`](crate::component::AsyncComponentBuilder::middleware).
+///
+/// This trait is implemented for all closures with a matching signature.
+///
+/// ```ignore
+/// let editor = Editor::builder()
+///     .middleware(|msg: EditorMsg| {
+///         if read_only() && msg.is_edit() {
+///             None
+///         } else {
+///             Some(msg)
+///         }
+///     })
+///     .launch(());
+/// ```
+pub trait Middleware<Input>: 'static {
+    /// Processes an input message.
+    ///
+    /// Return [`None`] to drop the message.
+    fn process(&self, message: Input) -> Option<Input>;
+}
+
+impl<Input, F> Middleware<Input> for F
+where
+    F: Fn(Input) -> Option<Input> + 'static,
+{
+    fn process(&self, message: Input) -> Option<Input> {
+        self(message)
+    }
+}
+
+/// Information about an input message that is passed to global middleware.
+#[derive(Debug)]
+pub struct MessageInfo<'a> {
+    /// The type name of the component that receives the message.
+    pub component: &'static str,
+    /// The input message.
+    pub message: &'a dyn Debug,
+}
+
+type GlobalMiddlewareFn = dyn Fn(&MessageInfo<'_>) -> bool + Send + Sync;
+
+/// Middleware that is applied to the inputs of all components.
+static GLOBAL_MIDDLEWARE: Lazy<RwLock<Vec<Box<GlobalMiddlewareFn>>>> = Lazy::new(RwLock::default);
+
+/// Installs a middleware that observes the input messages of all components
+/// and may veto them by returning `false`.
+///
+/// Global middleware runs after the middleware of the component.
+/// It's applied to regular and async components, but not to factories and workers.
+/// See also [`RelmApp::with_middleware`](crate::RelmApp::with_middleware).
+pub fn add_global_middleware<F>(middleware: F)
+where
+    F: Fn(&MessageInfo<'_>) -> bool + Send + Sync + 'static,
+{
+    GLOBAL_MIDDLEWARE
+        .write()
+        .unwrap()
+        .push(Box::new(middleware));
+}
+
+/// Returns `true` if the global middleware allows a message.
+fn global_middleware_allows<Input: Debug>(component: &'static str, message: &Input) -> bool {
+    let info = MessageInfo { component, message };
+    GLOBAL_MIDDLEWARE
+        .read()
+        .unwrap()
+        .iter()
+        .all(|middleware| middleware(&info))
+}
+
+/// The middleware installed on a single component.
+pub(crate) struct MiddlewareChain<Input> {
+    chain: Vec<Box<dyn Middleware<Input>>>,
+}
+
+impl<Input> Default for MiddlewareChain<Input> {
+    fn default() -> Self {
+        Self { chain: Vec::new() }
+    }
+}
+
+impl<Input> Debug for MiddlewareChain<Input> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MiddlewareChain")
+            .field("len", &self.chain.len())
+            .finish()
+    }
+}
+
+impl<Input: Debug> MiddlewareChain<Input> {
+    pub(crate) fn push<M: Middleware<Input>>(&mut self, middleware: M) {
+        self.chain.push(Box::new(middleware));
+    }
+
+    /// Runs the message through the component middleware and the global middleware.
+    pub(crate) fn process(&self, component: &'static str, message: Input) -> Option<Input> {
+        let message = self
+            .chain
+            .iter()
+            .try_fold(message, |message, middleware| middleware.process(message))?;
+
+        global_middleware_allows(component, &message).then_some(message)
+    }
+}
@@ -10,15 +10,48 @@ mod r#async;
 /// Message broker
 mod message_broker;
 
+/// Interceptors for input messages.
+pub(crate) mod middleware;
+
+/// Topic-based message bus
+mod message_bus;
+
+/// Side effects returned from pure update functions.
+mod effects;
+
+/// A wrapper that isolates panics of a component.
+mod error_boundary;
+
+/// Saving and restoring the state of components.
+mod persistent;
+
+/// Builders for the init types of components.
+mod init_builder;
+
+/// Launching components by name at runtime.
+mod registry;
+
+/// Typed state machines for structuring updates.
+mod state_machine;
+
 /// A simpler version of components that does work
 /// in the background.
 pub mod worker;
 
+pub use effects::{ComponentEffect, Effect, EffectFuture, Effects};
+pub use error_boundary::{ErrorBoundary, ErrorBoundaryInit, ErrorBoundaryOutput};
+pub use init_builder::InitBuilder;
 pub use message_broker::MessageBroker;
+pub use message_bus::MessageBus;
+pub use middleware::{add_global_middleware, MessageInfo, Middleware};
+pub use persistent::PersistentComponent;
+pub use registry::{ComponentRegistry, RegistryError};
+pub use state_machine::{InvalidTransition, Machine, StateMachine, Transition};
 
 pub use sync::{
-    CommandFuture, Component, ComponentBuilder, ComponentController, ComponentParts,
-    ComponentStream, Connector, Controller, SimpleComponent, StateWatcher,
+    AnyController, CommandFuture, Component, ComponentBuilder, ComponentController, ComponentId,
+    ComponentParts, ComponentStream, Connector, Controller, MappedConnector, OutputBroadcast,
+    SimpleComponent, StateWatcher,
 };
 
 pub use r#async::{
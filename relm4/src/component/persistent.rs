@@ -0,0 +1,27 @@
+/// Components whose state can be saved and restored later,
+/// for example to restore the contents of a window after a restart.
+///
+/// This trait can be implemented for [`Component`](crate::Component)s and
+/// [`FactoryComponent`](crate::factory::FactoryComponent)s.
+/// Use [`ComponentBuilder::launch_restored`](crate::ComponentBuilder::launch_restored)
+/// and [`ComponentController::save_state`](crate::ComponentController::save_state)
+/// for components or
+/// [`FactoryVecDeque::save_states`](crate::factory::FactoryVecDeque::save_states) and
+/// [`FactoryVecDequeGuard::restore_states`](crate::factory::FactoryVecDequeGuard::restore_states)
+/// for factories.
+///
+/// The state is only handed to you, so you can store it however you want.
+/// Deriving `serde::Serialize` and `serde::Deserialize` for the state makes
+/// it easy to write it to disk.
+pub trait PersistentComponent {
+    /// The state that is saved and restored.
+    type State;
+
+    /// Captures the current state.
+    fn save_state(&self) -> Self::State;
+
+    /// Applies a previously saved state after the model was initialized.
+    ///
+    /// The view is updated afterwards.
+    fn restore_state(&mut self, state: Self::State);
+}
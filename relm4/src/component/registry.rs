@@ -0,0 +1,176 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+
+use crate::{AnyController, Component, Sender};
+
+type ConstructorFn =
+    fn(Box<dyn Any>, Option<&Sender<Box<dyn Any>>>) -> Result<AnyController, Box<dyn Any>>;
+
+fn construct<C>(
+    init: Box<dyn Any>,
+    output: Option<&Sender<Box<dyn Any>>>,
+) -> Result<AnyController, Box<dyn Any>>
+where
+    C: Component,
+    C::Init: 'static,
+    C::Root: AsRef<gtk::Widget>,
+{
+    let init = init.downcast::<C::Init>()?;
+    let connector = C::builder().launch(*init);
+    let controller = match output {
+        Some(output) => connector.forward(output, |msg| Box::new(msg) as Box<dyn Any>),
+        None => connector.detach(),
+    };
+    Ok(controller.into())
+}
+
+/// An error returned when launching a component from a [`ComponentRegistry`].
+#[derive(Debug)]
+pub enum RegistryError {
+    /// No component was registered with this name.
+    Unknown(String),
+    /// The initialization parameters don't have the type of the registered component.
+    ///
+    /// Contains the initialization parameters.
+    InvalidInit(Box<dyn Any>),
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown(name) => write!(f, "no component registered as \"{name}\""),
+            Self::InvalidInit(_) => f.write_str("invalid initialization parameters"),
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+/// A registry of component constructors that can be launched by name at runtime.
+///
+/// This allows plugins to register their components at startup,
+/// while the host application launches them dynamically without knowing their types.
+/// Launched components are returned as [`AnyController`].
+///
+/// Use [`ComponentRegistry::global()`] to share the registry between crates
+/// or a static variable for a private registry.
+///
+/// ```ignore
+/// // In the plugin crate.
+/// ComponentRegistry::global().register::<TerminalPane>("terminal");
+///
+/// // In the host application.
+/// let pane = ComponentRegistry::global().launch("terminal", Box::new(TerminalInit::default()))?;
+/// panes.push(pane);
+/// ```
+pub struct ComponentRegistry {
+    constructors: Lazy<RwLock<HashMap<String, ConstructorFn>>>,
+}
+
+impl Debug for ComponentRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ComponentRegistry")
+            .field("names", &self.names())
+            .finish()
+    }
+}
+
+impl Default for ComponentRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static GLOBAL_REGISTRY: ComponentRegistry = ComponentRegistry::new();
+
+impl ComponentRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            constructors: Lazy::new(RwLock::default),
+        }
+    }
+
+    /// Returns the registry shared by the whole application.
+    #[must_use]
+    pub fn global() -> &'static Self {
+        &GLOBAL_REGISTRY
+    }
+
+    /// Registers a component under the given name.
+    ///
+    /// A component that was previously registered with the same name is replaced.
+    pub fn register<C>(&self, name: impl Into<String>)
+    where
+        C: Component,
+        C::Init: 'static,
+        C::Root: AsRef<gtk::Widget>,
+    {
+        self.constructors
+            .write()
+            .unwrap()
+            .insert(name.into(), construct::<C>);
+    }
+
+    /// Removes the component with the given name from the registry.
+    ///
+    /// Returns `true` if a component was registered with this name.
+    pub fn unregister(&self, name: &str) -> bool {
+        self.constructors.write().unwrap().remove(name).is_some()
+    }
+
+    /// Returns `true` if a component was registered with this name.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.constructors.read().unwrap().contains_key(name)
+    }
+
+    /// Returns the names of all registered components.
+    #[must_use]
+    pub fn names(&self) -> Vec<String> {
+        self.constructors.read().unwrap().keys().cloned().collect()
+    }
+
+    /// Launches the component registered with `name`, ignoring its outputs.
+    ///
+    /// `init` must contain the [`Component::Init`] value of the registered component.
+    pub fn launch(&self, name: &str, init: Box<dyn Any>) -> Result<AnyController, RegistryError> {
+        self.launch_inner(name, init, None)
+    }
+
+    /// Launches the component registered with `name` and forwards
+    /// its outputs as [`Box<dyn Any>`] to the designated sender.
+    ///
+    /// `init` must contain the [`Component::Init`] value of the registered component.
+    pub fn launch_forward(
+        &self,
+        name: &str,
+        init: Box<dyn Any>,
+        sender: &Sender<Box<dyn Any>>,
+    ) -> Result<AnyController, RegistryError> {
+        self.launch_inner(name, init, Some(sender))
+    }
+
+    fn launch_inner(
+        &self,
+        name: &str,
+        init: Box<dyn Any>,
+        sender: Option<&Sender<Box<dyn Any>>>,
+    ) -> Result<AnyController, RegistryError> {
+        // Don't hold the lock while launching so components can use the registry.
+        let constructor = self
+            .constructors
+            .read()
+            .unwrap()
+            .get(name)
+            .copied()
+            .ok_or_else(|| RegistryError::Unknown(name.to_owned()))?;
+
+        constructor(init, sender).map_err(RegistryError::InvalidInit)
+    }
+}
@@ -0,0 +1,190 @@
+use std::fmt::{self, Debug};
+
+/// The result of handling an event in a [`StateMachine`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Transition<S> {
+    /// Move to a new state.
+    To(S),
+    /// The event is valid but doesn't change the state.
+    Stay,
+    /// The event isn't allowed in the current state.
+    Invalid,
+}
+
+/// A typed state machine that can be used to structure the `update` method of a component.
+///
+/// Instead of matching on every combination of state and message in one big
+/// `match` statement, each state only declares which events it accepts.
+/// All other events are rejected and logged automatically by [`Machine::handle`].
+///
+/// ```
+/// use relm4::component::{Machine, StateMachine, Transition};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Player {
+///     Stopped,
+///     Playing,
+///     Paused,
+/// }
+///
+/// #[derive(Debug)]
+/// enum PlayerEvent {
+///     Play,
+///     Pause,
+///     Stop,
+/// }
+///
+/// impl StateMachine for Player {
+///     type Event = PlayerEvent;
+///
+///     fn transition(&self, event: &PlayerEvent) -> Transition<Self> {
+///         match (self, event) {
+///             (Self::Stopped | Self::Paused, PlayerEvent::Play) => Transition::To(Self::Playing),
+///             (Self::Playing, PlayerEvent::Pause) => Transition::To(Self::Paused),
+///             (Self::Playing | Self::Paused, PlayerEvent::Stop) => Transition::To(Self::Stopped),
+///             (Self::Stopped, PlayerEvent::Stop) => Transition::Stay,
+///             _ => Transition::Invalid,
+///         }
+///     }
+/// }
+///
+/// let mut player = Machine::new(Player::Stopped);
+/// assert!(player.handle(PlayerEvent::Play).is_ok());
+/// assert!(player.handle(PlayerEvent::Pause).is_ok());
+/// assert_eq!(player.state(), &Player::Paused);
+///
+/// // Pausing twice is not allowed.
+/// assert!(player.handle(PlayerEvent::Pause).is_err());
+/// assert_eq!(player.state(), &Player::Paused);
+/// ```
+pub trait StateMachine: Debug + Sized {
+    /// The events that cause transitions between states.
+    type Event: Debug;
+
+    /// Determines the next state after receiving an event.
+    fn transition(&self, event: &Self::Event) -> Transition<Self>;
+
+    /// Called after the state machine moved from the `previous` state
+    /// into this state because of `event`.
+    ///
+    /// This method does nothing by default.
+    #[allow(unused)]
+    fn on_enter(&mut self, previous: &Self, event: &Self::Event) {}
+}
+
+/// An error returned by [`Machine::handle`] if an event isn't allowed in the current state.
+#[derive(Debug)]
+pub struct InvalidTransition<E> {
+    /// The rejected event.
+    pub event: E,
+}
+
+impl<E: Debug> fmt::Display for InvalidTransition<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid transition for event {:?}", self.event)
+    }
+}
+
+impl<E: Debug> std::error::Error for InvalidTransition<E> {}
+
+/// Holds the current state of a [`StateMachine`] and applies events to it.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Machine<S: StateMachine> {
+    state: S,
+}
+
+impl<S: StateMachine> Machine<S> {
+    /// Creates a state machine in the given initial state.
+    #[must_use]
+    pub const fn new(initial: S) -> Self {
+        Self { state: initial }
+    }
+
+    /// Returns the current state.
+    #[must_use]
+    pub const fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Returns the current state and consumes the machine.
+    #[must_use]
+    pub fn into_state(self) -> S {
+        self.state
+    }
+
+    /// Applies an event to the current state.
+    ///
+    /// Invalid transitions leave the state unchanged, are logged as a
+    /// warning and return the rejected event as [`Err`].
+    pub fn handle(&mut self, event: S::Event) -> Result<(), InvalidTransition<S::Event>> {
+        match self.state.transition(&event) {
+            Transition::To(next) => {
+                let previous = std::mem::replace(&mut self.state, next);
+                self.state.on_enter(&previous, &event);
+                Ok(())
+            }
+            Transition::Stay => Ok(()),
+            Transition::Invalid => {
+                tracing::warn!(
+                    "Invalid transition from state {:?} with event {:?}",
+                    self.state,
+                    event
+                );
+                Err(InvalidTransition { event })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Machine, StateMachine, Transition};
+
+    #[derive(Debug, PartialEq)]
+    enum Door {
+        Open,
+        Closed { entered: usize },
+    }
+
+    #[derive(Debug)]
+    enum DoorEvent {
+        Open,
+        Close,
+    }
+
+    impl StateMachine for Door {
+        type Event = DoorEvent;
+
+        fn transition(&self, event: &DoorEvent) -> Transition<Self> {
+            match (self, event) {
+                (Self::Open, DoorEvent::Close) => Transition::To(Self::Closed { entered: 0 }),
+                (Self::Closed { .. }, DoorEvent::Open) => Transition::To(Self::Open),
+                (Self::Closed { .. }, DoorEvent::Close) => Transition::Stay,
+                _ => Transition::Invalid,
+            }
+        }
+
+        fn on_enter(&mut self, _previous: &Self, _event: &DoorEvent) {
+            if let Self::Closed { entered } = self {
+                *entered += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn state_machine() {
+        let mut door = Machine::new(Door::Open);
+
+        door.handle(DoorEvent::Close).unwrap();
+        assert_eq!(door.state(), &Door::Closed { entered: 1 });
+
+        // Staying in a state doesn't run `on_enter`.
+        door.handle(DoorEvent::Close).unwrap();
+        assert_eq!(door.state(), &Door::Closed { entered: 1 });
+
+        door.handle(DoorEvent::Open).unwrap();
+        let error = door.handle(DoorEvent::Open).unwrap_err();
+        assert!(matches!(error.event, DoorEvent::Open));
+        assert_eq!(door.into_state(), Door::Open);
+    }
+}
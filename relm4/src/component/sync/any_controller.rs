@@ -0,0 +1,151 @@
+use std::any::{self, Any};
+use std::fmt::{self, Debug};
+
+use super::{Component, ComponentController, Controller};
+
+/// Object-safe subset of [`ComponentController`] used by [`AnyController`].
+trait ErasedController {
+    fn send(&self, message: Box<dyn Any>) -> Result<(), Box<dyn Any>>;
+
+    fn detach_runtime(&mut self);
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl<C: Component> ErasedController for Controller<C> {
+    fn send(&self, message: Box<dyn Any>) -> Result<(), Box<dyn Any>> {
+        let message = message.downcast::<C::Input>()?;
+        self.sender()
+            .send(*message)
+            .map_err(|message| Box::new(message) as Box<dyn Any>)
+    }
+
+    fn detach_runtime(&mut self) {
+        ComponentController::detach_runtime(self);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+/// A type-erased [`Controller`].
+///
+/// This allows storing controllers of different components in a single collection,
+/// for example the panels of a dock or the panes of plugins.
+/// The root widget is available as [`gtk::Widget`] and inputs
+/// are sent as [`Box<dyn Any>`] that is downcast to the input type of the component.
+///
+/// ```ignore
+/// let panels: Vec<AnyController> = vec![
+///     FilesPanel::builder().launch(()).detach().into(),
+///     SearchPanel::builder().launch(()).detach().into(),
+/// ];
+///
+/// for panel in &panels {
+///     dock.append(panel.widget());
+/// }
+///
+/// panels[1].emit(SearchMsg::Focus).unwrap();
+/// ```
+pub struct AnyController {
+    widget: gtk::Widget,
+    type_name: &'static str,
+    inner: Box<dyn ErasedController>,
+}
+
+impl Debug for AnyController {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AnyController")
+            .field("widget", &self.widget)
+            .field("type_name", &self.type_name)
+            .finish()
+    }
+}
+
+impl AnyController {
+    /// Erases the type of a [`Controller`].
+    #[must_use]
+    pub fn new<C>(controller: Controller<C>) -> Self
+    where
+        C: Component,
+        C::Root: AsRef<gtk::Widget>,
+    {
+        Self {
+            widget: controller.widget().as_ref().clone(),
+            type_name: any::type_name::<C>(),
+            inner: Box::new(controller),
+        }
+    }
+
+    /// Returns the root widget of the component.
+    #[must_use]
+    pub const fn widget(&self) -> &gtk::Widget {
+        &self.widget
+    }
+
+    /// Returns the type name of the component, which is useful for debugging.
+    #[must_use]
+    pub const fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Sends an input to the component.
+    ///
+    /// Returns the message as [`Err`] if it doesn't have the input type
+    /// of the component or if the runtime of the component was shut down.
+    pub fn send(&self, message: Box<dyn Any>) -> Result<(), Box<dyn Any>> {
+        self.inner.send(message)
+    }
+
+    /// Sends an input to the component.
+    ///
+    /// Typed version of [`send()`](Self::send()).
+    pub fn emit<M: 'static>(&self, message: M) -> Result<(), M> {
+        self.send(Box::new(message))
+            .map_err(|message| *message.downcast::<M>().unwrap())
+    }
+
+    /// Returns `true` if the controller belongs to a component of type `C`.
+    #[must_use]
+    pub fn is<C: Component>(&self) -> bool {
+        self.inner.as_any().is::<Controller<C>>()
+    }
+
+    /// Returns the typed controller if it belongs to a component of type `C`.
+    #[must_use]
+    pub fn downcast_ref<C: Component>(&self) -> Option<&Controller<C>> {
+        self.inner.as_any().downcast_ref()
+    }
+
+    /// Converts this type back into a typed controller if it belongs to a component of type `C`.
+    pub fn downcast<C: Component>(self) -> Result<Controller<C>, Self> {
+        if self.is::<C>() {
+            Ok(*self.inner.into_any().downcast().unwrap())
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Dropping this type will usually stop the runtime of the component.
+    /// With this method you can give the runtime a static lifetime.
+    pub fn detach_runtime(&mut self) {
+        self.inner.detach_runtime();
+    }
+}
+
+impl<C> From<Controller<C>> for AnyController
+where
+    C: Component,
+    C::Root: AsRef<gtk::Widget>,
+{
+    fn from(controller: Controller<C>) -> Self {
+        Self::new(controller)
+    }
+}
@@ -3,7 +3,10 @@
 // SPDX-License-Identifier: MIT or Apache-2.0
 
 use super::super::MessageBroker;
-use super::{Component, ComponentParts, Connector, StateWatcher};
+use super::{Component, ComponentController, ComponentParts, Connector, StateWatcher};
+use crate::component::middleware::MiddlewareChain;
+use crate::component::{InitBuilder, Middleware, PersistentComponent};
+use crate::runtime_util::{run_shutdown_hook, schedule_post_init};
 use crate::{
     late_initialization, ComponentSender, GuardedReceiver, Receiver, RelmContainerExt,
     RelmWidgetExt, RuntimeSenders, Sender,
@@ -22,6 +25,10 @@ pub struct ComponentBuilder<C: Component> {
     /// The root widget of the component.
     pub root: C::Root,
     priority: glib::Priority,
+    input_capacity: Option<usize>,
+    middleware: MiddlewareChain<C::Input>,
+    pub(crate) thread_name: Option<String>,
+    pub(crate) stack_size: Option<usize>,
 
     pub(super) component: PhantomData<C>,
 }
@@ -32,6 +39,10 @@ impl<C: Component> Default for ComponentBuilder<C> {
         Self {
             root: C::init_root(),
             priority: glib::Priority::default(),
+            input_capacity: None,
+            middleware: MiddlewareChain::default(),
+            thread_name: None,
+            stack_size: None,
             component: PhantomData,
         }
     }
@@ -61,6 +72,48 @@ impl<C: Component> ComponentBuilder<C> {
         self.priority = priority;
         self
     }
+
+    /// Use a bounded input channel that holds at most `capacity` messages.
+    ///
+    /// By default, the input channel is unbounded and grows without limit
+    /// if a producer outpaces the component.
+    /// With a bounded channel, [`Sender::try_send`] and [`ComponentController::try_emit`]
+    /// return an error and [`Sender::send_async`] waits once the channel is full.
+    ///
+    /// Regular sends block until there's capacity again, so never use them
+    /// for components running on the main thread from the main thread itself.
+    /// This doesn't affect components launched with a [`MessageBroker`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    #[must_use]
+    pub fn bounded(mut self, capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "The capacity of the input channel must not be zero"
+        );
+        self.input_capacity = Some(capacity);
+        self
+    }
+
+    /// Install a [`Middleware`] that intercepts input messages before they reach the component.
+    ///
+    /// Multiple middlewares run in the order they were installed.
+    /// This doesn't affect workers.
+    #[must_use]
+    pub fn middleware<M: Middleware<C::Input>>(mut self, middleware: M) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Creates the input channel of the component.
+    pub(crate) fn input_channel(&self) -> (Sender<C::Input>, Receiver<C::Input>) {
+        match self.input_capacity {
+            Some(capacity) => crate::bounded_channel(capacity),
+            None => crate::channel(),
+        }
+    }
 }
 
 impl<C: Component> ComponentBuilder<C>
@@ -137,7 +190,7 @@ impl<C: Component> ComponentBuilder<C> {
     /// Starts the component, passing ownership to a future attached to a [gtk::glib::MainContext].
     pub fn launch(self, payload: C::Init) -> Connector<C> {
         // Used for all events to be processed by this component's internal service.
-        let (input_sender, input_receiver) = crate::channel::<C::Input>();
+        let (input_sender, input_receiver) = self.input_channel();
 
         self.launch_with_input_channel(payload, input_sender, input_receiver)
     }
@@ -160,13 +213,66 @@ impl<C: Component> ComponentBuilder<C> {
         )
     }
 
+    /// Similar to [`launch()`](ComponentBuilder::launch) but creates the init value
+    /// with a builder that is configured by `init`.
+    ///
+    /// ```ignore
+    /// let controller = App::builder()
+    ///     .launch_with(|init| init.title("Relm4").width(400))
+    ///     .detach();
+    /// ```
+    pub fn launch_with<F>(self, init: F) -> Connector<C>
+    where
+        C::Init: InitBuilder,
+        F: FnOnce(<C::Init as InitBuilder>::Builder) -> <C::Init as InitBuilder>::Builder,
+    {
+        self.launch(init(C::Init::builder()).into())
+    }
+
+    /// Similar to [`launch()`](ComponentBuilder::launch) but also restores a state
+    /// that was saved with [`ComponentController::save_state`] after initializing the component.
+    ///
+    /// If `state` is [`None`], the component is launched like usual.
+    pub fn launch_restored(self, payload: C::Init, state: Option<C::State>) -> Connector<C>
+    where
+        C: PersistentComponent,
+    {
+        let connector = self.launch(payload);
+        if let Some(state) = state {
+            // Borrowing mutably notifies the component to update its view.
+            connector.state().get_mut().model.restore_state(state);
+        }
+        connector
+    }
+
+    /// Similar to [`launch()`](ComponentBuilder::launch) but replaces the model
+    /// with a clone of `model` after initializing the component.
+    ///
+    /// This is useful for spawning a copy of an existing component,
+    /// for example to duplicate a tab.
+    /// Senders or other handles stored inside of the model still refer to the original component.
+    pub fn launch_cloned(self, payload: C::Init, model: &C) -> Connector<C>
+    where
+        C: Clone,
+    {
+        let connector = self.launch(payload);
+        // Borrowing mutably notifies the component to update its view.
+        connector.state().get_mut().model = model.clone();
+        connector
+    }
+
     fn launch_with_input_channel(
         self,
         payload: C::Init,
         input_sender: Sender<C::Input>,
         input_receiver: Receiver<C::Input>,
     ) -> Connector<C> {
-        let Self { root, priority, .. } = self;
+        let Self {
+            root,
+            priority,
+            middleware,
+            ..
+        } = self;
 
         let RuntimeSenders {
             output_sender,
@@ -182,9 +288,13 @@ impl<C: Component> ComponentBuilder<C> {
         // Gets notifications when a component's model and view is updated externally.
         let (notifier, notifier_receiver) = crate::channel();
 
+        // Inputs that are handled before regular inputs.
+        let (priority_sender, priority_receiver) = crate::channel::<C::Input>();
+
         // Encapsulates the senders used by component methods.
         let component_sender = ComponentSender::new(
             input_sender.clone(),
+            priority_sender.clone(),
             output_sender.clone(),
             cmd_sender,
             shutdown_recipient,
@@ -199,39 +309,62 @@ impl<C: Component> ComponentBuilder<C> {
         let watcher = StateWatcher {
             state,
             notifier,
+            released: Rc::default(),
+            priority_sender,
             shutdown_on_drop,
         };
 
         let rt_state = watcher.state.clone();
+        let rt_released = watcher.released.clone();
         let rt_root = root.clone();
 
+        // Notifies the runtime once the root widget is mapped.
+        let (post_init_sender, post_init_receiver) = crate::channel::<()>();
+        schedule_post_init(C::post_init_widget(&root), post_init_sender);
+
         // Spawns the component's service. It will receive both `Self::Input` and
         // `Self::CommandOutput` messages. It will spawn commands as requested by
         // updates, and send `Self::Output` messages externally.
         crate::spawn_local_with_priority(priority, async move {
+            let handle_input = |message: C::Input| {
+                let Some(message) = middleware.process(any::type_name::<C>(), message) else {
+                    return;
+                };
+
+                let ComponentParts { model, widgets } = &mut *rt_state.borrow_mut();
+
+                let span = info_span!(
+                    "update_with_view",
+                    input=?message,
+                    component=any::type_name::<C>(),
+                    id=model.id(),
+                );
+                let _enter = span.enter();
+
+                model.update_with_view(widgets, message, component_sender.clone(), &rt_root);
+            };
+
+            let pending_priority = priority_receiver.0.clone();
             let mut notifier = GuardedReceiver::new(notifier_receiver);
             let mut cmd = GuardedReceiver::new(cmd_receiver);
             let mut input = GuardedReceiver::new(input_receiver);
+            let mut priority_input = GuardedReceiver::new(priority_receiver);
+            let mut post_init = GuardedReceiver::new(post_init_receiver);
             loop {
+                // Handle all pending priority inputs before anything else.
+                while let Ok(message) = pending_priority.try_recv() {
+                    handle_input(message);
+                }
+
+                // Wakes up everyone who waits in `StateWatcher::get_async`.
+                rt_released.notify_waiters();
+
                 futures::select!(
                     // Performs the model update, checking if the update requested a command.
                     // Runs that command asynchronously in the background using tokio.
-                    message = input => {
-                        let ComponentParts {
-                            model,
-                            widgets,
-                        } = &mut *rt_state.borrow_mut();
-
-                        let span = info_span!(
-                            "update_with_view",
-                            input=?message,
-                            component=any::type_name::<C>(),
-                            id=model.id(),
-                        );
-                        let _enter = span.enter();
+                    message = input => handle_input(message),
 
-                        model.update_with_view(widgets, message, component_sender.clone(), &rt_root);
-                    }
+                    message = priority_input => handle_input(message),
 
                     // Handles responses from a command.
                     message = cmd => {
@@ -251,24 +384,29 @@ impl<C: Component> ComponentBuilder<C> {
                         model.update_cmd_with_view(widgets, message, component_sender.clone(), &rt_root);
                     }
 
-                    // Triggered when the model and view have been updated externally.
-                    _ = notifier => {
+                    // Runs the deferred initialization.
+                    _ = post_init => {
                         let ComponentParts {
                             model,
                             widgets,
                         } = &mut *rt_state.borrow_mut();
 
-                        model.update_view(widgets, component_sender.clone());
+                        model.post_init(widgets, component_sender.clone(), &rt_root);
                     }
 
-                    // Triggered when the component is destroyed
-                    _ = shutdown_event => {
+                    // Triggered when the model and view have been updated externally.
+                    _ = notifier => {
                         let ComponentParts {
                             model,
                             widgets,
                         } = &mut *rt_state.borrow_mut();
 
-                        model.shutdown(widgets, output_sender);
+                        model.update_view(widgets, component_sender.clone());
+                    }
+
+                    // Triggered when the component is destroyed
+                    _ = shutdown_event => {
+                        shutdown_component(&rt_state, output_sender).await;
 
                         shutdown_notifier.shutdown();
 
@@ -287,3 +425,18 @@ impl<C: Component> ComponentBuilder<C> {
         }
     }
 }
+
+/// Runs the synchronous and asynchronous shutdown hooks of a component.
+async fn shutdown_component<C: Component>(
+    state: &RefCell<ComponentParts<C>>,
+    output_sender: Sender<C::Output>,
+) {
+    // The state must not be borrowed while the hook runs.
+    let hook = {
+        let ComponentParts { model, widgets } = &mut *state.borrow_mut();
+        model.shutdown(widgets, output_sender.clone());
+        model.shutdown_async(widgets, output_sender)
+    };
+
+    run_shutdown_hook(hook, C::shutdown_deadline(), any::type_name::<C>()).await;
+}
@@ -5,7 +5,9 @@
 use super::stream::ComponentStream;
 use super::{Component, ComponentController, Controller, StateWatcher};
 use crate::{Receiver, Sender};
+use std::cell::RefCell;
 use std::fmt::{self, Debug};
+use std::rc::Rc;
 
 /// Contains the post-launch input sender and output receivers with the root widget.
 ///
@@ -47,6 +49,108 @@ impl<C: Component> Connector<C> {
         }
     }
 
+    /// Forwards output events to the designated sender,
+    /// dropping all events for which `transform` returns [`None`].
+    ///
+    /// This avoids adding a dummy "ignore" input variant to the parent
+    /// for outputs it isn't interested in.
+    pub fn filter_forward<X: 'static, F: (Fn(C::Output) -> Option<X>) + 'static>(
+        self,
+        sender_: &Sender<X>,
+        transform: F,
+    ) -> Controller<C> {
+        let Self {
+            state,
+            widget,
+            sender,
+            receiver,
+        } = self;
+
+        let sender_ = sender_.clone();
+        crate::spawn_local(async move {
+            while let Some(event) = receiver.recv().await {
+                if let Some(event) = transform(event) {
+                    if sender_.send(event).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+
+        Controller {
+            state,
+            widget,
+            sender,
+        }
+    }
+
+    /// Transforms the output events of the component before they are handled.
+    ///
+    /// Adapters can be chained before finishing with [`MappedConnector::forward`]
+    /// or another method for handling the events.
+    ///
+    /// ```ignore
+    /// let child = Child::builder()
+    ///     .launch(())
+    ///     .map(|output| output.value)
+    ///     .filter_forward(sender.input_sender(), |value| (value > 0).then_some(Msg::Value(value)));
+    /// ```
+    pub fn map<X: 'static, F: (Fn(C::Output) -> X) + 'static>(
+        self,
+        transform: F,
+    ) -> MappedConnector<C, X> {
+        MappedConnector {
+            connector: self,
+            transform: Box::new(move |event| Some(transform(event))),
+        }
+    }
+
+    /// Allows multiple listeners to receive the output events of the component.
+    ///
+    /// Each receiver created with [`OutputBroadcast::subscribe`] receives a clone of
+    /// every output event that is emitted after it subscribed.
+    /// Events are dropped while there are no subscribers.
+    ///
+    /// ```ignore
+    /// let (child, outputs) = Child::builder().launch(()).broadcast();
+    /// relm4::spawn_local(outputs.subscribe().forward(sender.input_sender().clone(), Msg::Child));
+    /// relm4::spawn_local(outputs.subscribe().forward(analytics.clone(), AnalyticsEvent::from));
+    /// ```
+    pub fn broadcast(self) -> (Controller<C>, OutputBroadcast<C::Output>)
+    where
+        C::Output: Clone,
+    {
+        let Self {
+            state,
+            widget,
+            sender,
+            receiver,
+        } = self;
+
+        let broadcast = OutputBroadcast {
+            subscribers: Rc::default(),
+        };
+
+        let subscribers = broadcast.subscribers.clone();
+        crate::spawn_local(async move {
+            while let Some(event) = receiver.recv().await {
+                // Remove all subscribers which had their receivers dropped.
+                subscribers
+                    .borrow_mut()
+                    .retain(|subscriber| subscriber.send(event.clone()).is_ok());
+            }
+        });
+
+        (
+            Controller {
+                state,
+                widget,
+                sender,
+            },
+            broadcast,
+        )
+    }
+
     /// Given a mutable closure, captures the receiver for handling.
     pub fn connect_receiver<F: FnMut(&mut Sender<C::Input>, C::Output) + 'static>(
         self,
@@ -135,3 +239,139 @@ where
             .finish()
     }
 }
+
+type TransformFn<Input, Output> = dyn Fn(Input) -> Option<Output>;
+
+/// A [`Connector`] with transformed output events.
+///
+/// Created by [`Connector::map`].
+pub struct MappedConnector<C: Component, X> {
+    connector: Connector<C>,
+    transform: Box<TransformFn<C::Output, X>>,
+}
+
+impl<C: Component, X: 'static> MappedConnector<C, X> {
+    /// Transforms the output events further.
+    pub fn map<Y: 'static, F: (Fn(X) -> Y) + 'static>(self, transform: F) -> MappedConnector<C, Y> {
+        let Self {
+            connector,
+            transform: previous,
+        } = self;
+
+        MappedConnector {
+            connector,
+            transform: Box::new(move |event| previous(event).map(&transform)),
+        }
+    }
+
+    /// Drops all output events for which `predicate` returns [`false`].
+    pub fn filter<F: (Fn(&X) -> bool) + 'static>(self, predicate: F) -> Self {
+        let Self {
+            connector,
+            transform: previous,
+        } = self;
+
+        Self {
+            connector,
+            transform: Box::new(move |event| previous(event).filter(&predicate)),
+        }
+    }
+
+    /// Forwards the transformed output events to the designated sender.
+    pub fn forward<Y: 'static, F: (Fn(X) -> Y) + 'static>(
+        self,
+        sender: &Sender<Y>,
+        transform: F,
+    ) -> Controller<C> {
+        let Self {
+            connector,
+            transform: previous,
+        } = self;
+
+        connector.filter_forward(sender, move |event| previous(event).map(&transform))
+    }
+
+    /// Forwards the transformed output events to the designated sender,
+    /// dropping all events for which `transform` returns [`None`].
+    pub fn filter_forward<Y: 'static, F: (Fn(X) -> Option<Y>) + 'static>(
+        self,
+        sender: &Sender<Y>,
+        transform: F,
+    ) -> Controller<C> {
+        let Self {
+            connector,
+            transform: previous,
+        } = self;
+
+        connector.filter_forward(sender, move |event| previous(event).and_then(&transform))
+    }
+
+    /// Ignore outputs from the component and finish the builder.
+    pub fn detach(self) -> Controller<C> {
+        self.connector.detach()
+    }
+}
+
+impl<C: Component, X> ComponentController<C> for MappedConnector<C, X> {
+    fn sender(&self) -> &Sender<C::Input> {
+        self.connector.sender()
+    }
+
+    fn state(&self) -> &StateWatcher<C> {
+        self.connector.state()
+    }
+
+    fn widget(&self) -> &C::Root {
+        self.connector.widget()
+    }
+
+    fn detach_runtime(&mut self) {
+        self.connector.detach_runtime();
+    }
+}
+
+impl<C, X> Debug for MappedConnector<C, X>
+where
+    C: Component + Debug,
+    C::Widgets: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MappedConnector")
+            .field("connector", &self.connector)
+            .field("transform", &"<transform fn>")
+            .finish()
+    }
+}
+
+/// Distributes the output events of a component to multiple receivers.
+///
+/// Created by [`Connector::broadcast`].
+pub struct OutputBroadcast<T> {
+    subscribers: Rc<RefCell<Vec<Sender<T>>>>,
+}
+
+impl<T> OutputBroadcast<T> {
+    /// Creates a new receiver for the output events.
+    #[must_use]
+    pub fn subscribe(&self) -> Receiver<T> {
+        let (sender, receiver) = crate::channel();
+        self.subscribers.borrow_mut().push(sender);
+        receiver
+    }
+}
+
+impl<T> Clone for OutputBroadcast<T> {
+    fn clone(&self) -> Self {
+        Self {
+            subscribers: self.subscribers.clone(),
+        }
+    }
+}
+
+impl<T> Debug for OutputBroadcast<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OutputBroadcast")
+            .field("subscribers", &self.subscribers.borrow().len())
+            .finish()
+    }
+}
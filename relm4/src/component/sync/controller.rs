@@ -4,10 +4,12 @@
 
 use std::cell::Ref;
 use std::fmt::{self, Debug};
+use std::future::Future;
 
-use crate::Sender;
+use crate::{Sender, TrySendError};
 
-use super::{Component, StateWatcher};
+use super::{Component, ComponentParts, Connector, StateWatcher};
+use crate::component::PersistentComponent;
 
 /// Shared behavior of component controller types.
 pub trait ComponentController<C: Component> {
@@ -16,9 +18,32 @@ pub trait ComponentController<C: Component> {
         self.sender().send(event).unwrap();
     }
 
+    /// Emits an input to the component without blocking.
+    ///
+    /// Returns [`TrySendError::Full`] if the component was launched with a
+    /// [bounded](super::ComponentBuilder::bounded) input channel that is full.
+    fn try_emit(&self, event: C::Input) -> Result<(), TrySendError<C::Input>> {
+        self.sender().try_send(event)
+    }
+
     /// Provides access to the component's sender.
     fn sender(&self) -> &Sender<C::Input>;
 
+    /// Emits an input to the component that is handled before all pending regular inputs.
+    ///
+    /// This is useful for urgent messages like "cancel" while the component
+    /// is busy processing a large backlog of messages.
+    fn emit_priority(&self, event: C::Input) {
+        self.priority_sender().send(event).unwrap();
+    }
+
+    /// Provides access to the component's sender for priority inputs.
+    ///
+    /// See [`emit_priority`](Self::emit_priority) for more information.
+    fn priority_sender(&self) -> &Sender<C::Input> {
+        &self.state().priority_sender
+    }
+
     /// Provides access to the state of a component.
     fn state(&self) -> &StateWatcher<C>;
 
@@ -34,9 +59,56 @@ pub trait ComponentController<C: Component> {
         Ref::map(part_ref, |part| &part.widgets)
     }
 
+    /// Tries to borrow the model and view of the [`Component`].
+    ///
+    /// Returns [`None`] instead of panicking if the component is currently being updated.
+    fn try_state(&self) -> Option<Ref<'_, ComponentParts<C>>> {
+        self.state().try_get()
+    }
+
+    /// Tries to return a reference to the [`Component`].
+    ///
+    /// Returns [`None`] instead of panicking if the component is currently being updated.
+    fn try_model(&self) -> Option<Ref<'_, C>> {
+        let part_ref = self.state().try_get()?;
+        Some(Ref::map(part_ref, |part| &part.model))
+    }
+
+    /// Returns a reference to the [`Component`] once its current update cycle has finished.
+    ///
+    /// See [`StateWatcher::get_async`] for more information.
+    fn model_async(&self) -> impl Future<Output = Ref<'_, C>>
+    where
+        Self: Sized,
+    {
+        async {
+            let part_ref = self.state().get_async().await;
+            Ref::map(part_ref, |part| &part.model)
+        }
+    }
+
     /// Returns the root widget of the component.
     fn widget(&self) -> &C::Root;
 
+    /// Captures the state of the [`Component`] so it can be
+    /// restored with [`ComponentBuilder::launch_restored`](crate::ComponentBuilder::launch_restored).
+    fn save_state(&self) -> C::State
+    where
+        C: PersistentComponent,
+    {
+        self.model().save_state()
+    }
+
+    /// Launches a new instance of the [`Component`] with a clone of the current model.
+    ///
+    /// See [`ComponentBuilder::launch_cloned`](crate::ComponentBuilder::launch_cloned) for more information.
+    fn fork(&self, payload: C::Init) -> Connector<C>
+    where
+        C: Clone,
+    {
+        C::builder().launch_cloned(payload, &self.model())
+    }
+
     /// Dropping this type will usually stop the runtime of the component.
     /// With this method you can give the runtime a static lifetime.
     /// In other words, dropping the controller or connector will not stop
@@ -0,0 +1,96 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use gtk::prelude::WidgetExt;
+
+use super::{Component, ComponentController, Connector, Controller};
+use crate::Sender;
+
+thread_local! {
+    /// Controllers of components that live as long as their root window.
+    static KEPT_ALIVE: RefCell<HashMap<ComponentId, Box<dyn Any>>> = RefCell::default();
+}
+
+/// Identifies a component that was kept alive with [`Controller::keep_alive`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ComponentId(u64);
+
+impl ComponentId {
+    fn next() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Returns the input sender of the component if it's still alive and of type `C`.
+    #[must_use]
+    pub fn sender<C: Component>(self) -> Option<Sender<C::Input>> {
+        KEPT_ALIVE.with(|kept_alive| {
+            kept_alive
+                .borrow()
+                .get(&self)?
+                .downcast_ref::<Controller<C>>()
+                .map(|controller| controller.sender().clone())
+        })
+    }
+
+    /// Returns `true` if the component is still alive.
+    #[must_use]
+    pub fn is_alive(self) -> bool {
+        KEPT_ALIVE.with(|kept_alive| kept_alive.borrow().contains_key(&self))
+    }
+
+    /// Drops the controller of the component, which shuts it down.
+    ///
+    /// Returns `false` if the component was already shut down.
+    pub fn release(self) -> bool {
+        // Drop the controller after the borrow ended, because its shutdown might release other components.
+        let controller = KEPT_ALIVE.with(|kept_alive| kept_alive.borrow_mut().remove(&self));
+        controller.is_some()
+    }
+}
+
+impl<C> Controller<C>
+where
+    C: Component,
+    C::Root: AsRef<gtk::Window>,
+{
+    /// Keeps the component alive as long as its root window isn't destroyed.
+    ///
+    /// Unlike [`ComponentController::detach_runtime`], the component is shut down once
+    /// its root window is destroyed, which happens when it's closed
+    /// unless [`hide-on-close`](gtk::Window::set_hide_on_close) is set.
+    /// Hidden windows keep the component alive until [`ComponentId::release`] is called.
+    /// The returned [`ComponentId`] can be used to obtain the sender of
+    /// the component later or to shut it down early.
+    ///
+    /// Only windows are supported because other widgets aren't destroyed
+    /// when they are removed from their parent, which would leak the component.
+    ///
+    /// Must be called from the main thread.
+    pub fn keep_alive(self) -> ComponentId {
+        let id = ComponentId::next();
+
+        self.widget().as_ref().connect_destroy(move |_| {
+            id.release();
+        });
+        KEPT_ALIVE.with(|kept_alive| kept_alive.borrow_mut().insert(id, Box::new(self)));
+
+        id
+    }
+}
+
+impl<C> Connector<C>
+where
+    C: Component,
+    C::Root: AsRef<gtk::Window>,
+{
+    /// Ignores outputs from the component and keeps it alive as long as
+    /// its root window isn't destroyed.
+    ///
+    /// See [`Controller::keep_alive`] for more information.
+    pub fn keep_alive(self) -> ComponentId {
+        self.detach().keep_alive()
+    }
+}
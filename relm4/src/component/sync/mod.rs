@@ -2,16 +2,20 @@
 // Copyright 2022 System76 <info@system76.com>
 // SPDX-License-Identifier: MIT or Apache-2.0
 
+mod any_controller;
 mod builder;
 mod connector;
 mod controller;
+mod keep_alive;
 mod state_watcher;
 mod stream;
 mod traits;
 
+pub use any_controller::AnyController;
 pub use builder::ComponentBuilder;
-pub use connector::Connector;
+pub use connector::{Connector, MappedConnector, OutputBroadcast};
 pub use controller::{ComponentController, Controller};
+pub use keep_alive::ComponentId;
 pub use state_watcher::StateWatcher;
 pub use stream::ComponentStream;
 pub use traits::{Component, SimpleComponent};
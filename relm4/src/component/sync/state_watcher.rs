@@ -3,6 +3,11 @@ use crate::{Component, ComponentParts, ShutdownOnDrop};
 use std::cell::{Ref, RefCell, RefMut};
 use std::fmt::{self, Debug};
 use std::rc::Rc;
+use std::time::Duration;
+
+use futures::future;
+use gtk::glib;
+use tokio::sync::Notify;
 
 /// Keeps track of a components model and view.
 ///
@@ -11,6 +16,10 @@ pub struct StateWatcher<C: Component> {
     /// The models and widgets maintained by the component.
     pub(super) state: Rc<RefCell<ComponentParts<C>>>,
     pub(super) notifier: crate::Sender<()>,
+    /// Notified by the runtime whenever it waits for new messages,
+    /// which means that the model and view aren't borrowed by the component.
+    pub(super) released: Rc<Notify>,
+    pub(super) priority_sender: crate::Sender<C::Input>,
     pub(super) shutdown_on_drop: ShutdownOnDrop,
 }
 
@@ -28,6 +37,48 @@ impl<C: Component> StateWatcher<C> {
         self.state.borrow_mut()
     }
 
+    /// Tries to borrow the model and view of a component.
+    ///
+    /// Returns [`None`] instead of panicking if the component
+    /// is currently being updated.
+    #[must_use]
+    pub fn try_get(&self) -> Option<Ref<'_, ComponentParts<C>>> {
+        self.state.try_borrow().ok()
+    }
+
+    /// Tries to borrow the model and view of a component mutably,
+    /// and notifies the component to check for updates on success.
+    ///
+    /// Returns [`None`] instead of panicking if the model
+    /// and view are currently borrowed.
+    #[must_use]
+    pub fn try_get_mut(&self) -> Option<RefMut<'_, ComponentParts<C>>> {
+        let state = self.state.try_borrow_mut().ok()?;
+        self.notifier.send(()).unwrap();
+        Some(state)
+    }
+
+    /// Borrows the model and view of a component once the
+    /// current update cycle of the component has finished.
+    ///
+    /// Unlike [`get()`](Self::get), this never panics.
+    /// Don't hold the returned reference across await points.
+    pub async fn get_async(&self) -> Ref<'_, ComponentParts<C>> {
+        loop {
+            // Register before checking the state, so no notification is missed.
+            let released = self.released.notified();
+            if let Ok(state) = self.state.try_borrow() {
+                return state;
+            }
+
+            // The state can also be borrowed outside of the runtime, for example
+            // with `get_mut()`, so check again after the next main loop iteration.
+            let next_iteration = glib::timeout_future(Duration::ZERO);
+            futures::pin_mut!(released);
+            future::select(released, next_iteration).await;
+        }
+    }
+
     pub(super) fn detach_runtime(&mut self) {
         self.shutdown_on_drop.deactivate()
     }
@@ -42,7 +93,47 @@ where
         f.debug_struct("StateWatcher")
             .field("state", &self.state)
             .field("notifier", &self.notifier)
+            .field("released", &self.released)
             .field("shutdown_on_drop", &self.shutdown_on_drop)
             .finish()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use gtk::glib;
+
+    use crate::{Component, ComponentController};
+
+    #[gtk::test]
+    fn get_async_waits_for_external_borrows() {
+        let controller = Rc::new(<()>::builder().launch(()).detach());
+        let borrowed = controller.state().state.borrow_mut();
+
+        let done = Rc::new(Cell::new(false));
+        crate::spawn_local({
+            let controller = controller.clone();
+            let done = done.clone();
+            async move {
+                let _state = controller.state().get_async().await;
+                done.set(true);
+            }
+        });
+
+        let context = glib::MainContext::default();
+        for _ in 0..10 {
+            context.iteration(false);
+        }
+        assert!(!done.get());
+
+        // The runtime isn't involved, so only polling picks up the release.
+        drop(borrowed);
+        for _ in 0..10 {
+            context.iteration(false);
+        }
+        assert!(done.get());
+    }
+}
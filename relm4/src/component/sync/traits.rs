@@ -3,7 +3,9 @@
 // SPDX-License-Identifier: MIT or Apache-2.0
 
 use std::fmt::Debug;
+use std::time::Duration;
 
+use crate::runtime_util::DEFAULT_SHUTDOWN_DEADLINE;
 use crate::{ComponentBuilder, ComponentParts, ComponentSender, Sender};
 
 /// The fundamental building block of a Relm4 application.
@@ -92,6 +94,36 @@ pub trait Component: Sized + 'static {
     #[allow(unused)]
     fn update_view(&self, widgets: &mut Self::Widgets, sender: ComponentSender<Self>) {}
 
+    /// Deferred initialization that runs once after [`init`](Self::init).
+    ///
+    /// This method is called once the widget returned by
+    /// [`post_init_widget`](Self::post_init_widget) is mapped, so the root
+    /// can already be measured. If the root is mapped before the runtime starts,
+    /// it's called right away. Use it for expensive work like loading data,
+    /// so the window can appear instantly with placeholders.
+    ///
+    /// This method does nothing by default.
+    #[allow(unused)]
+    fn post_init(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        sender: ComponentSender<Self>,
+        root: &Self::Root,
+    ) {
+    }
+
+    /// The widget whose `map` signal triggers [`post_init`](Self::post_init).
+    ///
+    /// The [`component`](relm4_macros::component) macro implements this method
+    /// for components with a single root widget.
+    /// If this returns [`None`], [`post_init`](Self::post_init) is called
+    /// as soon as the main loop becomes idle instead.
+    #[allow(unused)]
+    #[must_use]
+    fn post_init_widget(root: &Self::Root) -> Option<gtk::Widget> {
+        None
+    }
+
     /// Updates the model and view when a new input is received.
     ///
     /// Overriding this method is helpful if you need access to the widgets while processing an
@@ -122,6 +154,35 @@ pub trait Component: Sized + 'static {
     #[allow(unused)]
     fn shutdown(&mut self, widgets: &mut Self::Widgets, output: Sender<Self::Output>) {}
 
+    /// Asynchronous cleanup that runs after [`shutdown`](Self::shutdown).
+    ///
+    /// The runtime awaits the returned future before the component is destroyed,
+    /// which is useful to flush network writes or close files.
+    /// Once the application quits, Relm4 waits for all pending shutdown futures.
+    /// Futures that take longer than [`shutdown_deadline`](Self::shutdown_deadline)
+    /// are cancelled.
+    ///
+    /// The model and widgets aren't borrowed while the future runs,
+    /// so it must own everything it needs, for example by moving or cloning it out of the model.
+    ///
+    /// This method does nothing by default.
+    #[allow(unused)]
+    fn shutdown_async(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        output: Sender<Self::Output>,
+    ) -> impl std::future::Future<Output = ()> + 'static {
+        async {}
+    }
+
+    /// The maximum time the runtime waits for [`shutdown_async`](Self::shutdown_async).
+    ///
+    /// Defaults to five seconds.
+    #[must_use]
+    fn shutdown_deadline() -> Duration {
+        DEFAULT_SHUTDOWN_DEADLINE
+    }
+
     /// An identifier for the component used for debug logging.
     ///
     /// The default implementation of this method uses the address of the component, but
@@ -170,11 +231,46 @@ pub trait SimpleComponent: Sized + 'static {
     #[allow(unused)]
     fn update_view(&self, widgets: &mut Self::Widgets, sender: ComponentSender<Self>) {}
 
+    /// Deferred initialization that runs once after [`init`](Self::init).
+    ///
+    /// See [`Component::post_init`] for more information.
+    #[allow(unused)]
+    fn post_init(&mut self, widgets: &mut Self::Widgets, sender: ComponentSender<Self>) {}
+
+    /// The widget whose `map` signal triggers [`post_init`](Self::post_init).
+    ///
+    /// See [`Component::post_init_widget`] for more information.
+    #[allow(unused)]
+    #[must_use]
+    fn post_init_widget(root: &Self::Root) -> Option<gtk::Widget> {
+        None
+    }
+
     /// Last method called before a component is shut down.
     ///
     /// This method is guaranteed to be called even when the entire application is shut down.
     #[allow(unused)]
     fn shutdown(&mut self, widgets: &mut Self::Widgets, output: Sender<Self::Output>) {}
+
+    /// Asynchronous cleanup that runs after [`shutdown`](Self::shutdown).
+    ///
+    /// See [`Component::shutdown_async`] for more information.
+    #[allow(unused)]
+    fn shutdown_async(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        output: Sender<Self::Output>,
+    ) -> impl std::future::Future<Output = ()> + 'static {
+        async {}
+    }
+
+    /// The maximum time the runtime waits for [`shutdown_async`](Self::shutdown_async).
+    ///
+    /// Defaults to five seconds.
+    #[must_use]
+    fn shutdown_deadline() -> Duration {
+        DEFAULT_SHUTDOWN_DEADLINE
+    }
 }
 
 impl<C> Component for C
@@ -209,9 +305,34 @@ where
         C::update_view(self, widgets, sender);
     }
 
+    fn post_init(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        sender: ComponentSender<Self>,
+        _root: &Self::Root,
+    ) {
+        C::post_init(self, widgets, sender);
+    }
+
+    fn post_init_widget(root: &Self::Root) -> Option<gtk::Widget> {
+        C::post_init_widget(root)
+    }
+
     fn shutdown(&mut self, widgets: &mut Self::Widgets, output: Sender<Self::Output>) {
         self.shutdown(widgets, output);
     }
+
+    fn shutdown_async(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        output: Sender<Self::Output>,
+    ) -> impl std::future::Future<Output = ()> + 'static {
+        C::shutdown_async(self, widgets, output)
+    }
+
+    fn shutdown_deadline() -> Duration {
+        C::shutdown_deadline()
+    }
 }
 
 /// An empty, non-interactive component as a placeholder for tests.
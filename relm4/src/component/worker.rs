@@ -71,109 +71,253 @@ where
     C::Output: Send,
     C::CommandOutput: Send,
 {
+    /// Sets the name of the worker thread, which is shown in debuggers and profilers.
+    ///
+    /// The threads of a worker pool are named `{name}-{index}`.
+    #[must_use]
+    pub fn thread_name(mut self, name: impl Into<String>) -> Self {
+        self.thread_name = Some(name.into());
+        self
+    }
+
+    /// Sets the stack size of the worker thread in bytes.
+    ///
+    /// By default, the stack size of [`std::thread`] is used.
+    #[must_use]
+    pub const fn stack_size(mut self, size: usize) -> Self {
+        self.stack_size = Some(size);
+        self
+    }
+
+    /// Creates the configuration of a worker thread.
+    fn thread_builder(&self, name: Option<String>) -> thread::Builder {
+        let mut builder = thread::Builder::new();
+        if let Some(name) = name {
+            builder = builder.name(name);
+        }
+        if let Some(size) = self.stack_size {
+            builder = builder.stack_size(size);
+        }
+        builder
+    }
+
     /// Starts a worker on a separate thread,
     /// passing ownership to a future attached to a [gtk::glib::MainContext].
     pub fn detach_worker(self, payload: C::Init) -> WorkerHandle<C> {
-        let Self { root, .. } = self;
-
         // Used for all events to be processed by this component's internal service.
-        let (input_sender, input_receiver) = crate::channel::<C::Input>();
-
-        let RuntimeSenders {
+        let (input_sender, input_receiver) = self.input_channel();
+
+        // Inputs that are handled before regular inputs.
+        let (priority_sender, priority_receiver) = crate::channel();
+
+        // Used by this component to send events to be handled externally by the caller.
+        let (output_sender, output_receiver) = crate::channel();
+
+        let thread = self.thread_builder(self.thread_name.clone());
+        let shutdown_on_drop = spawn_worker::<C>(
+            thread,
+            payload,
+            WorkerInputs {
+                input_sender: input_sender.clone(),
+                input_receiver,
+                priority_sender: priority_sender.clone(),
+                priority_receiver,
+            },
             output_sender,
-            output_receiver,
-            cmd_sender,
-            cmd_receiver,
-            shutdown_notifier,
-            shutdown_recipient,
-            shutdown_on_drop,
-            mut shutdown_event,
-        } = RuntimeSenders::<C::Output, C::CommandOutput>::new();
-
-        // Encapsulates the senders used by component methods.
-        let component_sender = ComponentSender::new(
-            input_sender.clone(),
-            output_sender.clone(),
-            cmd_sender,
-            shutdown_recipient,
         );
 
-        let mut state = C::init(payload, root, component_sender.clone());
-
-        thread::spawn(move || {
-            let context = glib::MainContext::thread_default().unwrap_or_default();
-
-            // Spawns the component's service. It will receive both `Self::Input` and
-            // `Self::CommandOutput` messages. It will spawn commands as requested by
-            // updates, and send `Self::Output` messages externally.
-            context.block_on(async move {
-                let mut cmd = GuardedReceiver::new(cmd_receiver);
-                let mut input = GuardedReceiver::new(input_receiver);
-
-                loop {
-                    futures::select!(
-                        // Performs the model update, checking if the update requested a command.
-                        // Runs that command asynchronously in the background using tokio.
-                        message = input => {
-                            let ComponentParts {
-                                model,
-                                widgets,
-                            } = &mut state;
-
-                            let span = info_span!(
-                                "update_with_view",
-                                input=?message,
-                                component=any::type_name::<C>(),
-                                id=model.id(),
-                            );
-                            let _enter = span.enter();
-
-                            model.update_with_view(widgets, message, component_sender.clone(), &root);
-                        }
-
-                        // Handles responses from a command.
-                        message = cmd => {
-                            let ComponentParts {
-                                model,
-                                widgets,
-                            } = &mut state;
-
-                            let span = info_span!(
-                                "update_cmd_with_view",
-                                cmd_output=?message,
-                                component=any::type_name::<C>(),
-                                id=model.id(),
-                            );
-                            let _enter = span.enter();
-
-                            model.update_cmd_with_view(widgets, message, component_sender.clone(), &root);
-                        },
-
-                        // Triggered when the component is destroyed
-                        _ = shutdown_event => {
-                            let ComponentParts {
-                                model,
-                                widgets,
-                            } = &mut state;
-
-                            model.shutdown(widgets, output_sender);
-
-                            shutdown_notifier.shutdown();
-
-                            return;
-                        }
-                    );
-                }
-            });
-        });
-
         // Give back a type for controlling the component service.
         WorkerHandle {
             sender: input_sender,
+            priority_sender,
             receiver: output_receiver,
             shutdown_on_drop,
         }
     }
+
+    /// Starts `size` copies of a worker, each on a separate thread.
+    ///
+    /// All workers receive their inputs from the same queue, so a message
+    /// is always handled by the next worker that's idle.
+    /// The outputs of all workers are sent to a single receiver.
+    ///
+    /// Because each message is only handled by one worker, this is useful
+    /// for independent CPU-bound jobs like generating thumbnails.
+    /// Note that messages a worker sends to itself through its [`ComponentSender`]
+    /// are added to the shared queue and might be handled by another worker.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn detach_worker_pool(self, size: usize, payload: C::Init) -> WorkerPoolHandle<C>
+    where
+        C::Init: Clone,
+    {
+        assert!(size > 0, "a worker pool needs at least one worker");
+
+        // All workers share the same input queues.
+        let (input_sender, input_receiver) = self.input_channel();
+        let (priority_sender, priority_receiver) = crate::channel();
+
+        // Collects the outputs of all workers.
+        let (output_sender, output_receiver) = crate::channel();
+
+        let shutdown_on_drop = (0..size)
+            .map(|index| {
+                let name = self
+                    .thread_name
+                    .as_ref()
+                    .map(|name| format!("{name}-{index}"));
+                spawn_worker::<C>(
+                    self.thread_builder(name),
+                    payload.clone(),
+                    WorkerInputs {
+                        input_sender: input_sender.clone(),
+                        input_receiver: Receiver(input_receiver.0.clone()),
+                        priority_sender: priority_sender.clone(),
+                        priority_receiver: Receiver(priority_receiver.0.clone()),
+                    },
+                    output_sender.clone(),
+                )
+            })
+            .collect();
+
+        WorkerPoolHandle {
+            sender: input_sender,
+            priority_sender,
+            receiver: output_receiver,
+            shutdown_on_drop,
+        }
+    }
+}
+
+/// The channels that deliver inputs to a worker.
+struct WorkerInputs<Input> {
+    input_sender: Sender<Input>,
+    input_receiver: Receiver<Input>,
+    priority_sender: Sender<Input>,
+    priority_receiver: Receiver<Input>,
+}
+
+/// Initializes a worker and runs it on a new thread.
+fn spawn_worker<C>(
+    thread: thread::Builder,
+    payload: C::Init,
+    inputs: WorkerInputs<C::Input>,
+    output_sender: Sender<C::Output>,
+) -> ShutdownOnDrop
+where
+    C: Component<Root = (), Widgets = ()> + Send,
+    C::Input: Send,
+    C::Output: Send,
+    C::CommandOutput: Send,
+{
+    let RuntimeSenders {
+        cmd_sender,
+        cmd_receiver,
+        shutdown_notifier,
+        shutdown_recipient,
+        shutdown_on_drop,
+        mut shutdown_event,
+        ..
+    } = RuntimeSenders::<C::Output, C::CommandOutput>::new_worker();
+
+    let WorkerInputs {
+        input_sender,
+        input_receiver,
+        priority_sender,
+        priority_receiver,
+    } = inputs;
+
+    // Encapsulates the senders used by component methods.
+    let component_sender = ComponentSender::new(
+        input_sender,
+        priority_sender,
+        output_sender.clone(),
+        cmd_sender,
+        shutdown_recipient,
+    );
+
+    let root = ();
+    let mut state = C::init(payload, root, component_sender.clone());
+
+    let result = thread.spawn(move || {
+        let context = glib::MainContext::thread_default().unwrap_or_default();
+
+        // Spawns the component's service. It will receive both `Self::Input` and
+        // `Self::CommandOutput` messages. It will spawn commands as requested by
+        // updates, and send `Self::Output` messages externally.
+        context.block_on(async move {
+            let handle_input = |state: &mut ComponentParts<C>, message: C::Input| {
+                let ComponentParts { model, widgets } = state;
+
+                let span = info_span!(
+                    "update_with_view",
+                    input=?message,
+                    component=any::type_name::<C>(),
+                    id=model.id(),
+                );
+                let _enter = span.enter();
+
+                model.update_with_view(widgets, message, component_sender.clone(), &root);
+            };
+
+            let pending_priority = priority_receiver.0.clone();
+            let mut cmd = GuardedReceiver::new(cmd_receiver);
+            let mut input = GuardedReceiver::new(input_receiver);
+            let mut priority_input = GuardedReceiver::new(priority_receiver);
+
+            loop {
+                // Handle all pending priority inputs before anything else.
+                while let Ok(message) = pending_priority.try_recv() {
+                    handle_input(&mut state, message);
+                }
+
+                futures::select!(
+                    // Performs the model update, checking if the update requested a command.
+                    // Runs that command asynchronously in the background using tokio.
+                    message = input => handle_input(&mut state, message),
+
+                    message = priority_input => handle_input(&mut state, message),
+
+                    // Handles responses from a command.
+                    message = cmd => {
+                        let ComponentParts {
+                            model,
+                            widgets,
+                        } = &mut state;
+
+                        let span = info_span!(
+                            "update_cmd_with_view",
+                            cmd_output=?message,
+                            component=any::type_name::<C>(),
+                            id=model.id(),
+                        );
+                        let _enter = span.enter();
+
+                        model.update_cmd_with_view(widgets, message, component_sender.clone(), &root);
+                    },
+
+                    // Triggered when the component is destroyed
+                    _ = shutdown_event => {
+                        let ComponentParts {
+                            model,
+                            widgets,
+                        } = &mut state;
+
+                        model.shutdown(widgets, output_sender);
+
+                        shutdown_notifier.shutdown();
+
+                        return;
+                    }
+                );
+            }
+        });
+    });
+    result.expect("Couldn't spawn the worker thread");
+
+    shutdown_on_drop
 }
 
 #[derive(Debug)]
@@ -181,6 +325,8 @@ where
 pub struct WorkerHandle<W: Component> {
     // Sends inputs to the worker.
     sender: Sender<W::Input>,
+    // Sends inputs that are handled before regular inputs.
+    priority_sender: Sender<W::Input>,
     // Where the worker will send its outputs to.
     receiver: Receiver<W::Output>,
     // Shutdown the worker when this is dropped
@@ -199,6 +345,7 @@ where
     ) -> WorkerController<W> {
         let Self {
             sender,
+            priority_sender,
             receiver,
             shutdown_on_drop,
         } = self;
@@ -212,6 +359,7 @@ where
 
         WorkerController {
             sender,
+            priority_sender,
             shutdown_on_drop,
         }
     }
@@ -224,6 +372,7 @@ where
     ) -> WorkerController<W> {
         let Self {
             sender: own_sender,
+            priority_sender,
             receiver,
             shutdown_on_drop,
         } = self;
@@ -231,6 +380,7 @@ where
         crate::spawn_local(receiver.forward(sender.clone(), transform));
         WorkerController {
             sender: own_sender,
+            priority_sender,
             shutdown_on_drop,
         }
     }
@@ -240,12 +390,14 @@ where
     pub fn detach(self) -> WorkerController<W> {
         let Self {
             sender,
+            priority_sender,
             shutdown_on_drop,
             ..
         } = self;
 
         WorkerController {
             sender,
+            priority_sender,
             shutdown_on_drop,
         }
     }
@@ -256,6 +408,8 @@ where
 pub struct WorkerController<W: Component> {
     // Sends inputs to the worker.
     sender: Sender<W::Input>,
+    // Sends inputs that are handled before regular inputs.
+    priority_sender: Sender<W::Input>,
     // Shutdown the worker when this is dropped
     shutdown_on_drop: ShutdownOnDrop,
 }
@@ -272,6 +426,22 @@ impl<W: Component> WorkerController<W> {
         &self.sender
     }
 
+    /// Emits an input to the worker that is handled before all pending regular inputs.
+    ///
+    /// This is useful for messages like "cancel" that should preempt a long queue of jobs.
+    /// Note that the job the worker is currently processing isn't interrupted.
+    pub fn emit_priority(&self, event: W::Input) {
+        self.priority_sender.send(event).unwrap();
+    }
+
+    /// Provides access to the worker's sender for priority inputs.
+    ///
+    /// See [`emit_priority`](Self::emit_priority) for more information.
+    #[must_use]
+    pub const fn priority_sender(&self) -> &Sender<W::Input> {
+        &self.priority_sender
+    }
+
     /// Dropping this type will usually stop the runtime of the worker.
     /// With this method you can give the runtime a static lifetime.
     /// In other words, dropping the [`WorkerController`] will not stop
@@ -280,3 +450,149 @@ impl<W: Component> WorkerController<W> {
         self.shutdown_on_drop.deactivate();
     }
 }
+
+#[derive(Debug)]
+/// Handle to a pool of workers in the background.
+pub struct WorkerPoolHandle<W: Component> {
+    // Sends inputs to the shared queue of the workers.
+    sender: Sender<W::Input>,
+    // Sends inputs that are handled before regular inputs.
+    priority_sender: Sender<W::Input>,
+    // Where the workers will send their outputs to.
+    receiver: Receiver<W::Output>,
+    // Shutdown the workers when this is dropped
+    shutdown_on_drop: Vec<ShutdownOnDrop>,
+}
+
+impl<W: Component> WorkerPoolHandle<W>
+where
+    W::Input: 'static,
+    W::Output: 'static,
+{
+    /// Given a mutable closure, captures the receiver for handling.
+    pub fn connect_receiver<F: FnMut(&mut Sender<W::Input>, W::Output) + 'static>(
+        self,
+        mut func: F,
+    ) -> WorkerPool<W> {
+        let Self {
+            sender,
+            priority_sender,
+            receiver,
+            shutdown_on_drop,
+        } = self;
+
+        let mut sender_ = sender.clone();
+        crate::spawn_local(async move {
+            while let Some(event) = receiver.recv().await {
+                func(&mut sender_, event);
+            }
+        });
+
+        WorkerPool {
+            sender,
+            priority_sender,
+            shutdown_on_drop,
+        }
+    }
+
+    /// Forwards output events to the designated sender.
+    pub fn forward<X: 'static, F: (Fn(W::Output) -> X) + 'static>(
+        self,
+        sender: &Sender<X>,
+        transform: F,
+    ) -> WorkerPool<W> {
+        let Self {
+            sender: own_sender,
+            priority_sender,
+            receiver,
+            shutdown_on_drop,
+        } = self;
+
+        crate::spawn_local(receiver.forward(sender.clone(), transform));
+        WorkerPool {
+            sender: own_sender,
+            priority_sender,
+            shutdown_on_drop,
+        }
+    }
+
+    /// Ignore outputs from the workers and finish the builder.
+    #[must_use]
+    pub fn detach(self) -> WorkerPool<W> {
+        let Self {
+            sender,
+            priority_sender,
+            shutdown_on_drop,
+            ..
+        } = self;
+
+        WorkerPool {
+            sender,
+            priority_sender,
+            shutdown_on_drop,
+        }
+    }
+}
+
+/// Sends inputs to a pool of workers. On drop, shuts down all workers.
+///
+/// Created with [`ComponentBuilder::detach_worker_pool`].
+///
+/// ```ignore
+/// let thumbnailer = Thumbnailer::builder()
+///     .detach_worker_pool(4, ())
+///     .forward(sender.input_sender(), AppMsg::ThumbnailReady);
+///
+/// for path in paths {
+///     thumbnailer.emit(ThumbnailerMsg::Generate(path));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct WorkerPool<W: Component> {
+    // Sends inputs to the shared queue of the workers.
+    sender: Sender<W::Input>,
+    // Sends inputs that are handled before regular inputs.
+    priority_sender: Sender<W::Input>,
+    // Shutdown the workers when this is dropped
+    shutdown_on_drop: Vec<ShutdownOnDrop>,
+}
+
+impl<W: Component> WorkerPool<W> {
+    /// Emits an input to the next idle worker.
+    pub fn emit(&self, event: W::Input) {
+        self.sender.send(event).unwrap();
+    }
+
+    /// Provides access to the sender of the shared input queue.
+    #[must_use]
+    pub const fn sender(&self) -> &Sender<W::Input> {
+        &self.sender
+    }
+
+    /// Emits an input to the next idle worker that is handled before all pending regular inputs.
+    pub fn emit_priority(&self, event: W::Input) {
+        self.priority_sender.send(event).unwrap();
+    }
+
+    /// Provides access to the sender of the shared priority queue.
+    #[must_use]
+    pub const fn priority_sender(&self) -> &Sender<W::Input> {
+        &self.priority_sender
+    }
+
+    /// Returns the number of workers in the pool.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.shutdown_on_drop.len()
+    }
+
+    /// Dropping this type will usually stop the runtime of the workers.
+    /// With this method you can give the runtimes a static lifetime.
+    /// In other words, dropping the [`WorkerPool`] will not stop
+    /// the workers anymore, they will run until the app is closed.
+    pub fn detach_runtime(&mut self) {
+        for shutdown_on_drop in &mut self.shutdown_on_drop {
+            shutdown_on_drop.deactivate();
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use gtk::accessible::{Property, Relation};
+use gtk::prelude::{AccessibleExt, AccessibleExtManual, Cast, IsA};
+
+/// Trait that extends [`gtk::prelude::AccessibleExt`].
+///
+/// It provides setters for common accessible properties and relations,
+/// so they can be used like regular properties in the widgets macro.
+///
+/// ```
+/// # use relm4::RelmAccessibleExt;
+/// # gtk::init().unwrap();
+/// let label = gtk::Label::new(Some("Volume"));
+/// let scale = gtk::Scale::default();
+/// scale.set_accessible_labelled_by(&label);
+/// scale.set_accessible_description("Adjusts the volume of all sounds");
+/// ```
+pub trait RelmAccessibleExt {
+    /// Sets the label that is read by screen readers.
+    fn set_accessible_label(&self, label: &str);
+
+    /// Sets the description that is read by screen readers.
+    fn set_accessible_description(&self, description: &str);
+
+    /// Sets the widget that labels this widget.
+    fn set_accessible_labelled_by(&self, label: &impl IsA<gtk::Accessible>);
+
+    /// Sets the widget that describes this widget.
+    fn set_accessible_described_by(&self, description: &impl IsA<gtk::Accessible>);
+
+    /// Removes the label that is read by screen readers.
+    fn reset_accessible_label(&self);
+
+    /// Removes the description that is read by screen readers.
+    fn reset_accessible_description(&self);
+}
+
+impl<T: IsA<gtk::Accessible>> RelmAccessibleExt for T {
+    fn set_accessible_label(&self, label: &str) {
+        self.update_property(&[Property::Label(label)]);
+    }
+
+    fn set_accessible_description(&self, description: &str) {
+        self.update_property(&[Property::Description(description)]);
+    }
+
+    fn set_accessible_labelled_by(&self, label: &impl IsA<gtk::Accessible>) {
+        self.update_relation(&[Relation::LabelledBy(&[label.upcast_ref()])]);
+    }
+
+    fn set_accessible_described_by(&self, description: &impl IsA<gtk::Accessible>) {
+        self.update_relation(&[Relation::DescribedBy(&[description.upcast_ref()])]);
+    }
+
+    fn reset_accessible_label(&self) {
+        self.reset_property(gtk::AccessibleProperty::Label);
+    }
+
+    fn reset_accessible_description(&self) {
+        self.reset_property(gtk::AccessibleProperty::Description);
+    }
+}
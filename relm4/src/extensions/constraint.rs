@@ -0,0 +1,48 @@
+use gtk::prelude::{Cast, IsA, WidgetExt};
+
+/// Trait for adding constraints to widgets that use a [`gtk::ConstraintLayout`].
+///
+/// Together with the [`constraint!`](crate::constraint!) macro, this allows declaring
+/// constraints between sibling widgets in the widgets macro.
+///
+/// ```
+/// # use relm4::prelude::*;
+/// # gtk::init().unwrap();
+/// use relm4::RelmConstraintExt;
+///
+/// let container = gtk::Box::default();
+/// let label = gtk::Label::new(Some("Name"));
+/// let entry = gtk::Entry::default();
+/// label.set_parent(&container);
+/// entry.set_parent(&container);
+///
+/// container.add_constraint(relm4::constraint!(label.start == super.start + 6.0));
+/// container.add_constraint(relm4::constraint!(entry.start == label.end + 12.0));
+/// container.add_constraint(relm4::constraint!(entry.end == super.end - 6.0));
+/// ```
+pub trait RelmConstraintExt {
+    /// Returns the [`gtk::ConstraintLayout`] of the widget.
+    ///
+    /// If the widget uses another layout manager, it's replaced by a new constraint layout.
+    fn constraint_layout(&self) -> gtk::ConstraintLayout;
+
+    /// Adds a constraint to the [`gtk::ConstraintLayout`] of the widget.
+    fn add_constraint(&self, constraint: gtk::Constraint) {
+        self.constraint_layout().add_constraint(constraint);
+    }
+}
+
+impl<T: IsA<gtk::Widget>> RelmConstraintExt for T {
+    fn constraint_layout(&self) -> gtk::ConstraintLayout {
+        if let Some(layout) = self
+            .layout_manager()
+            .and_then(|layout| layout.downcast::<gtk::ConstraintLayout>().ok())
+        {
+            layout
+        } else {
+            let layout = gtk::ConstraintLayout::new();
+            self.set_layout_manager(Some(layout.clone()));
+            layout
+        }
+    }
+}
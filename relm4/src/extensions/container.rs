@@ -7,6 +7,17 @@ use crate::{ContainerChild, RelmSetChildExt};
 pub trait RelmContainerExt: ContainerChild {
     /// Add widget as child to container.
     fn container_add(&self, widget: &impl AsRef<Self::Child>);
+
+    /// Moves a child of the container directly after `sibling`,
+    /// or to the start if `sibling` is [`None`].
+    ///
+    /// Containers that can't reorder their children keep the current order.
+    fn container_move_after(
+        &self,
+        _widget: &impl AsRef<Self::Child>,
+        _sibling: Option<&gtk::Widget>,
+    ) {
+    }
 }
 
 impl<T: RelmSetChildExt> RelmContainerExt for T {
@@ -48,7 +59,40 @@ macro_rules! add_child_impl {
     }
 }
 
-append_impl!(gtk::Box, gtk::ListBox);
+impl RelmContainerExt for gtk::Box {
+    fn container_add(&self, widget: &impl AsRef<gtk::Widget>) {
+        self.append(widget.as_ref());
+    }
+
+    fn container_move_after(
+        &self,
+        widget: &impl AsRef<gtk::Widget>,
+        sibling: Option<&gtk::Widget>,
+    ) {
+        self.reorder_child_after(widget.as_ref(), sibling);
+    }
+}
+
+impl RelmContainerExt for gtk::ListBox {
+    fn container_add(&self, widget: &impl AsRef<gtk::ListBoxRow>) {
+        self.append(widget.as_ref());
+    }
+
+    fn container_move_after(
+        &self,
+        widget: &impl AsRef<gtk::ListBoxRow>,
+        sibling: Option<&gtk::Widget>,
+    ) {
+        // Rows keep their child when they are removed.
+        let row = widget.as_ref();
+        self.remove(row);
+
+        let position = sibling
+            .and_then(|sibling| sibling.downcast_ref::<gtk::ListBoxRow>())
+            .map_or(0, |sibling| sibling.index() + 1);
+        self.insert(row, position);
+    }
+}
 add_child_impl!(gtk::InfoBar, gtk::Stack);
 
 #[cfg(feature = "libadwaita")]
@@ -93,6 +93,7 @@ pub trait RelmIterChildrenExt: ContainerChild + IsA<gtk::Widget> {
 }
 
 impl RelmIterChildrenExt for gtk::Box {}
+impl RelmIterChildrenExt for gtk::Fixed {}
 impl RelmIterChildrenExt for gtk::ListBox {}
 impl RelmIterChildrenExt for gtk::FlowBox {}
 impl RelmIterChildrenExt for gtk::Grid {
@@ -121,6 +122,7 @@ impl RelmIterChildrenExt for gtk::Grid {
     }
 }
 impl RelmIterChildrenExt for gtk::Stack {}
+impl RelmIterChildrenExt for gtk::Overlay {}
 
 #[cfg(feature = "libadwaita")]
 #[cfg_attr(docsrs, doc(cfg(feature = "libadwaita")))]
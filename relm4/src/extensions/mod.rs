@@ -1,3 +1,5 @@
+mod accessible;
+mod constraint;
 mod container;
 mod iter_children;
 mod object_ext;
@@ -8,6 +10,8 @@ mod set_child;
 mod tests;
 mod widget_ext;
 
+pub use accessible::RelmAccessibleExt;
+pub use constraint::RelmConstraintExt;
 pub use container::RelmContainerExt;
 pub use iter_children::RelmIterChildrenExt;
 pub use object_ext::RelmObjectExt;
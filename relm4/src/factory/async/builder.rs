@@ -35,8 +35,13 @@ where
         let (shutdown_notifier, shutdown_receiver) = shutdown::channel();
 
         // Encapsulates the senders used by component methods.
-        let component_sender =
-            AsyncFactorySender::new(input_sender, output_sender, cmd_sender, shutdown_receiver);
+        let component_sender = AsyncFactorySender::new(
+            input_sender.clone(),
+            input_sender,
+            output_sender,
+            cmd_sender,
+            shutdown_receiver,
+        );
 
         let root_widget = C::init_root();
 
@@ -70,7 +75,7 @@ where
 
         let input_sender = component_sender.input_sender().clone();
 
-        let loading_widgets = C::init_loading_widgets(root_widget.clone());
+        let loading_widgets = C::init_loading_widgets_with_init(&init, root_widget.clone());
 
         let future_receiver = {
             let index = index.clone();
@@ -147,10 +152,12 @@ impl<C: AsyncFactoryComponent> FutureData<C> {
         } = self;
 
         let mut data = Box::new(data);
-        let widgets =
+        let mut widgets =
             Box::new(data.init_widgets(&index, root, &returned_widget, component_sender.clone()));
+        data.on_attach(&mut widgets, component_sender.clone());
 
         let output_sender = component_sender.output_sender().clone();
+        let detach_sender = component_sender.clone();
 
         // Spawns the component's service. It will receive both `Self::Input` and
         // `Self::CommandOutput` messages. It will spawn commands as requested by
@@ -200,7 +207,10 @@ impl<C: AsyncFactoryComponent> FutureData<C> {
                     );
                 }
             },
-            C::shutdown,
+            move |model, widgets, output| {
+                model.on_detach(widgets, detach_sender.clone());
+                model.shutdown(widgets, output);
+            },
         )
     }
 }
@@ -58,6 +58,23 @@ pub trait AsyncFactoryComponent:
         None
     }
 
+    /// Like [`init_loading_widgets()`], but with access to the initialization data.
+    ///
+    /// This allows you to choose a different placeholder for each element,
+    /// for example to show the title of an element immediately while its
+    /// content is still loading.
+    ///
+    /// By default, this method calls [`init_loading_widgets()`].
+    ///
+    /// [`init_loading_widgets()`]: AsyncFactoryComponent::init_loading_widgets
+    #[must_use]
+    fn init_loading_widgets_with_init(
+        _init: &Self::Init,
+        root: Self::Root,
+    ) -> Option<LoadingWidgets> {
+        Self::init_loading_widgets(root)
+    }
+
     /// Initializes the widgets.
     fn init_widgets(
         &mut self,
@@ -117,6 +134,22 @@ pub trait AsyncFactoryComponent:
         }
     }
 
+    /// Called after the widgets were initialized while the root widget
+    /// is attached to the parent widget.
+    ///
+    /// Because the model is initialized asynchronously, this happens
+    /// after the [`init_model()`](AsyncFactoryComponent::init_model) future completed.
+    #[allow(unused)]
+    fn on_attach(&mut self, widgets: &mut Self::Widgets, sender: AsyncFactorySender<Self>) {}
+
+    /// Called after the root widget was removed from the parent widget,
+    /// right before [`shutdown()`](AsyncFactoryComponent::shutdown).
+    ///
+    /// This method isn't called if the element is removed
+    /// before the model was initialized.
+    #[allow(unused)]
+    fn on_detach(&mut self, widgets: &mut Self::Widgets, sender: AsyncFactorySender<Self>) {}
+
     /// Last method called before a factory component is shut down.
     #[allow(unused)]
     fn shutdown(&mut self, widgets: &mut Self::Widgets, output: Sender<Self::Output>) {}
@@ -88,7 +88,19 @@ impl<C, Widgets, Output> DataGuard<C, Widgets, Output> {
         &mut self.data
     }
 
+    pub(super) fn get_mut_with_widgets(&mut self) -> (&mut C, &mut Widgets) {
+        (&mut self.data, &mut self.widgets)
+    }
+
     pub(super) fn into_inner(self) -> C {
+        let (data, widgets) = self.into_parts();
+        drop(widgets);
+        data
+    }
+
+    /// Shuts down the runtime like [`into_inner`](Self::into_inner),
+    /// but also returns the widgets, so they can be reused.
+    pub(super) fn into_parts(self) -> (C, Widgets) {
         let Self {
             mut data,
             mut widgets,
@@ -103,10 +115,9 @@ impl<C, Widgets, Output> DataGuard<C, Widgets, Output> {
 
         shutdown_fn(&mut data, &mut widgets, output_sender);
 
-        drop(widgets);
         drop(shutdown_fn);
 
-        *data
+        (*data, *widgets)
     }
 }
 
@@ -40,6 +40,14 @@ impl Clone for DynamicIndex {
 }
 
 impl DynamicIndex {
+    /// Creates an index with the value `0` that isn't managed by a factory.
+    ///
+    /// This is useful for launching a [`StandaloneFactoryComponent`](super::StandaloneFactoryComponent).
+    #[must_use]
+    pub fn standalone() -> Self {
+        Self::new(0)
+    }
+
     /// Get the current index number.
     ///
     /// This value is updated by the factory container and might change after each update function.
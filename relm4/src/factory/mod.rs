@@ -20,9 +20,12 @@ pub use r#async::{
     AsyncFactoryVecDequeConnector, AsyncFactoryVecDequeGuard,
 };
 pub use sync::{
-    CloneableFactoryComponent, FactoryComponent, FactoryHashMap, FactoryHashMapBuilder,
-    FactoryHashMapConnector, FactoryVecDeque, FactoryVecDequeBuilder, FactoryVecDequeConnector,
-    FactoryVecDequeGuard,
+    CloneableFactoryComponent, FactoryComponent, FactoryElementMut, FactoryElementSender,
+    FactoryHashMap, FactoryHashMapBuilder, FactoryHashMapConnector, FactoryHashMapEntry,
+    FactoryHashMapOccupiedEntry, FactoryHashMapVacantEntry, FactoryKeyedVec,
+    FactoryKeyedVecBuilder, FactoryKeyedVecConnector, FactoryVecDeque, FactoryVecDequeBuilder,
+    FactoryVecDequeConnector, FactoryVecDequeGuard, FactoryVirtualList, FactoryVirtualListBuilder,
+    FactoryVirtualListConnector, RecyclableFactoryComponent, StandaloneFactoryComponent,
 };
 
 pub use crate::channel::{AsyncFactorySender, FactorySender};
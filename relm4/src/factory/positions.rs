@@ -14,7 +14,44 @@ pub struct GridPosition {
     pub height: i32,
 }
 
-#[derive(Debug)]
+impl GridPosition {
+    /// Places the widget with the given index in a cell of a grid with `columns` columns.
+    ///
+    /// The widgets fill the grid row by row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `columns` is zero.
+    #[must_use]
+    pub fn row_major(index: usize, columns: usize) -> Self {
+        assert!(columns > 0, "grid must have at least one column");
+        Self::cell((index / columns) as i32, (index % columns) as i32)
+    }
+
+    /// Places the widget with the given index in a cell of a grid with `rows` rows.
+    ///
+    /// The widgets fill the grid column by column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows` is zero.
+    #[must_use]
+    pub fn column_major(index: usize, rows: usize) -> Self {
+        assert!(rows > 0, "grid must have at least one row");
+        Self::cell((index % rows) as i32, (index / rows) as i32)
+    }
+
+    fn cell(row: i32, column: i32) -> Self {
+        Self {
+            column,
+            row,
+            width: 1,
+            height: 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
 /// Position used for [`gtk::Fixed`].
 pub struct FixedPosition {
     /// Position on the x-axis.
@@ -22,3 +59,43 @@ pub struct FixedPosition {
     /// Position on the y-axis.
     pub y: f64,
 }
+
+/// Position used for overlay children of a [`gtk::Overlay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverlayPosition {
+    /// The horizontal alignment of the overlay child.
+    pub halign: gtk::Align,
+    /// The vertical alignment of the overlay child.
+    pub valign: gtk::Align,
+    /// Whether the overlay child is included in the size
+    /// measurement of the [`gtk::Overlay`].
+    pub measure: bool,
+    /// Whether the overlay child is clipped to the size of the main child.
+    pub clip: bool,
+}
+
+impl Default for OverlayPosition {
+    fn default() -> Self {
+        Self {
+            halign: gtk::Align::Fill,
+            valign: gtk::Align::Fill,
+            measure: false,
+            clip: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GridPosition;
+
+    #[test]
+    fn grid_position_from_index() {
+        let position = GridPosition::row_major(7, 3);
+        assert_eq!((position.row, position.column), (2, 1));
+
+        let position = GridPosition::column_major(7, 3);
+        assert_eq!((position.row, position.column), (1, 2));
+        assert_eq!((position.width, position.height), (1, 1));
+    }
+}
@@ -1,40 +1,97 @@
-use super::{FactoryComponent, FactoryHandle};
+use super::{FactoryComponent, FactoryHandle, RebindFn, RecycledWidgets};
 
 use crate::factory::{DataGuard, FactorySender, FactoryView};
 use crate::shutdown::ShutdownSender;
 use crate::{shutdown, GuardedReceiver, Receiver, Sender};
 
 use std::any;
+use std::fmt;
+use std::sync::{Arc, Mutex};
 
 use tracing::info_span;
 
-#[derive(Debug)]
 pub(super) struct FactoryBuilder<C: FactoryComponent> {
     pub(super) data: Box<C>,
     pub(super) root_widget: C::Root,
     pub(super) component_sender: FactorySender<C>,
     pub(super) input_receiver: Receiver<C::Input>,
     pub(super) cmd_receiver: Receiver<C::CommandOutput>,
+    pub(super) output_target: Arc<Mutex<Sender<C::Output>>>,
     pub(super) shutdown_notifier: ShutdownSender,
+    /// Widgets of a discarded element that are reused instead of calling `init_widgets`.
+    pub(super) recycled_widgets: Option<(Box<C::Widgets>, RebindFn<C>)>,
+}
+
+impl<C: FactoryComponent> fmt::Debug for FactoryBuilder<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FactoryBuilder")
+            .field("data", &self.data)
+            .field("root_widget", &self.root_widget)
+            .field("component_sender", &self.component_sender)
+            .field("input_receiver", &self.input_receiver)
+            .field("cmd_receiver", &self.cmd_receiver)
+            .field("output_target", &self.output_target)
+            .field("shutdown_notifier", &self.shutdown_notifier)
+            .field("recycled_widgets", &self.recycled_widgets.is_some())
+            .finish()
+    }
 }
 
 impl<C: FactoryComponent> FactoryBuilder<C> {
     pub(super) fn new(index: &C::Index, init: C::Init, output_sender: Sender<C::Output>) -> Self {
+        Self::with_widgets(index, init, output_sender, None)
+    }
+
+    /// Creates a new element that reuses the widgets of a discarded one.
+    ///
+    /// The element still gets its own channels, so nothing
+    /// that belonged to the discarded element can reach it.
+    pub(super) fn recycle(
+        index: &C::Index,
+        init: C::Init,
+        output_sender: Sender<C::Output>,
+        recycled: RecycledWidgets<C>,
+    ) -> Self {
+        Self::with_widgets(index, init, output_sender, Some(recycled))
+    }
+
+    fn with_widgets(
+        index: &C::Index,
+        init: C::Init,
+        output_sender: Sender<C::Output>,
+        recycled: Option<RecycledWidgets<C>>,
+    ) -> Self {
         // Used for all events to be processed by this component's internal service.
         let (input_sender, input_receiver) = crate::channel::<C::Input>();
 
         // Sends messages from commands executed from the background.
         let (cmd_sender, cmd_receiver) = crate::channel::<C::CommandOutput>();
 
+        // Outputs are sent to the factory the component currently belongs to,
+        // so the component can be moved to another factory.
+        let output_target = Arc::new(Mutex::new(output_sender));
+
         // Notifies the component's child commands that it is now deceased.
         let (shutdown_notifier, shutdown_receiver) = shutdown::channel();
 
         // Encapsulates the senders used by component methods.
-        let component_sender =
-            FactorySender::new(input_sender, output_sender, cmd_sender, shutdown_receiver);
+        let component_sender = FactorySender::with_output_target(
+            input_sender.clone(),
+            input_sender,
+            output_target.clone(),
+            cmd_sender,
+            shutdown_receiver,
+        );
 
         let data = Box::new(C::init_model(init, index, component_sender.clone()));
-        let root_widget = data.init_root();
+        let (root_widget, recycled_widgets) = match recycled {
+            Some(RecycledWidgets {
+                root_widget,
+                widgets,
+                rebind,
+            }) => (root_widget, Some((widgets, rebind))),
+            None => (data.init_root(), None),
+        };
 
         Self {
             data,
@@ -42,7 +99,9 @@ impl<C: FactoryComponent> FactoryBuilder<C> {
             component_sender,
             input_receiver,
             cmd_receiver,
+            output_target,
             shutdown_notifier,
+            recycled_widgets,
         }
     }
 
@@ -58,21 +117,30 @@ impl<C: FactoryComponent> FactoryBuilder<C> {
             component_sender,
             input_receiver,
             cmd_receiver,
+            output_target,
             shutdown_notifier,
+            recycled_widgets,
         } = self;
 
         // Gets notifications when a component's model and view is updated externally.
         let (notifier, notifier_receiver) = crate::channel();
 
-        let widgets = Box::new(data.init_widgets(
-            index,
-            root_widget.clone(),
-            &returned_widget,
-            component_sender.clone(),
-        ));
+        let widgets = if let Some((mut widgets, rebind)) = recycled_widgets {
+            rebind(&mut data, index, &mut widgets, component_sender.clone());
+            widgets
+        } else {
+            Box::new(data.init_widgets(
+                index,
+                root_widget.clone(),
+                &returned_widget,
+                component_sender.clone(),
+            ))
+        };
 
         let input_sender = component_sender.input_sender().clone();
-        let output_sender = component_sender.output_sender().clone();
+        let output_sender = output_target.lock().unwrap().clone();
+        let sender = component_sender.clone();
+        let shutdown_output_target = output_target.clone();
 
         // Spawns the component's service. It will receive both `Self::Input` and
         // `Self::CommandOutput` messages. It will spawn commands as requested by
@@ -124,16 +192,24 @@ impl<C: FactoryComponent> FactoryBuilder<C> {
                     }
                 }
             },
-            C::shutdown,
+            // Use the output sender of the factory that owns the component at shutdown.
+            move |model, widgets, _| {
+                let output = shutdown_output_target.lock().unwrap().clone();
+                model.shutdown(widgets, output);
+            },
         );
 
         // Give back a type for controlling the component service.
-        FactoryHandle {
+        let mut handle = FactoryHandle {
             data,
             root_widget,
             returned_widget,
             input: input_sender,
             notifier,
-        }
+            sender,
+            output_target,
+        };
+        handle.on_attach();
+        handle
     }
 }
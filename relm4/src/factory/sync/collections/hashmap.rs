@@ -2,53 +2,19 @@ use crate::{Receiver, Sender};
 
 use crate::factory::sync::builder::FactoryBuilder;
 use crate::factory::sync::handle::FactoryHandle;
-use crate::factory::{CloneableFactoryComponent, FactoryComponent, FactoryView};
+use crate::factory::{
+    CloneableFactoryComponent, FactoryComponent, FactoryElementSender, FactoryView,
+};
 
-use std::collections::hash_map::RandomState;
+use super::FactoryElementGuard;
+
+use std::collections::hash_map::{self, RandomState};
 use std::collections::HashMap;
 use std::hash::{BuildHasher, Hash, Hasher};
 use std::iter::FusedIterator;
 use std::marker::PhantomData;
 use std::ops;
 
-#[derive(Debug)]
-#[must_use]
-pub struct FactoryElementGuard<'a, C>
-where
-    C: FactoryComponent,
-{
-    inner: &'a mut FactoryHandle<C>,
-}
-
-impl<'a, C> ops::Deref for FactoryElementGuard<'a, C>
-where
-    C: FactoryComponent,
-{
-    type Target = C;
-
-    fn deref(&self) -> &Self::Target {
-        self.inner.data.get()
-    }
-}
-
-impl<'a, C> ops::DerefMut for FactoryElementGuard<'a, C>
-where
-    C: FactoryComponent,
-{
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        self.inner.data.get_mut()
-    }
-}
-
-impl<'a, C> Drop for FactoryElementGuard<'a, C>
-where
-    C: FactoryComponent,
-{
-    fn drop(&mut self) {
-        self.inner.notifier.send(()).unwrap()
-    }
-}
-
 #[derive(Debug)]
 /// A builder-pattern struct for building a [`FactoryHashMap`].
 pub struct FactoryHashMapBuilder<K, C: FactoryComponent, S = RandomState> {
@@ -188,6 +154,10 @@ where
 
 /// A container similar to [`HashMap`] that can be used to store
 /// values of type [`FactoryComponent`].
+///
+/// The order of the widgets is unspecified.
+/// If you need the widgets to follow the insertion order,
+/// use [`FactoryKeyedVec`](crate::factory::FactoryKeyedVec) instead.
 #[derive(Debug)]
 pub struct FactoryHashMap<K, C: FactoryComponent, S = RandomState> {
     widget: C::ParentWidget,
@@ -272,8 +242,9 @@ where
 
     /// Clears the map, removing all factory components.
     pub fn clear(&mut self) {
-        for (_, handle) in self.inner.drain() {
+        for (_, mut handle) in self.inner.drain() {
             self.widget.factory_remove(&handle.returned_widget);
+            handle.on_detach();
         }
     }
 }
@@ -304,6 +275,31 @@ where
         self.inner[key].input.emit(msg);
     }
 
+    /// Computes the positions of all elements again and updates their widgets.
+    ///
+    /// Use this method if the [`Position`](crate::factory::Position) of the elements
+    /// depends on other data, for example to reflow a grid after the number
+    /// of columns changed.
+    pub fn reposition_all(&self) {
+        for (key, handle) in self.inner.iter() {
+            let position = C::position(handle.data.get(), key);
+            self.widget
+                .factory_update_position(&handle.returned_widget, &position);
+        }
+    }
+
+    /// Returns a [`FactoryElementSender`] for sending messages to one of the elements.
+    ///
+    /// The sender can be used without access to the factory and
+    /// stops delivering messages once the element was removed.
+    ///
+    /// Returns [`None`] if `key` is invalid.
+    pub fn element_sender(&self, key: &K) -> Option<FactoryElementSender<C>> {
+        self.inner
+            .get(key)
+            .map(|c| FactoryElementSender::new(c.input.clone()))
+    }
+
     /// Tries to get an immutable reference to
     /// the model of one element.
     ///
@@ -322,6 +318,25 @@ where
             .map(|c| FactoryElementGuard { inner: c })
     }
 
+    /// Gets the given key's corresponding entry in the map for in-place manipulation.
+    pub fn entry(&mut self, key: K) -> FactoryHashMapEntry<'_, K, C> {
+        match self.inner.entry(key) {
+            hash_map::Entry::Occupied(inner) => {
+                FactoryHashMapEntry::Occupied(FactoryHashMapOccupiedEntry {
+                    inner,
+                    widget: &self.widget,
+                })
+            }
+            hash_map::Entry::Vacant(inner) => {
+                FactoryHashMapEntry::Vacant(FactoryHashMapVacantEntry {
+                    inner,
+                    widget: &self.widget,
+                    output_sender: &self.output_sender,
+                })
+            }
+        }
+    }
+
     /// Inserts a new factory component into the map.
     ///
     /// If the map did not have this key present, None is returned.
@@ -347,8 +362,9 @@ where
 
     /// Removes a key from the map, returning the factory component at the key if the key was previously in the map.
     pub fn remove(&mut self, key: &K) -> Option<C> {
-        if let Some(handle) = self.inner.remove(key) {
+        if let Some(mut handle) = self.inner.remove(key) {
             self.widget.factory_remove(&handle.returned_widget);
+            handle.on_detach();
             Some(handle.data.into_inner())
         } else {
             None
@@ -356,6 +372,179 @@ where
     }
 }
 
+/// A view into a single entry of a [`FactoryHashMap`], which may either be vacant or occupied.
+///
+/// This is constructed from the [`entry`](FactoryHashMap::entry) method.
+#[derive(Debug)]
+pub enum FactoryHashMapEntry<'a, K, C: FactoryComponent> {
+    /// An occupied entry.
+    Occupied(FactoryHashMapOccupiedEntry<'a, K, C>),
+    /// A vacant entry.
+    Vacant(FactoryHashMapVacantEntry<'a, K, C>),
+}
+
+impl<'a, K, C> FactoryHashMapEntry<'a, K, C>
+where
+    C: FactoryComponent<Index = K>,
+{
+    /// Returns a reference to this entry's key.
+    pub fn key(&self) -> &K {
+        match self {
+            Self::Occupied(entry) => entry.key(),
+            Self::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Ensures a factory component is in the entry by inserting a new one
+    /// from `init` if empty, and returns a mutable reference to its model.
+    pub fn or_insert(self, init: C::Init) -> FactoryElementGuard<'a, C> {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(init),
+        }
+    }
+
+    /// Ensures a factory component is in the entry by inserting a new one
+    /// from the result of `init` if empty, and returns a mutable reference to its model.
+    ///
+    /// `init` is only called if the entry is vacant.
+    pub fn or_insert_with<F>(self, init: F) -> FactoryElementGuard<'a, C>
+    where
+        F: FnOnce() -> C::Init,
+    {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(init()),
+        }
+    }
+
+    /// Like [`or_insert_with`](Self::or_insert_with), but passes the key to `init`.
+    pub fn or_insert_with_key<F>(self, init: F) -> FactoryElementGuard<'a, C>
+    where
+        F: FnOnce(&K) -> C::Init,
+    {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => {
+                let init = init(entry.key());
+                entry.insert(init)
+            }
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry
+    /// before any potential inserts into the map.
+    ///
+    /// The view of the element is updated afterwards.
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut C),
+    {
+        match self {
+            Self::Occupied(mut entry) => {
+                f(&mut entry.get_mut());
+                Self::Occupied(entry)
+            }
+            Self::Vacant(entry) => Self::Vacant(entry),
+        }
+    }
+}
+
+/// A view into an occupied entry of a [`FactoryHashMap`].
+///
+/// It is part of the [`FactoryHashMapEntry`] enum.
+#[derive(Debug)]
+pub struct FactoryHashMapOccupiedEntry<'a, K, C: FactoryComponent> {
+    inner: hash_map::OccupiedEntry<'a, K, FactoryHandle<C>>,
+    widget: &'a C::ParentWidget,
+}
+
+impl<'a, K, C> FactoryHashMapOccupiedEntry<'a, K, C>
+where
+    C: FactoryComponent<Index = K>,
+{
+    /// Returns a reference to the key in the entry.
+    pub fn key(&self) -> &K {
+        self.inner.key()
+    }
+
+    /// Returns an immutable reference to the model of the element in the entry.
+    pub fn get(&self) -> &C {
+        self.inner.get().data.get()
+    }
+
+    /// Returns a mutable reference to the model of the element in the entry.
+    pub fn get_mut(&mut self) -> FactoryElementGuard<'_, C> {
+        FactoryElementGuard {
+            inner: self.inner.get_mut(),
+        }
+    }
+
+    /// Converts the entry into a mutable reference to the model
+    /// of the element with a lifetime bound to the map itself.
+    pub fn into_mut(self) -> FactoryElementGuard<'a, C> {
+        FactoryElementGuard {
+            inner: self.inner.into_mut(),
+        }
+    }
+
+    /// Takes the factory component out of the map and returns its model.
+    pub fn remove(self) -> C {
+        let mut handle = self.inner.remove();
+        self.widget.factory_remove(&handle.returned_widget);
+        handle.on_detach();
+        handle.data.into_inner()
+    }
+}
+
+/// A view into a vacant entry of a [`FactoryHashMap`].
+///
+/// It is part of the [`FactoryHashMapEntry`] enum.
+#[derive(Debug)]
+pub struct FactoryHashMapVacantEntry<'a, K, C: FactoryComponent> {
+    inner: hash_map::VacantEntry<'a, K, FactoryHandle<C>>,
+    widget: &'a C::ParentWidget,
+    output_sender: &'a Sender<C::Output>,
+}
+
+impl<'a, K, C> FactoryHashMapVacantEntry<'a, K, C>
+where
+    C: FactoryComponent<Index = K>,
+{
+    /// Returns a reference to the key that would be used
+    /// when inserting a value through the [`FactoryHashMapVacantEntry`].
+    pub fn key(&self) -> &K {
+        self.inner.key()
+    }
+
+    /// Takes ownership of the key.
+    pub fn into_key(self) -> K {
+        self.inner.into_key()
+    }
+
+    /// Creates a new factory component from `init` with the entry's key
+    /// and returns a mutable reference to its model.
+    pub fn insert(self, init: C::Init) -> FactoryElementGuard<'a, C> {
+        let Self {
+            inner,
+            widget,
+            output_sender,
+        } = self;
+
+        let key = inner.key();
+        let builder = FactoryBuilder::new(key, init, output_sender.clone());
+
+        let position = C::position(&builder.data, key);
+        let returned_widget = widget.factory_append(builder.root_widget.clone(), &position);
+
+        let component = builder.launch(key, returned_widget);
+
+        FactoryElementGuard {
+            inner: inner.insert(component),
+        }
+    }
+}
+
 /// Implements the Clone Trait for [`FactoryHashMap`] if the component implements [`CloneableFactoryComponent`].
 impl<K, C> Clone for FactoryHashMap<K, C, RandomState>
 where
@@ -378,3 +567,136 @@ where
         clone
     }
 }
+
+#[cfg(test)]
+mod test {
+    use gtk::glib::MainContext;
+    use gtk::prelude::Cast;
+
+    use crate::factory::{FactoryComponent, FactorySender, FactoryView};
+    use crate::RelmIterChildrenExt;
+
+    use super::{FactoryHashMap, FactoryHashMapEntry};
+
+    #[derive(Debug)]
+    struct Element {
+        value: u8,
+    }
+
+    impl FactoryComponent for Element {
+        type ParentWidget = gtk::Box;
+        type CommandOutput = ();
+        type Input = ();
+        type Output = ();
+        type Init = u8;
+        type Root = gtk::Label;
+        type Widgets = gtk::Label;
+        type Index = char;
+
+        fn init_model(value: Self::Init, _: &char, _: FactorySender<Self>) -> Self {
+            Self { value }
+        }
+
+        fn init_root(&self) -> Self::Root {
+            gtk::Label::default()
+        }
+
+        fn init_widgets(
+            &mut self,
+            _: &char,
+            root: Self::Root,
+            _: &<Self::ParentWidget as FactoryView>::ReturnedWidget,
+            _: FactorySender<Self>,
+        ) -> Self::Widgets {
+            root.set_label(&self.value.to_string());
+            root
+        }
+
+        fn update_view(&self, label: &mut Self::Widgets, _: FactorySender<Self>) {
+            label.set_label(&self.value.to_string());
+        }
+    }
+
+    fn factory() -> FactoryHashMap<char, Element> {
+        FactoryHashMap::builder().launch_default().detach()
+    }
+
+    fn process_messages() {
+        while MainContext::default().iteration(false) {}
+    }
+
+    /// Values shown by the widgets in ascending order.
+    fn rendered(factory: &FactoryHashMap<char, Element>) -> Vec<u8> {
+        let mut values: Vec<u8> = factory
+            .widget()
+            .iter_children()
+            .map(|child| {
+                let label = child.downcast::<gtk::Label>().unwrap();
+                label.label().parse().unwrap()
+            })
+            .collect();
+        values.sort_unstable();
+        values
+    }
+
+    #[gtk::test]
+    fn entry_or_insert() {
+        let mut factory = factory();
+
+        assert_eq!(factory.entry('a').or_insert(1).value, 1);
+        assert_eq!(rendered(&factory), [1]);
+
+        // Occupied entries keep their element.
+        assert_eq!(factory.entry('a').or_insert(2).value, 1);
+        assert_eq!(
+            factory.entry('a').or_insert_with(|| unreachable!()).value,
+            1
+        );
+        assert_eq!(factory.len(), 1);
+
+        let element = factory.entry('b').or_insert_with_key(|key| {
+            assert_eq!(*key, 'b');
+            3
+        });
+        assert_eq!(element.value, 3);
+        drop(element);
+        assert_eq!(rendered(&factory), [1, 3]);
+    }
+
+    #[gtk::test]
+    fn entry_and_modify() {
+        let mut factory = factory();
+        factory.insert('a', 1);
+
+        factory
+            .entry('a')
+            .and_modify(|element| element.value += 10)
+            .or_insert(0);
+        process_messages();
+        assert_eq!(factory.get(&'a').unwrap().value, 11);
+        assert_eq!(rendered(&factory), [11]);
+
+        // Vacant entries are not modified.
+        factory
+            .entry('b')
+            .and_modify(|_| unreachable!())
+            .or_insert(2);
+        process_messages();
+        assert_eq!(rendered(&factory), [2, 11]);
+    }
+
+    #[gtk::test]
+    fn entry_remove() {
+        let mut factory = factory();
+        factory.insert('a', 1);
+        factory.insert('b', 2);
+
+        let FactoryHashMapEntry::Occupied(entry) = factory.entry('a') else {
+            panic!("Expected an occupied entry");
+        };
+        assert_eq!(entry.remove().value, 1);
+
+        assert!(matches!(factory.entry('a'), FactoryHashMapEntry::Vacant(_)));
+        assert_eq!(rendered(&factory), [2]);
+    }
+}
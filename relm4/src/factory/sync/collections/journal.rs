@@ -0,0 +1,68 @@
+use crate::factory::FactoryComponent;
+
+use std::fmt;
+
+/// A structural change of a [`FactoryVecDeque`](super::FactoryVecDeque) that can be reverted.
+#[derive(Debug)]
+pub(super) enum Edit<Init> {
+    Inserted { index: usize },
+    Removed { index: usize, init: Init },
+    Moved { from: usize, to: usize },
+    Swapped { first: usize, second: usize },
+}
+
+/// Records structural changes of a [`FactoryVecDeque`](super::FactoryVecDeque),
+/// so they can be undone and redone.
+///
+/// All edits made through one guard are grouped into a single step.
+pub(super) struct Journal<C: FactoryComponent> {
+    pub(super) get_init: fn(&C) -> C::Init,
+    current: Vec<Edit<C::Init>>,
+    pub(super) undo: Vec<Vec<Edit<C::Init>>>,
+    pub(super) redo: Vec<Vec<Edit<C::Init>>>,
+}
+
+impl<C: FactoryComponent> fmt::Debug for Journal<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Journal")
+            .field("current", &self.current.len())
+            .field("undo", &self.undo.len())
+            .field("redo", &self.redo.len())
+            .finish()
+    }
+}
+
+impl<C: FactoryComponent> Journal<C> {
+    pub(super) fn new(get_init: fn(&C) -> C::Init) -> Self {
+        Self {
+            get_init,
+            current: Vec::new(),
+            undo: Vec::new(),
+            redo: Vec::new(),
+        }
+    }
+
+    pub(super) fn record(&mut self, edit: Edit<C::Init>) {
+        self.current.push(edit);
+    }
+
+    pub(super) fn record_removal(&mut self, index: usize, model: &C) {
+        let init = (self.get_init)(model);
+        self.record(Edit::Removed { index, init });
+    }
+
+    /// Finishes the current step.
+    pub(super) fn commit(&mut self) {
+        if !self.current.is_empty() {
+            self.undo.push(std::mem::take(&mut self.current));
+            self.redo.clear();
+        }
+    }
+
+    /// Forgets all recorded steps.
+    pub(super) fn clear(&mut self) {
+        self.current.clear();
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
@@ -0,0 +1,626 @@
+use crate::{Receiver, Sender};
+
+use crate::factory::sync::builder::FactoryBuilder;
+use crate::factory::sync::handle::FactoryHandle;
+use crate::factory::{FactoryComponent, FactoryElementSender, FactoryView};
+
+use super::FactoryElementGuard;
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ops;
+
+#[derive(Debug)]
+/// A builder-pattern struct for building a [`FactoryKeyedVec`].
+pub struct FactoryKeyedVecBuilder<K, C: FactoryComponent> {
+    component: PhantomData<C>,
+    key: PhantomData<K>,
+}
+
+impl<K, C> Default for FactoryKeyedVecBuilder<K, C>
+where
+    C: FactoryComponent,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, C> FactoryKeyedVecBuilder<K, C>
+where
+    C: FactoryComponent,
+    C::ParentWidget: Default,
+{
+    #[must_use]
+    /// Launch the factory with a default parent widget.
+    pub fn launch_default(self) -> FactoryKeyedVecConnector<K, C> {
+        self.launch(Default::default())
+    }
+}
+
+impl<K, C> FactoryKeyedVecBuilder<K, C>
+where
+    C: FactoryComponent,
+{
+    /// Creates a new [`FactoryKeyedVecBuilder`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            component: PhantomData,
+            key: PhantomData,
+        }
+    }
+
+    /// Launch the factory.
+    /// This is similar to [`Connector::launch`](crate::component::ComponentBuilder::launch).
+    pub fn launch(self, widget: C::ParentWidget) -> FactoryKeyedVecConnector<K, C> {
+        let (output_sender, output_receiver) = crate::channel();
+
+        FactoryKeyedVecConnector {
+            widget,
+            output_sender,
+            output_receiver,
+            _key: PhantomData,
+        }
+    }
+}
+
+#[derive(Debug)]
+/// Second stage of the builder-pattern for building a [`FactoryKeyedVec`].
+pub struct FactoryKeyedVecConnector<K, C>
+where
+    C: FactoryComponent,
+{
+    widget: C::ParentWidget,
+    output_sender: Sender<C::Output>,
+    output_receiver: Receiver<C::Output>,
+    _key: PhantomData<K>,
+}
+
+impl<K, C> FactoryKeyedVecConnector<K, C>
+where
+    C: FactoryComponent,
+{
+    /// Forwards output events to the designated sender.
+    pub fn forward<F, Msg>(self, sender_: &Sender<Msg>, f: F) -> FactoryKeyedVec<K, C>
+    where
+        F: Fn(C::Output) -> Msg + Send + 'static,
+        C::Output: Send,
+        Msg: Send + 'static,
+    {
+        let Self {
+            widget,
+            output_sender,
+            output_receiver,
+            ..
+        } = self;
+
+        let sender_clone = sender_.clone();
+
+        crate::spawn(async move {
+            while let Some(msg) = output_receiver.recv().await {
+                if sender_clone.send(f(msg)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        FactoryKeyedVec {
+            widget,
+            output_sender,
+            keys: Vec::new(),
+            inner: HashMap::new(),
+        }
+    }
+
+    /// Ignore outputs from the component and finish the builder.
+    pub fn detach(self) -> FactoryKeyedVec<K, C> {
+        let Self {
+            widget,
+            output_sender,
+            ..
+        } = self;
+
+        FactoryKeyedVec {
+            widget,
+            output_sender,
+            keys: Vec::new(),
+            inner: HashMap::new(),
+        }
+    }
+}
+
+/// An ordered container of factory components that are addressed by keys.
+///
+/// Unlike the [`DynamicIndex`](crate::factory::DynamicIndex) of a
+/// [`FactoryVecDeque`](crate::factory::FactoryVecDeque), the key of an element
+/// never changes when other elements are inserted, removed or moved.
+/// The widgets are always kept in the same order as the elements,
+/// which is the insertion order unless elements are moved or sorted.
+/// This makes it an ordered alternative to
+/// [`FactoryHashMap`](crate::factory::FactoryHashMap).
+///
+/// Changes are applied to the widgets immediately.
+#[derive(Debug)]
+pub struct FactoryKeyedVec<K, C: FactoryComponent> {
+    widget: C::ParentWidget,
+    output_sender: Sender<C::Output>,
+    keys: Vec<K>,
+    inner: HashMap<K, FactoryHandle<C>>,
+}
+
+impl<K, C> Drop for FactoryKeyedVec<K, C>
+where
+    C: FactoryComponent,
+{
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<K, C> ops::Index<&K> for FactoryKeyedVec<K, C>
+where
+    C: FactoryComponent<Index = K>,
+    K: Hash + Eq,
+{
+    type Output = C;
+
+    fn index(&self, key: &K) -> &Self::Output {
+        self.get(key).expect("Called `get` on an invalid key")
+    }
+}
+
+impl<K, C> FactoryKeyedVec<K, C>
+where
+    C: FactoryComponent,
+{
+    /// Creates a new [`FactoryKeyedVec`].
+    #[must_use]
+    pub fn builder() -> FactoryKeyedVecBuilder<K, C> {
+        FactoryKeyedVecBuilder::new()
+    }
+
+    /// Returns the number of elements in the [`FactoryKeyedVec`].
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns true if the [`FactoryKeyedVec`] is empty.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Send clone of a message to all of the elements.
+    pub fn broadcast(&self, msg: C::Input)
+    where
+        C::Input: Clone,
+    {
+        self.inner.values().for_each(|c| c.input.emit(msg.clone()));
+    }
+
+    /// Returns the widget all components are attached to.
+    pub const fn widget(&self) -> &C::ParentWidget {
+        &self.widget
+    }
+
+    /// Returns an iterator over the keys in the order of the elements.
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &K> + ExactSizeIterator + FusedIterator {
+        self.keys.iter()
+    }
+
+    /// Clears the [`FactoryKeyedVec`], removing all factory components.
+    pub fn clear(&mut self) {
+        self.keys.clear();
+        for (_, mut handle) in self.inner.drain() {
+            self.widget.factory_remove(&handle.returned_widget);
+            handle.on_detach();
+        }
+    }
+}
+
+impl<K, C> FactoryKeyedVec<K, C>
+where
+    C: FactoryComponent<Index = K>,
+    K: Hash + Eq,
+{
+    /// Returns an iterator over the keys and models in the order of the elements.
+    pub fn iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (&K, &C)> + ExactSizeIterator + FusedIterator {
+        self.keys
+            .iter()
+            .map(|key| (key, self.inner[key].data.get()))
+    }
+
+    /// Returns an iterator over the models in the order of the elements.
+    pub fn values(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = &C> + ExactSizeIterator + FusedIterator {
+        self.keys.iter().map(|key| self.inner[key].data.get())
+    }
+
+    /// Send a message to one of the elements.
+    pub fn send(&self, key: &K, msg: C::Input) {
+        self.inner[key].input.emit(msg);
+    }
+
+    /// Computes the positions of all elements again and updates their widgets.
+    ///
+    /// Use this method if the [`Position`](crate::factory::Position) of the elements
+    /// depends on other data, for example to reflow a grid after the number
+    /// of columns changed.
+    pub fn reposition_all(&self) {
+        for (key, handle) in self.keys.iter().map(|key| (key, &self.inner[key])) {
+            let position = C::position(handle.data.get(), key);
+            self.widget
+                .factory_update_position(&handle.returned_widget, &position);
+        }
+    }
+
+    /// Returns a [`FactoryElementSender`] for sending messages to one of the elements.
+    ///
+    /// The sender can be used without access to the factory and
+    /// stops delivering messages once the element was removed.
+    ///
+    /// Returns [`None`] if `key` is invalid.
+    pub fn element_sender(&self, key: &K) -> Option<FactoryElementSender<C>> {
+        self.inner
+            .get(key)
+            .map(|c| FactoryElementSender::new(c.input.clone()))
+    }
+
+    /// Returns true if the [`FactoryKeyedVec`] contains an element for the key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.inner.contains_key(key)
+    }
+
+    /// Returns the current position of the element with this key.
+    ///
+    /// Returns [`None`] if `key` is invalid.
+    pub fn position(&self, key: &K) -> Option<usize> {
+        self.keys.iter().position(|k| k == key)
+    }
+
+    /// Tries to get an immutable reference to
+    /// the model of one element.
+    ///
+    /// Returns [`None`] if `key` is invalid.
+    pub fn get(&self, key: &K) -> Option<&C> {
+        self.inner.get(key).map(|c| c.data.get())
+    }
+
+    /// Tries to get the key and an immutable reference to
+    /// the model of the element at `index`.
+    ///
+    /// Returns [`None`] if `index` is out of bounds.
+    pub fn get_index(&self, index: usize) -> Option<(&K, &C)> {
+        self.keys
+            .get(index)
+            .map(|key| (key, self.inner[key].data.get()))
+    }
+
+    /// Tries to get a mutable reference to
+    /// the model of one element.
+    ///
+    /// Returns [`None`] if `key` is invalid.
+    pub fn get_mut(&mut self, key: &K) -> Option<FactoryElementGuard<'_, C>> {
+        self.inner
+            .get_mut(key)
+            .map(|c| FactoryElementGuard { inner: c })
+    }
+}
+
+impl<K, C> FactoryKeyedVec<K, C>
+where
+    C: FactoryComponent<Index = K>,
+    K: Hash + Eq + Clone,
+{
+    /// Appends an element at the end of the [`FactoryKeyedVec`].
+    ///
+    /// If an element with the same key already existed, it is removed and returned.
+    pub fn push_back(&mut self, key: K, init: C::Init) -> Option<C> {
+        let existing = self.remove(&key);
+        self.insert_new(self.len(), key, init);
+        existing
+    }
+
+    /// Prepends an element to the [`FactoryKeyedVec`].
+    ///
+    /// If an element with the same key already existed, it is removed and returned.
+    pub fn push_front(&mut self, key: K, init: C::Init) -> Option<C> {
+        let existing = self.remove(&key);
+        self.insert_new(0, key, init);
+        existing
+    }
+
+    /// Inserts an element at `index`, shifting all following elements towards the back.
+    ///
+    /// If an element with the same key already existed, it is removed and returned.
+    /// In this case `index` refers to the position after the removal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length of the [`FactoryKeyedVec`].
+    pub fn insert(&mut self, index: usize, key: K, init: C::Init) -> Option<C> {
+        let existing = self.remove(&key);
+        self.insert_new(index, key, init);
+        existing
+    }
+
+    fn insert_new(&mut self, index: usize, key: K, init: C::Init) {
+        assert!(index <= self.len(), "Index out of bounds");
+
+        let builder = FactoryBuilder::new(&key, init, self.output_sender.clone());
+        let position = C::position(&builder.data, &key);
+
+        let returned_widget = if index == 0 {
+            self.widget
+                .factory_prepend(builder.root_widget.clone(), &position)
+        } else {
+            let previous = &self.inner[&self.keys[index - 1]].returned_widget;
+            self.widget
+                .factory_insert_after(builder.root_widget.clone(), &position, previous)
+        };
+
+        let component = builder.launch(&key, returned_widget);
+
+        self.keys.insert(index, key.clone());
+        assert!(self.inner.insert(key, component).is_none());
+    }
+
+    /// Removes the element with this key and returns its model.
+    ///
+    /// Returns [`None`] if `key` is invalid.
+    pub fn remove(&mut self, key: &K) -> Option<C> {
+        if let Some(mut handle) = self.inner.remove(key) {
+            self.keys.retain(|k| k != key);
+            self.widget.factory_remove(&handle.returned_widget);
+            handle.on_detach();
+            Some(handle.data.into_inner())
+        } else {
+            None
+        }
+    }
+
+    /// Moves the element with this key to `target`,
+    /// shifting all elements between these positions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is invalid or `target` is out of bounds.
+    pub fn move_to(&mut self, key: &K, target: usize) {
+        let current_position = self
+            .position(key)
+            .expect("Called `move_to` on an invalid key");
+        assert!(target < self.len(), "Index out of bounds");
+
+        if current_position != target {
+            let key = self.keys.remove(current_position);
+            self.keys.insert(target, key);
+            self.move_widget(target);
+        }
+    }
+
+    /// Moves the element with this key to the front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is invalid.
+    pub fn move_front(&mut self, key: &K) {
+        self.move_to(key, 0);
+    }
+
+    /// Moves the element with this key to the back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is invalid.
+    pub fn move_back(&mut self, key: &K) {
+        self.move_to(key, self.len() - 1);
+    }
+
+    /// Sorts the elements with a comparator function that
+    /// receives the keys and models of two elements.
+    ///
+    /// The sort is stable and the components aren't recreated,
+    /// only their widgets are moved.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&K, &C, &K, &C) -> Ordering,
+    {
+        let inner = &self.inner;
+        self.keys
+            .sort_by(|a, b| compare(a, inner[a].data.get(), b, inner[b].data.get()));
+        self.move_all_widgets();
+    }
+
+    /// Sorts the elements with a key extraction function
+    /// that receives the key and model of an element.
+    ///
+    /// The sort is stable and the components aren't recreated,
+    /// only their widgets are moved.
+    pub fn sort_by_key<T, F>(&mut self, mut f: F)
+    where
+        T: Ord,
+        F: FnMut(&K, &C) -> T,
+    {
+        let inner = &self.inner;
+        self.keys.sort_by_key(|key| f(key, inner[key].data.get()));
+        self.move_all_widgets();
+    }
+
+    /// Sorts the elements by their keys.
+    ///
+    /// The components aren't recreated, only their widgets are moved.
+    pub fn sort_keys(&mut self)
+    where
+        K: Ord,
+    {
+        self.keys.sort();
+        self.move_all_widgets();
+    }
+
+    /// Moves all widgets so that they match the order of the keys.
+    fn move_all_widgets(&self) {
+        for index in 0..self.len() {
+            self.move_widget(index);
+        }
+    }
+
+    /// Moves the widget of the element at `index` to the right place.
+    fn move_widget(&self, index: usize) {
+        let widget = &self.inner[&self.keys[index]].returned_widget;
+        if index == 0 {
+            self.widget.factory_move_start(widget);
+        } else {
+            let previous = &self.inner[&self.keys[index - 1]].returned_widget;
+            self.widget.factory_move_after(widget, previous);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use gtk::prelude::Cast;
+
+    use crate::factory::{FactoryComponent, FactorySender, FactoryView};
+    use crate::RelmIterChildrenExt;
+
+    use super::FactoryKeyedVec;
+
+    #[derive(Debug)]
+    struct Element {
+        value: u8,
+    }
+
+    impl FactoryComponent for Element {
+        type ParentWidget = gtk::Box;
+        type CommandOutput = ();
+        type Input = ();
+        type Output = ();
+        type Init = u8;
+        type Root = gtk::Label;
+        type Widgets = gtk::Label;
+        type Index = char;
+
+        fn init_model(value: Self::Init, _: &char, _: FactorySender<Self>) -> Self {
+            Self { value }
+        }
+
+        fn init_root(&self) -> Self::Root {
+            gtk::Label::default()
+        }
+
+        fn init_widgets(
+            &mut self,
+            _: &char,
+            root: Self::Root,
+            _: &<Self::ParentWidget as FactoryView>::ReturnedWidget,
+            _: FactorySender<Self>,
+        ) -> Self::Widgets {
+            root.set_label(&self.value.to_string());
+            root
+        }
+    }
+
+    fn factory(elements: &[(char, u8)]) -> FactoryKeyedVec<char, Element> {
+        let mut factory = FactoryKeyedVec::builder().launch_default().detach();
+        for (key, value) in elements {
+            factory.push_back(*key, *value);
+        }
+        factory
+    }
+
+    fn keys(factory: &FactoryKeyedVec<char, Element>) -> Vec<char> {
+        factory.keys().copied().collect()
+    }
+
+    /// Values shown by the widgets, in the order of the widgets.
+    fn rendered(factory: &FactoryKeyedVec<char, Element>) -> Vec<u8> {
+        factory
+            .widget()
+            .iter_children()
+            .map(|child| {
+                let label = child.downcast::<gtk::Label>().unwrap();
+                label.label().parse().unwrap()
+            })
+            .collect()
+    }
+
+    #[gtk::test]
+    fn insert() {
+        let mut factory = factory(&[('a', 1), ('b', 2)]);
+        assert!(factory.push_front('c', 3).is_none());
+        assert!(factory.insert(1, 'd', 4).is_none());
+
+        assert_eq!(keys(&factory), ['c', 'd', 'a', 'b']);
+        assert_eq!(rendered(&factory), [3, 4, 1, 2]);
+        assert_eq!(factory.position(&'a'), Some(2));
+        assert_eq!(factory.get_index(1).map(|(key, _)| *key), Some('d'));
+
+        // Inserting an existing key replaces the element.
+        let replaced = factory.push_back('c', 5).unwrap();
+        assert_eq!(replaced.value, 3);
+        assert_eq!(keys(&factory), ['d', 'a', 'b', 'c']);
+        assert_eq!(rendered(&factory), [4, 1, 2, 5]);
+        assert_eq!(factory[&'c'].value, 5);
+    }
+
+    #[gtk::test]
+    fn remove() {
+        let mut factory = factory(&[('a', 1), ('b', 2), ('c', 3)]);
+
+        assert_eq!(factory.remove(&'b').unwrap().value, 2);
+        assert!(factory.remove(&'b').is_none());
+        assert!(!factory.contains_key(&'b'));
+        assert_eq!(keys(&factory), ['a', 'c']);
+        assert_eq!(rendered(&factory), [1, 3]);
+
+        factory.clear();
+        assert!(factory.is_empty());
+        assert!(rendered(&factory).is_empty());
+    }
+
+    #[gtk::test]
+    fn move_elements() {
+        let mut factory = factory(&[('a', 1), ('b', 2), ('c', 3), ('d', 4)]);
+        let widgets: Vec<gtk::Widget> = factory.widget().iter_children().collect();
+
+        factory.move_to(&'a', 2);
+        assert_eq!(keys(&factory), ['b', 'c', 'a', 'd']);
+        assert_eq!(rendered(&factory), [2, 3, 1, 4]);
+
+        factory.move_front(&'d');
+        assert_eq!(keys(&factory), ['d', 'b', 'c', 'a']);
+        assert_eq!(rendered(&factory), [4, 2, 3, 1]);
+
+        factory.move_back(&'b');
+        assert_eq!(keys(&factory), ['d', 'c', 'a', 'b']);
+        assert_eq!(rendered(&factory), [4, 3, 1, 2]);
+
+        // The widgets are moved instead of being recreated.
+        for widget in factory.widget().iter_children() {
+            assert!(widgets.contains(&widget));
+        }
+    }
+
+    #[gtk::test]
+    fn sort() {
+        let mut factory = factory(&[('c', 2), ('a', 3), ('d', 1), ('b', 2)]);
+
+        factory.sort_by_key(|_, element| element.value);
+        assert_eq!(keys(&factory), ['d', 'c', 'b', 'a']);
+        assert_eq!(rendered(&factory), [1, 2, 2, 3]);
+
+        factory.sort_keys();
+        assert_eq!(keys(&factory), ['a', 'b', 'c', 'd']);
+        assert_eq!(rendered(&factory), [3, 2, 2, 1]);
+
+        factory.sort_by(|a, _, b, _| b.cmp(a));
+        assert_eq!(keys(&factory), ['d', 'c', 'b', 'a']);
+        assert_eq!(rendered(&factory), [1, 2, 2, 3]);
+    }
+}
@@ -1,14 +1,28 @@
 //! Containers similar to [`std::collections`] that can be used to store factory data.
 
 mod hashmap;
+mod journal;
+mod keyed_vec;
 mod vec_deque;
+mod virtual_list;
 
-pub use hashmap::{FactoryHashMap, FactoryHashMapBuilder, FactoryHashMapConnector};
+pub use hashmap::{
+    FactoryHashMap, FactoryHashMapBuilder, FactoryHashMapConnector, FactoryHashMapEntry,
+    FactoryHashMapOccupiedEntry, FactoryHashMapVacantEntry,
+};
+pub use keyed_vec::{FactoryKeyedVec, FactoryKeyedVecBuilder, FactoryKeyedVecConnector};
 pub use vec_deque::{
-    FactoryVecDeque, FactoryVecDequeBuilder, FactoryVecDequeConnector, FactoryVecDequeGuard,
+    FactoryElementMut, FactoryVecDeque, FactoryVecDequeBuilder, FactoryVecDequeConnector,
+    FactoryVecDequeGuard,
+};
+pub use virtual_list::{
+    FactoryVirtualList, FactoryVirtualListBuilder, FactoryVirtualListConnector,
 };
 
-use crate::factory::DynamicIndex;
+use crate::factory::sync::handle::FactoryHandle;
+use crate::factory::{DynamicIndex, FactoryComponent};
+
+use std::ops;
 
 #[derive(Debug)]
 struct RenderedState {
@@ -22,4 +36,47 @@ struct ModelStateValue {
     index: DynamicIndex,
     uid: usize,
     changed: bool,
+    visible: bool,
+    selected: bool,
+}
+
+/// Provides mutable access to the model of a single element.
+///
+/// The view of the element is updated after the guard goes out of scope.
+#[derive(Debug)]
+#[must_use]
+pub struct FactoryElementGuard<'a, C>
+where
+    C: FactoryComponent,
+{
+    pub(in crate::factory::sync) inner: &'a mut FactoryHandle<C>,
+}
+
+impl<'a, C> ops::Deref for FactoryElementGuard<'a, C>
+where
+    C: FactoryComponent,
+{
+    type Target = C;
+
+    fn deref(&self) -> &Self::Target {
+        self.inner.data.get()
+    }
+}
+
+impl<'a, C> ops::DerefMut for FactoryElementGuard<'a, C>
+where
+    C: FactoryComponent,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.inner.data.get_mut()
+    }
+}
+
+impl<'a, C> Drop for FactoryElementGuard<'a, C>
+where
+    C: FactoryComponent,
+{
+    fn drop(&mut self) {
+        self.inner.notifier.send(()).unwrap()
+    }
 }
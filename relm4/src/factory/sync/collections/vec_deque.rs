@@ -1,25 +1,62 @@
 use crate::{Receiver, Sender};
 
+use crate::component::PersistentComponent;
 use crate::factory::sync::builder::FactoryBuilder;
 use crate::factory::sync::component_storage::ComponentStorage;
-use crate::factory::sync::traits::CloneableFactoryComponent;
-use crate::factory::{DynamicIndex, FactoryComponent, FactoryView};
+use crate::factory::sync::handle::{RebindFn, RecycledWidgets};
+use crate::factory::sync::traits::{CloneableFactoryComponent, RecyclableFactoryComponent};
+use crate::factory::{DynamicIndex, FactoryComponent, FactoryElementSender, FactoryView};
 
+use super::journal::{Edit, Journal};
 use super::{ModelStateValue, RenderedState};
 
+use std::cell::RefCell;
+use std::cmp::Ordering;
 use std::collections::hash_map::DefaultHasher;
-use std::collections::VecDeque;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::{self, Debug};
 use std::hash::Hash;
 use std::iter::FusedIterator;
 use std::marker::PhantomData;
-use std::ops::{Deref, Index, IndexMut};
+use std::ops::{Bound, Deref, DerefMut, Index, IndexMut, Range, RangeBounds};
+use std::rc::Rc;
 
-#[cfg(feature = "libadwaita")]
-use gtk::prelude::Cast;
+use gtk::prelude::{ObjectExt, StaticType, ToValue, WidgetExt};
+use gtk::{gdk, glib};
 
 #[cfg(feature = "libadwaita")]
 use std::hash::Hasher;
 
+/// Returns the widget of the closest element before `index` that was already launched.
+fn previous_returned_widget<C: FactoryComponent>(
+    components: &VecDeque<ComponentStorage<C>>,
+    index: usize,
+) -> Option<&<C::ParentWidget as FactoryView>::ReturnedWidget> {
+    components
+        .range(..index)
+        .rev()
+        .find_map(ComponentStorage::returned_widget)
+}
+
+/// Converts any range into a [`Range`] for a collection with `len` elements.
+fn resolve_range<R: RangeBounds<usize>>(range: &R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start
+            .checked_add(1)
+            .expect("attempted to index slice from after maximum usize"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end
+            .checked_add(1)
+            .expect("attempted to index slice up to maximum usize"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    start..end
+}
+
 /// Provides methods to edit the underlying [`FactoryVecDeque`].
 ///
 /// The changes will be rendered on the widgets after the guard goes out of scope.
@@ -37,6 +74,9 @@ where
     C: FactoryComponent<Index = DynamicIndex>,
 {
     fn drop(&mut self) {
+        if let Some(journal) = &mut self.inner.journal {
+            journal.commit();
+        }
         self.inner.render_changes();
     }
 }
@@ -134,6 +174,19 @@ where
             .map(ComponentStorage::get_mut)
     }
 
+    /// Returns the [`DynamicIndex`] and a mutable reference to the model
+    /// of the first element whose model matches the predicate.
+    ///
+    /// Returns [`None`] if no element matches.
+    pub fn find_mut<F>(&mut self, mut predicate: F) -> Option<(DynamicIndex, &mut C)>
+    where
+        F: FnMut(&C) -> bool,
+    {
+        let index = self.position(&mut predicate)?;
+        let dyn_index = self.inner.model_state[index].index.clone();
+        self.get_mut(index).map(|model| (dyn_index, model))
+    }
+
     /// Provides a mutable reference to the model of the back element.
     ///
     ///  Returns [`None`] if the deque is empty.
@@ -172,6 +225,10 @@ where
         self.inner.model_state.remove(index);
         let component = self.inner.components.remove(index);
 
+        if let (Some(journal), Some(component)) = (&mut self.inner.journal, &component) {
+            journal.record_removal(index, component.get());
+        }
+
         // Decrement the indexes of the following elements.
         for states in self.inner.model_state.iter_mut().skip(index) {
             states.index.decrement();
@@ -186,6 +243,64 @@ where
         component.map(ComponentStorage::extract)
     }
 
+    /// Removes an element from anywhere in the [`FactoryVecDeque`] and returns it,
+    /// replacing it with the last element.
+    ///
+    /// This does not preserve ordering, but only the widget of the last element
+    /// needs to be moved.
+    /// Returns [`None`] if index is out of bounds.
+    ///
+    /// Element at index 0 is the front of the queue.
+    pub fn swap_remove(&mut self, index: usize) -> Option<C> {
+        let last = self.len().checked_sub(1)?;
+        if index > last {
+            None
+        } else {
+            self.swap(index, last);
+            self.pop_back()
+        }
+    }
+
+    /// Removes the specified range from the [`FactoryVecDeque`]
+    /// and returns the removed elements.
+    ///
+    /// Element at index 0 is the front of the queue.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the [`FactoryVecDeque`].
+    pub fn drain<R>(&mut self, range: R) -> Vec<C>
+    where
+        R: RangeBounds<usize>,
+    {
+        let Range { start, end } = resolve_range(&range, self.len());
+
+        self.inner.model_state.drain(start..end);
+        let components: Vec<_> = self.inner.components.drain(start..end).collect();
+
+        if let Some(journal) = &mut self.inner.journal {
+            for component in &components {
+                journal.record_removal(start, component.get());
+            }
+        }
+
+        // Update the indexes of the following elements.
+        for (index, state) in self.inner.model_state.iter().enumerate().skip(start) {
+            state.index.set_value(index);
+        }
+
+        components
+            .into_iter()
+            .map(|component| {
+                if let Some(widget) = component.returned_widget() {
+                    self.inner.widget.factory_remove(widget);
+                }
+                component.extract()
+            })
+            .collect()
+    }
+
     /// Appends an element at the end of the [`FactoryVecDeque`].
     pub fn push_back(&mut self, init: C::Init) -> DynamicIndex {
         let index = self.len();
@@ -207,14 +322,28 @@ where
     ///
     /// Panics if index is greater than [`FactoryVecDeque`]’s length.
     pub fn insert(&mut self, index: usize, init: C::Init) -> DynamicIndex {
-        let dyn_index = DynamicIndex::new(index);
-
         // Increment the indexes of the following elements.
         for states in self.inner.model_state.iter_mut().skip(index) {
             states.index.increment();
         }
 
-        let builder = FactoryBuilder::new(&dyn_index, init, self.output_sender.clone());
+        let recycled = self
+            .inner
+            .recycle_pool
+            .as_mut()
+            .and_then(|pool| pool.elements.pop());
+
+        let dyn_index = DynamicIndex::new(index);
+        let builder = if let Some(recycled) = recycled {
+            FactoryBuilder::recycle(&dyn_index, init, self.output_sender.clone(), recycled)
+        } else {
+            FactoryBuilder::new(&dyn_index, init, self.output_sender.clone())
+        };
+
+        // Replaces the drag and drop controllers of recycled widgets.
+        if let Some(drag_setup) = &self.inner.drag_setup {
+            drag_setup(&builder.root_widget, Some(&dyn_index));
+        }
 
         self.inner
             .components
@@ -225,13 +354,141 @@ where
                 index: dyn_index.clone(),
                 uid: self.uid_counter,
                 changed: false,
+                visible: true,
+                selected: false,
             },
         );
         self.inner.uid_counter += 1;
 
+        if let Some(journal) = &mut self.inner.journal {
+            journal.record(Edit::Inserted { index });
+        }
+
         dyn_index
     }
 
+    /// Moves the element at `index` into `other` at position `target`
+    /// without recreating it.
+    ///
+    /// The model, the widgets and the running commands of the element are kept.
+    /// [`FactoryComponent::on_detach`] and [`FactoryComponent::on_attach`]
+    /// are called when the root widget is moved to the parent widget of `other`
+    /// and all outputs of the element are sent to `other` afterwards.
+    /// Only [`FactorySender::output`](crate::factory::FactorySender::output) follows the element,
+    /// senders returned by [`FactorySender::output_sender`](crate::factory::FactorySender::output_sender)
+    /// keep sending to the factory the element was created in.
+    ///
+    /// Returns the [`DynamicIndex`] of the element, which
+    /// now refers to its position in `other`.
+    ///
+    /// Drag and drop set up by [`FactoryVecDeque::enable_drag_reorder`]
+    /// of this factory is removed from the element and replaced by the one of `other`.
+    /// The journals enabled by [`FactoryVecDeque::enable_journal`]
+    /// of both factories are cleared.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds or if `target`
+    /// is greater than the length of `other`.
+    pub fn transfer(
+        &mut self,
+        index: usize,
+        other: &mut FactoryVecDequeGuard<'_, C>,
+        target: usize,
+    ) -> DynamicIndex {
+        assert!(target <= other.len(), "Target index out of bounds");
+
+        let state = self
+            .inner
+            .model_state
+            .remove(index)
+            .expect("Index out of bounds");
+        let mut component = self.inner.components.remove(index).unwrap();
+
+        // Decrement the indexes of the following elements.
+        for states in self.inner.model_state.iter_mut().skip(index) {
+            states.index.decrement();
+        }
+
+        if let Some(widget) = component.returned_widget() {
+            // Don't carry the filter state over to the other factory.
+            if !state.visible {
+                self.inner.widget.factory_set_visible(widget, true);
+            }
+            self.inner.widget.factory_remove(widget);
+        }
+        component.on_detach();
+        component.set_output_sender(other.inner.output_sender.clone());
+
+        // Drops must only be reported to the factory that owns the element.
+        if let Some(drag_setup) = &other.inner.drag_setup {
+            drag_setup(component.widget(), Some(&state.index));
+        } else if let Some(drag_setup) = &self.inner.drag_setup {
+            drag_setup(component.widget(), None);
+        }
+
+        // Transfers can't be undone.
+        for journal in [&mut self.inner.journal, &mut other.inner.journal]
+            .into_iter()
+            .flatten()
+        {
+            journal.clear();
+        }
+
+        // Increment the indexes of the following elements.
+        for states in other.inner.model_state.iter_mut().skip(target) {
+            states.index.increment();
+        }
+
+        state.index.set_value(target);
+        other.inner.components.insert(target, component);
+        other.inner.model_state.insert(
+            target,
+            ModelStateValue {
+                index: state.index.clone(),
+                uid: other.inner.uid_counter,
+                changed: false,
+                visible: true,
+                selected: false,
+            },
+        );
+        other.inner.uid_counter += 1;
+
+        state.index
+    }
+
+    /// Moves all elements of `other` to the end of this factory without recreating them,
+    /// leaving `other` empty.
+    ///
+    /// This works like calling [`transfer`](Self::transfer) for every element of `other`,
+    /// so the same notes apply.
+    pub fn append(&mut self, other: &mut FactoryVecDequeGuard<'_, C>) {
+        let start = self.len();
+        // Start at the back, so no indexes need to be updated in `other`.
+        for index in (0..other.len()).rev() {
+            other.transfer(index, self, start);
+        }
+    }
+
+    /// Moves the elements at `at` and after to the end of `other`
+    /// without recreating them.
+    ///
+    /// This works like calling [`transfer`](Self::transfer) for every moved element,
+    /// so the same notes apply.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the length of the factory.
+    pub fn split_off(&mut self, at: usize, other: &mut FactoryVecDequeGuard<'_, C>) {
+        assert!(at <= self.len(), "Index out of bounds");
+
+        let start = other.len();
+        // Start at the back, so no indexes need to be updated in this factory.
+        for index in (at..self.len()).rev() {
+            self.transfer(index, other, start);
+        }
+    }
+
     /// Swaps elements at indices `first` and `second`.
     ///
     /// `first` and `second` may be equal.
@@ -250,6 +507,10 @@ where
             // Update indexes.
             self.model_state[first].index.set_value(first);
             self.model_state[second].index.set_value(second);
+
+            if let Some(journal) = &mut self.inner.journal {
+                journal.record(Edit::Swapped { first, second });
+            }
         }
     }
 
@@ -298,6 +559,13 @@ where
                     state.index.decrement();
                 }
             }
+
+            if let Some(journal) = &mut self.inner.journal {
+                journal.record(Edit::Moved {
+                    from: current_position,
+                    to: target,
+                });
+            }
         }
     }
 
@@ -321,18 +589,361 @@ where
         self.move_to(current_position, self.len() - 1);
     }
 
+    /// Updates the [`FactoryVecDeque`] to match the elements of an iterator
+    /// with as few changes as possible.
+    ///
+    /// Each item of the iterator consists of a key and the data to initialize
+    /// a new element. The `key` function computes the key of existing elements.
+    /// Elements with matching keys are reused and moved into place,
+    /// which keeps their state and widgets intact.
+    /// All other elements are removed and new elements are created for
+    /// keys that didn't exist before.
+    ///
+    /// The initialization data of reused elements is dropped, so
+    /// changes to existing elements must be applied separately, for example
+    /// with [`Self::get_mut`].
+    pub fn sync_from_iter<K, I, F>(&mut self, iter: I, key: F)
+    where
+        I: IntoIterator<Item = (K, C::Init)>,
+        F: Fn(&C) -> K,
+        K: Hash + Eq,
+    {
+        // Dynamic indexes stay valid while elements are moved.
+        let mut existing: HashMap<K, DynamicIndex> = self
+            .inner
+            .components
+            .iter()
+            .zip(self.inner.model_state.iter())
+            .map(|(component, state)| (key(component.get()), state.index.clone()))
+            .collect();
+
+        let mut target = 0;
+        for (key, init) in iter {
+            if let Some(index) = existing.remove(&key) {
+                self.move_to(index.current_index(), target);
+            } else {
+                self.insert(target, init);
+            }
+            target += 1;
+        }
+
+        // All elements that weren't reused are now at the back.
+        self.drain(target..);
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all elements `e` for which `f(&e)` returns false.
+    /// This method operates in place, visiting each element exactly once in the
+    /// original order, and preserves the order of the retained elements.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&C) -> bool,
+    {
+        self.retain_storage(|component, _| f(component.get()));
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all elements `e` for which `f(&mut e)` returns false.
+    /// This method operates in place, visiting each element exactly once in the
+    /// original order, and preserves the order of the retained elements.
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut C) -> bool,
+    {
+        self.retain_storage(|component, state| {
+            // Mark as modified
+            state.changed = true;
+            f(component.get_mut())
+        });
+    }
+
+    fn retain_storage<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut ComponentStorage<C>, &mut ModelStateValue) -> bool,
+    {
+        let elements: Vec<_> = self
+            .inner
+            .components
+            .drain(..)
+            .zip(self.inner.model_state.drain(..))
+            .collect();
+
+        for (mut component, mut state) in elements {
+            if f(&mut component, &mut state) {
+                // Update indexes.
+                state.index.set_value(self.inner.components.len());
+                self.inner.components.push_back(component);
+                self.inner.model_state.push_back(state);
+            } else {
+                if let Some(journal) = &mut self.inner.journal {
+                    journal.record_removal(self.inner.components.len(), component.get());
+                }
+
+                self.inner.discard(component, state);
+            }
+        }
+    }
+
+    /// Sorts the elements of the [`FactoryVecDeque`] with a comparator function.
+    ///
+    /// The sort is stable. Existing components are not recreated,
+    /// their widgets are just reordered once the guard is dropped.
+    ///
+    /// Sorting clears the journal enabled by [`FactoryVecDeque::enable_journal`].
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&C, &C) -> Ordering,
+    {
+        let mut elements: Vec<_> = self
+            .inner
+            .components
+            .drain(..)
+            .zip(self.inner.model_state.drain(..))
+            .collect();
+
+        elements.sort_by(|(first, _), (second, _)| compare(first.get(), second.get()));
+
+        // Sorting can't be undone.
+        if let Some(journal) = &mut self.inner.journal {
+            journal.clear();
+        }
+
+        for (index, (component, state)) in elements.into_iter().enumerate() {
+            // Update indexes.
+            state.index.set_value(index);
+            self.inner.components.push_back(component);
+            self.inner.model_state.push_back(state);
+        }
+    }
+
+    /// Sorts the elements of the [`FactoryVecDeque`] with a key extraction function.
+    ///
+    /// The sort is stable. See [`Self::sort_by`] for more details.
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&C) -> K,
+        K: Ord,
+    {
+        self.sort_by(|first, second| f(first).cmp(&f(second)));
+    }
+
+    /// Sets a filter that decides which elements are visible.
+    ///
+    /// Elements for which `filter` returns `false` are hidden, but their
+    /// components stay alive inside the [`FactoryVecDeque`] and keep their state.
+    /// The filter is evaluated again every time the changes are rendered.
+    pub fn set_filter<F>(&mut self, filter: F)
+    where
+        F: Fn(&C) -> bool + 'static,
+    {
+        self.inner.filter = Some(Box::new(filter));
+    }
+
+    /// Removes the filter and shows all elements again.
+    pub fn clear_filter(&mut self) {
+        self.inner.filter = None;
+    }
+
+    /// Only shows `page_size` elements at a time or all elements if [`None`] is passed.
+    ///
+    /// Elements outside of the current page are hidden, but their
+    /// components stay alive inside the [`FactoryVecDeque`] and keep their state.
+    /// If a filter is set, pages only contain elements that match the filter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `page_size` is zero.
+    pub fn set_page_size(&mut self, page_size: Option<usize>) {
+        assert_ne!(page_size, Some(0), "Page size must not be zero");
+        self.inner.pagination.page_size = page_size;
+    }
+
+    /// Shows the page at `page`.
+    ///
+    /// Pages beyond the last page show the last page instead.
+    pub fn set_page(&mut self, page: usize) {
+        self.inner.pagination.page = page;
+    }
+
+    /// Shows the next page.
+    ///
+    /// Returns false if the current page is already the last page.
+    pub fn next_page(&mut self) -> bool {
+        let has_next = self.page() + 1 < self.page_count();
+        if has_next {
+            self.inner.pagination.page += 1;
+        }
+        has_next
+    }
+
+    /// Shows the previous page.
+    ///
+    /// Returns false if the current page is already the first page.
+    pub fn prev_page(&mut self) -> bool {
+        let has_prev = self.page() > 0;
+        if has_prev {
+            self.inner.pagination.page -= 1;
+        }
+        has_prev
+    }
+
+    /// Reverts the changes recorded by the journal for the last guard
+    /// that changed the structure of the [`FactoryVecDeque`].
+    ///
+    /// Removed elements are recreated from their initialization data.
+    /// Returns false if there's nothing to undo or if
+    /// the journal isn't enabled by [`FactoryVecDeque::enable_journal`].
+    pub fn undo(&mut self) -> bool {
+        self.apply_journal_step(true)
+    }
+
+    /// Applies the changes reverted by [`Self::undo`] again.
+    ///
+    /// Returns false if there's nothing to redo or if
+    /// the journal isn't enabled by [`FactoryVecDeque::enable_journal`].
+    pub fn redo(&mut self) -> bool {
+        self.apply_journal_step(false)
+    }
+
+    fn apply_journal_step(&mut self, undo: bool) -> bool {
+        // Take the journal, so the reverted edits aren't recorded.
+        if let Some(mut journal) = self.inner.journal.take() {
+            journal.commit();
+
+            let step = if undo {
+                journal.undo.pop()
+            } else {
+                journal.redo.pop()
+            };
+            let applied = step.is_some();
+
+            if let Some(step) = step {
+                let reverted = step
+                    .into_iter()
+                    .rev()
+                    .map(|edit| self.revert_edit(edit, journal.get_init))
+                    .collect();
+                if undo {
+                    journal.redo.push(reverted);
+                } else {
+                    journal.undo.push(reverted);
+                }
+            }
+
+            self.inner.journal = Some(journal);
+            applied
+        } else {
+            false
+        }
+    }
+
+    /// Reverts an edit and returns the edit that reverts it again.
+    fn revert_edit(&mut self, edit: Edit<C::Init>, get_init: fn(&C) -> C::Init) -> Edit<C::Init> {
+        match edit {
+            Edit::Inserted { index } => {
+                let model = self.remove(index).unwrap();
+                Edit::Removed {
+                    index,
+                    init: get_init(&model),
+                }
+            }
+            Edit::Removed { index, init } => {
+                self.insert(index, init);
+                Edit::Inserted { index }
+            }
+            Edit::Moved { from, to } => {
+                self.move_to(to, from);
+                Edit::Moved { from: to, to: from }
+            }
+            Edit::Swapped { first, second } => {
+                self.swap(first, second);
+                Edit::Swapped { first, second }
+            }
+        }
+    }
+
+    /// Computes the positions of all elements again once the guard is dropped.
+    ///
+    /// Usually, positions are only updated for elements whose index changed.
+    /// Use this method if the [`Position`](crate::factory::Position) of the elements
+    /// depends on other data, for example to reflow a grid after the number
+    /// of columns changed.
+    pub fn reposition_all(&mut self) {
+        self.inner.reposition = true;
+    }
+
+    /// Selects or deselects the element at `index`.
+    ///
+    /// The selection follows the element when it is moved
+    /// and is forgotten when the element is removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set_selected(&mut self, index: usize, selected: bool) {
+        self.inner.model_state[index].selected = selected;
+    }
+
+    /// Toggles the selection of the element at `index`
+    /// and returns whether it is selected now.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn toggle_selected(&mut self, index: usize) -> bool {
+        let state = &mut self.inner.model_state[index];
+        state.selected = !state.selected;
+        state.selected
+    }
+
+    /// Selects all elements in the range
+    /// without changing the selection of other elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the [`FactoryVecDeque`].
+    pub fn select_range<R>(&mut self, range: R)
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = resolve_range(&range, self.len());
+        self.inner
+            .model_state
+            .range_mut(range)
+            .for_each(|state| state.selected = true);
+    }
+
+    /// Selects all elements.
+    pub fn select_all(&mut self) {
+        self.select_range(..);
+    }
+
+    /// Deselects all elements.
+    pub fn clear_selection(&mut self) {
+        self.inner
+            .model_state
+            .iter_mut()
+            .for_each(|state| state.selected = false);
+    }
+
     /// Remove all components from the [`FactoryVecDeque`].
     pub fn clear(&mut self) {
-        self.inner.model_state.clear();
+        let elements: Vec<_> = self
+            .inner
+            .components
+            .drain(..)
+            .zip(self.inner.model_state.drain(..))
+            .collect();
 
-        for component in self.inner.components.drain(..) {
-            // Remove all widgets
-            if let Some(widget) = component.returned_widget() {
-                self.inner.widget.factory_remove(widget);
+        for (component, state) in elements {
+            if let Some(journal) = &mut self.inner.journal {
+                journal.record_removal(0, component.get());
             }
 
-            // Make sure the component is shutdown properly
-            component.extract();
+            self.inner.discard(component, state);
         }
 
         self.inner.rendered_state.clear();
@@ -352,6 +963,53 @@ where
                 component.get_mut()
             })
     }
+
+    /// Returns an iterator over the components that only marks
+    /// components as changed if they are actually mutated.
+    ///
+    /// Unlike [`iter_mut`](Self::iter_mut), which updates the view of every element,
+    /// only the views of elements that were mutably dereferenced
+    /// are updated once the guard is dropped.
+    /// This avoids redrawing the whole list if only a few elements change.
+    pub fn iter_mut_tracked(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = FactoryElementMut<'_, C>> + ExactSizeIterator + FusedIterator
+    {
+        self.inner
+            .components
+            .iter_mut()
+            .zip(self.inner.model_state.iter_mut())
+            .map(|(component, state)| FactoryElementMut {
+                model: component.get_mut(),
+                changed: &mut state.changed,
+            })
+    }
+}
+
+/// Mutable access to the model of an element that keeps
+/// track of whether the model was changed.
+///
+/// The element is only marked as changed if the model is mutably dereferenced.
+/// Its view is updated once the [`FactoryVecDequeGuard`] is dropped.
+#[derive(Debug)]
+pub struct FactoryElementMut<'a, C> {
+    model: &'a mut C,
+    changed: &'a mut bool,
+}
+
+impl<'a, C> Deref for FactoryElementMut<'a, C> {
+    type Target = C;
+
+    fn deref(&self) -> &Self::Target {
+        self.model
+    }
+}
+
+impl<'a, C> DerefMut for FactoryElementMut<'a, C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        *self.changed = true;
+        self.model
+    }
 }
 
 impl<'a, C> Deref for FactoryVecDequeGuard<'a, C>
@@ -365,7 +1023,27 @@ where
     }
 }
 
-impl<'a, C> Index<usize> for FactoryVecDequeGuard<'a, C>
+impl<'a, C> Extend<C::Init> for FactoryVecDequeGuard<'a, C>
+where
+    C: FactoryComponent<Index = DynamicIndex>,
+{
+    /// Appends all elements of the iterator at the end of the [`FactoryVecDeque`].
+    ///
+    /// The widgets of all new elements are created together
+    /// once the guard is dropped.
+    fn extend<T: IntoIterator<Item = C::Init>>(&mut self, iter: T) {
+        let iter = iter.into_iter();
+        let (lower_bound, _) = iter.size_hint();
+        self.inner.components.reserve(lower_bound);
+        self.inner.model_state.reserve(lower_bound);
+
+        for init in iter {
+            self.push_back(init);
+        }
+    }
+}
+
+impl<'a, C> Index<usize> for FactoryVecDequeGuard<'a, C>
 where
     C: FactoryComponent<Index = DynamicIndex>,
 {
@@ -486,6 +1164,15 @@ where
             rendered_state: VecDeque::new(),
             // 0 is always an invalid uid
             uid_counter: 1,
+            filter: None,
+            drag_setup: None,
+            empty_changed: None,
+            reported_empty: true,
+            pagination: Pagination::default(),
+            journal: None,
+            reposition: false,
+            batch_rendering: None,
+            recycle_pool: None,
         }
     }
 
@@ -504,6 +1191,15 @@ where
             rendered_state: VecDeque::new(),
             // 0 is always an invalid uid
             uid_counter: 1,
+            filter: None,
+            drag_setup: None,
+            empty_changed: None,
+            reported_empty: true,
+            pagination: Pagination::default(),
+            journal: None,
+            reposition: false,
+            batch_rendering: None,
+            recycle_pool: None,
         }
     }
 }
@@ -512,7 +1208,16 @@ where
 /// data associated with components that implement [`FactoryComponent`].
 ///
 /// To access mutable methods of the factory, create a guard using [`Self::guard`].
-#[derive(Debug)]
+///
+/// # Large collections
+///
+/// Every element in the factory has its own widgets, so a factory with
+/// thousands of elements also creates thousands of widget trees.
+/// [`Self::enable_recycling`] only avoids building new widgets for elements that
+/// replace discarded ones, the number of widget trees stays the same.
+/// For very large collections, use [`FactoryVirtualList`](super::FactoryVirtualList)
+/// instead. It keeps all models in memory, but only creates widgets for the
+/// visible elements and recycles them while scrolling.
 pub struct FactoryVecDeque<C>
 where
     C: FactoryComponent<Index = DynamicIndex>,
@@ -523,6 +1228,103 @@ where
     model_state: VecDeque<ModelStateValue>,
     rendered_state: VecDeque<RenderedState>,
     uid_counter: usize,
+    filter: Option<Box<dyn Fn(&C) -> bool>>,
+    drag_setup: Option<Box<DragSetupFn<C>>>,
+    empty_changed: Option<Box<dyn Fn(bool)>>,
+    /// Whether the factory was empty the last time the changes were rendered.
+    reported_empty: bool,
+    pagination: Pagination,
+    journal: Option<Journal<C>>,
+    reposition: bool,
+    batch_rendering: Option<BatchRendering>,
+    recycle_pool: Option<RecyclePool<C>>,
+}
+
+/// Widgets of discarded elements that can be reused for new elements.
+struct RecyclePool<C: FactoryComponent> {
+    capacity: usize,
+    elements: Vec<RecycledWidgets<C>>,
+    rebind: RebindFn<C>,
+}
+
+/// Limits the number of elements that are launched in a single render.
+struct BatchRendering {
+    batch_size: usize,
+    render_next: Rc<dyn Fn()>,
+}
+
+/// Page state of a [`FactoryVecDeque`].
+#[derive(Default)]
+struct Pagination {
+    page_size: Option<usize>,
+    page: usize,
+    /// The page and number of pages of the last render.
+    rendered: Option<(usize, usize)>,
+    page_changed: Option<Box<dyn Fn(usize, usize)>>,
+}
+
+impl Debug for Pagination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pagination")
+            .field("page_size", &self.page_size)
+            .field("page", &self.page)
+            .field("rendered", &self.rendered)
+            .field(
+                "page_changed",
+                &self.page_changed.as_ref().map(|_| "<page changed fn>"),
+            )
+            .finish()
+    }
+}
+
+impl Pagination {
+    fn page_count(&self, len: usize) -> usize {
+        self.page_size.map_or(1, |size| len.div_ceil(size).max(1))
+    }
+}
+
+/// The key of the controllers added by [`FactoryVecDeque::enable_drag_reorder`].
+const DRAG_CONTROLLERS_KEY: &str = "relm4-drag-reorder-controllers";
+
+/// Replaces the drag and drop controllers of an element
+/// or only removes them if no index is passed.
+type DragSetupFn<C> = dyn Fn(&<C as FactoryComponent>::Root, Option<&DynamicIndex>);
+
+impl<C> Debug for FactoryVecDeque<C>
+where
+    C: FactoryComponent<Index = DynamicIndex>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FactoryVecDeque")
+            .field("widget", &self.widget)
+            .field("output_sender", &self.output_sender)
+            .field("components", &self.components)
+            .field("model_state", &self.model_state)
+            .field("rendered_state", &self.rendered_state)
+            .field("uid_counter", &self.uid_counter)
+            .field("filter", &self.filter.as_ref().map(|_| "<filter fn>"))
+            .field(
+                "drag_setup",
+                &self.drag_setup.as_ref().map(|_| "<drag setup fn>"),
+            )
+            .field(
+                "empty_changed",
+                &self.empty_changed.as_ref().map(|_| "<empty changed fn>"),
+            )
+            .field("reported_empty", &self.reported_empty)
+            .field("pagination", &self.pagination)
+            .field("journal", &self.journal)
+            .field("reposition", &self.reposition)
+            .field(
+                "batch_size",
+                &self.batch_rendering.as_ref().map(|b| b.batch_size),
+            )
+            .field(
+                "recycled",
+                &self.recycle_pool.as_ref().map(|p| p.elements.len()),
+            )
+            .finish()
+    }
 }
 
 impl<C> Drop for FactoryVecDeque<C>
@@ -573,6 +1375,25 @@ where
     fn render_changes(&mut self) {
         let mut first_position_change_idx = None;
 
+        // Forget removed elements, so the remaining widgets don't need to be moved.
+        let mut first_removed_idx = None;
+        let model_uids: HashSet<usize> = self.model_state.iter().map(|s| s.uid).collect();
+        let mut rendered_index = 0;
+        self.rendered_state.retain(|r| {
+            let retain = model_uids.contains(&r.uid);
+            if !retain && first_removed_idx.is_none() {
+                first_removed_idx = Some(rendered_index);
+            }
+            rendered_index += 1;
+            retain
+        });
+
+        let mut launch_budget = self
+            .batch_rendering
+            .as_ref()
+            .map_or(usize::MAX, |b| b.batch_size);
+        let mut pending = false;
+
         let components = &mut self.components;
         let rendered_state = &mut self.rendered_state;
         for (index, state) in self.model_state.iter().enumerate() {
@@ -596,11 +1417,10 @@ where
 
                 // Detach and re-attach item
                 let widget = components[index].returned_widget().unwrap();
-                if index == 0 {
-                    self.widget.factory_move_start(widget);
-                } else {
-                    let previous_widget = components[index - 1].returned_widget().unwrap();
+                if let Some(previous_widget) = previous_returned_widget(components, index) {
                     self.widget.factory_move_after(widget, previous_widget);
+                } else {
+                    self.widget.factory_move_start(widget);
                 }
 
                 if state.changed {
@@ -613,19 +1433,26 @@ where
                 }
 
                 // The element doesn't exist yet
+                if launch_budget == 0 {
+                    // Launch the element in one of the next renders.
+                    pending = true;
+                    continue;
+                }
+                launch_budget -= 1;
+
                 let comp = &components[index];
                 let insert_widget = comp.widget();
                 let position = C::position(comp.get(), &state.index);
-                let returned_widget = if index == 0 {
-                    self.widget.factory_prepend(insert_widget, &position)
-                } else {
-                    let previous_widget = components[index - 1].returned_widget().unwrap();
-                    self.widget
-                        .factory_insert_after(insert_widget, &position, previous_widget)
-                };
+                let returned_widget =
+                    if let Some(previous_widget) = previous_returned_widget(components, index) {
+                        self.widget
+                            .factory_insert_after(insert_widget, &position, previous_widget)
+                    } else {
+                        self.widget.factory_prepend(insert_widget, &position)
+                    };
                 let component = components.remove(index).unwrap();
                 let dyn_index = &self.model_state[index].index;
-                let component = component.launch(dyn_index, returned_widget).unwrap();
+                let component = component.launch(dyn_index, returned_widget);
                 components.insert(index, component);
             }
         }
@@ -634,32 +1461,133 @@ where
         self.model_state.iter_mut().for_each(|s| s.changed = false);
 
         // Set rendered state to the state of the model
-        // because everything should be up-to-date now.
+        // because everything should be up-to-date now,
+        // except for elements that weren't launched yet.
         self.rendered_state = self
             .model_state
             .iter()
             .zip(components.iter())
-            .map(|(s, c)| {
+            .filter_map(|(s, c)| {
                 let mut hasher = DefaultHasher::default();
-                c.returned_widget().unwrap().hash(&mut hasher);
+                c.returned_widget()?.hash(&mut hasher);
 
-                RenderedState {
+                Some(RenderedState {
                     uid: s.uid,
                     #[cfg(feature = "libadwaita")]
                     widget_hash: hasher.finish(),
-                }
+                })
             })
             .collect();
 
+        // Removing elements changes the index of the following elements as well.
+        let first_position_change_idx = first_position_change_idx
+            .into_iter()
+            .chain(first_removed_idx)
+            .chain(std::mem::take(&mut self.reposition).then_some(0))
+            .min();
+
         if let Some(change_index) = first_position_change_idx {
             for (index, comp) in components.iter().enumerate().skip(change_index) {
-                let position = C::position(comp.get(), &self.model_state[index].index);
-                self.widget
-                    .factory_update_position(comp.returned_widget().unwrap(), &position);
+                if let Some(widget) = comp.returned_widget() {
+                    let position = C::position(comp.get(), &self.model_state[index].index);
+                    self.widget.factory_update_position(widget, &position);
+                }
+            }
+        }
+
+        let matches: Vec<bool> = components
+            .iter()
+            .map(|comp| {
+                self.filter
+                    .as_ref()
+                    .map_or(true, |filter| filter(comp.get()))
+            })
+            .collect();
+
+        // Pages only contain elements that match the filter.
+        let match_count = matches.iter().filter(|m| **m).count();
+        let page_count = self.pagination.page_count(match_count);
+        self.pagination.page = self.pagination.page.min(page_count - 1);
+        let page_range = self.pagination.page_size.map(|size| {
+            let start = self.pagination.page * size;
+            start..start + size
+        });
+
+        // Only update the visibility of elements whose filter or page changed.
+        let mut match_index = 0;
+        for ((state, comp), matches) in self
+            .model_state
+            .iter_mut()
+            .zip(components.iter())
+            .zip(matches)
+        {
+            let visible = matches
+                && page_range
+                    .as_ref()
+                    .map_or(true, |range| range.contains(&match_index));
+            if matches {
+                match_index += 1;
+            }
+            if let Some(widget) = comp.returned_widget() {
+                if state.visible != visible {
+                    state.visible = visible;
+                    self.widget.factory_set_visible(widget, visible);
+                }
+            }
+        }
+
+        let is_empty = self.components.is_empty();
+        if self.reported_empty != is_empty {
+            self.reported_empty = is_empty;
+            if let Some(empty_changed) = &self.empty_changed {
+                empty_changed(is_empty);
+            }
+        }
+
+        let page = (self.pagination.page, page_count);
+        if self.pagination.rendered.replace(page) != Some(page) {
+            if let Some(page_changed) = &self.pagination.page_changed {
+                page_changed(page.0, page.1);
+            }
+        }
+
+        if pending {
+            if let Some(batch_rendering) = &self.batch_rendering {
+                let render_next = batch_rendering.render_next.clone();
+                glib::idle_add_local_once(move || render_next());
             }
         }
     }
 
+    /// Removes the widget of a component that was removed from the model
+    /// and shuts it down or keeps it for recycling.
+    fn discard(&mut self, component: ComponentStorage<C>, state: ModelStateValue) {
+        if let Some(widget) = component.returned_widget() {
+            if !state.visible {
+                // Recycled widgets should be visible when they are added again.
+                self.widget.factory_set_visible(widget, true);
+            }
+            self.widget.factory_remove(widget);
+        }
+
+        let component = match &mut self.recycle_pool {
+            Some(pool) if pool.elements.len() < pool.capacity => {
+                // The component is shut down and only its widgets are kept.
+                match component.into_recycled(pool.rebind) {
+                    Ok(recycled) => {
+                        pool.elements.push(recycled);
+                        return;
+                    }
+                    Err(component) => component,
+                }
+            }
+            _ => component,
+        };
+
+        // Make sure the component is shutdown properly
+        component.extract();
+    }
+
     /// Returns the number of elements in the [`FactoryVecDeque`].
     pub fn len(&self) -> usize {
         self.components.len()
@@ -675,6 +1603,18 @@ where
         self.components[index].send(msg);
     }
 
+    /// Returns a [`FactoryElementSender`] for sending messages to one of the elements.
+    ///
+    /// Unlike the index, the sender stays tied to the element
+    /// when elements are moved, inserted or removed.
+    ///
+    /// Returns [`None`] if `index` is invalid.
+    pub fn element_sender(&self, index: usize) -> Option<FactoryElementSender<C>> {
+        self.components
+            .get(index)
+            .map(|c| FactoryElementSender::new(c.input_sender().clone()))
+    }
+
     /// Send clone of a message to all of the elements.
     pub fn broadcast(&self, msg: C::Input)
     where
@@ -705,11 +1645,189 @@ where
         self.get(0)
     }
 
+    /// Returns the index of the first element whose model matches the predicate.
+    ///
+    /// Returns [`None`] if no element matches.
+    pub fn position<F>(&self, mut predicate: F) -> Option<usize>
+    where
+        F: FnMut(&C) -> bool,
+    {
+        self.iter().position(|model| predicate(model))
+    }
+
+    /// Returns the [`DynamicIndex`] and an immutable reference to the model
+    /// of the first element whose model matches the predicate.
+    ///
+    /// Returns [`None`] if no element matches.
+    pub fn find<F>(&self, mut predicate: F) -> Option<(DynamicIndex, &C)>
+    where
+        F: FnMut(&C) -> bool,
+    {
+        self.components
+            .iter()
+            .zip(self.model_state.iter())
+            .map(|(component, state)| (state, component.get()))
+            .find(|(_, model)| predicate(model))
+            .map(|(state, model)| (state.index.clone(), model))
+    }
+
+    /// Returns true if the element at `index` is selected.
+    ///
+    /// Returns false if `index` is invalid.
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.model_state
+            .get(index)
+            .map_or(false, |state| state.selected)
+    }
+
+    /// Returns an iterator over the current indices of the selected elements.
+    pub fn selected_indices(&self) -> impl DoubleEndedIterator<Item = usize> + '_ {
+        self.model_state
+            .iter()
+            .enumerate()
+            .filter(|(_, state)| state.selected)
+            .map(|(index, _)| index)
+    }
+
+    /// Returns an iterator over the [`DynamicIndex`] and the model of the selected elements.
+    pub fn iter_selected(&self) -> impl DoubleEndedIterator<Item = (DynamicIndex, &C)> + '_ {
+        self.model_state
+            .iter()
+            .zip(self.components.iter())
+            .filter(|(state, _)| state.selected)
+            .map(|(state, component)| (state.index.clone(), component.get()))
+    }
+
     /// Returns the widget all components are attached to.
     pub const fn widget(&self) -> &C::ParentWidget {
         &self.widget
     }
 
+    /// Returns the number of elements per page or [`None`]
+    /// if all elements are shown.
+    pub const fn page_size(&self) -> Option<usize> {
+        self.pagination.page_size
+    }
+
+    /// Returns the current page.
+    pub fn page(&self) -> usize {
+        self.pagination.page.min(self.page_count() - 1)
+    }
+
+    /// Returns the number of pages.
+    ///
+    /// There's always at least one page, even if the [`FactoryVecDeque`] is empty.
+    pub fn page_count(&self) -> usize {
+        let len = if let Some(filter) = &self.filter {
+            self.iter().filter(|model| filter(model)).count()
+        } else {
+            self.len()
+        };
+        self.pagination.page_count(len)
+    }
+
+    /// Sets a callback that is called when the current page
+    /// or the number of pages changes.
+    ///
+    /// The callback receives the current page and the number of pages.
+    /// It is called once the changes of a guard have been rendered.
+    pub fn connect_page_changed<F>(&mut self, f: F)
+    where
+        F: Fn(usize, usize) + 'static,
+    {
+        self.pagination.page_changed = Some(Box::new(f));
+    }
+
+    /// Returns true if [`FactoryVecDequeGuard::undo`] can revert changes.
+    pub fn can_undo(&self) -> bool {
+        self.journal
+            .as_ref()
+            .map_or(false, |journal| !journal.undo.is_empty())
+    }
+
+    /// Returns true if [`FactoryVecDequeGuard::redo`] can apply reverted changes.
+    pub fn can_redo(&self) -> bool {
+        self.journal
+            .as_ref()
+            .map_or(false, |journal| !journal.redo.is_empty())
+    }
+
+    /// Stops recording changes and forgets all recorded changes.
+    pub fn disable_journal(&mut self) {
+        self.journal = None;
+    }
+
+    /// Sets a callback that is called when the [`FactoryVecDeque`]
+    /// becomes empty or stops being empty.
+    ///
+    /// The callback receives `true` if the factory is empty now.
+    /// It is called once the changes of a guard have been rendered,
+    /// which makes it suitable for showing a placeholder
+    /// such as an `adw::StatusPage`.
+    pub fn connect_empty_changed<F>(&mut self, f: F)
+    where
+        F: Fn(bool) + 'static,
+    {
+        self.empty_changed = Some(Box::new(f));
+    }
+
+    /// Stops recycling elements and drops all widgets that wait to be reused.
+    pub fn disable_recycling(&mut self) {
+        self.recycle_pool = None;
+    }
+
+    /// Launches at most `batch_size` new elements each time the changes are rendered.
+    ///
+    /// Creating widgets for thousands of elements at once can block the main loop
+    /// for a noticeable amount of time.
+    /// With batch rendering, the remaining elements are launched in later renders,
+    /// so the main loop can handle other events in between.
+    /// Elements that haven't been launched yet have no widgets, but their models can
+    /// be accessed and edited as usual.
+    ///
+    /// If elements are still waiting to be launched after a render,
+    /// `render_next` is called once the main loop is idle.
+    /// It should trigger the next render, usually by sending a message
+    /// to the component that owns the factory, which then drops a new guard:
+    ///
+    /// ```ignore
+    /// factory.enable_batch_rendering(100, move || sender.input(AppMsg::RenderFactory));
+    ///
+    /// // In the update function
+    /// AppMsg::RenderFactory => drop(self.factory.guard()),
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is zero.
+    pub fn enable_batch_rendering<F>(&mut self, batch_size: usize, render_next: F)
+    where
+        F: Fn() + 'static,
+    {
+        assert!(batch_size > 0, "The batch size must not be zero");
+        self.batch_rendering = Some(BatchRendering {
+            batch_size,
+            render_next: Rc::new(render_next),
+        });
+    }
+
+    /// Launches all new elements at once again.
+    ///
+    /// Elements that are still waiting to be launched are launched
+    /// once the changes are rendered the next time.
+    pub fn disable_batch_rendering(&mut self) {
+        self.batch_rendering = None;
+    }
+
+    /// Returns `true` if some elements haven't been launched
+    /// yet because of [`enable_batch_rendering`](Self::enable_batch_rendering).
+    #[must_use]
+    pub fn has_pending_elements(&self) -> bool {
+        self.components
+            .iter()
+            .any(|component| component.returned_widget().is_none())
+    }
+
     /// Returns an iterator over the components.
     pub fn iter(&self) -> impl DoubleEndedIterator<Item = &C> + ExactSizeIterator + FusedIterator {
         self.components.iter().map(ComponentStorage::get)
@@ -723,15 +1841,211 @@ where
         let mut output = Self::builder().launch(widget).detach();
         {
             let mut edit = output.guard();
-            for component in component_iter {
-                edit.push_back(component);
-            }
+            edit.extend(component_iter);
             edit.drop();
         }
         output
     }
 }
 
+impl<C> FactoryVecDeque<C>
+where
+    C: FactoryComponent<Index = DynamicIndex>,
+    C::Root: AsRef<gtk::Widget>,
+{
+    /// Allows users to reorder the elements with drag and drop.
+    ///
+    /// Every element gets a drag source and a drop target.
+    /// When an element is dropped onto another one, `f` is called
+    /// with the index of the dragged element and the index of the element
+    /// it was dropped on. The resulting message is sent to `sender`.
+    ///
+    /// The factory isn't changed automatically, so you usually want to
+    /// call [`FactoryVecDequeGuard::move_to`] when handling the message:
+    ///
+    /// ```ignore
+    /// Msg::Move(from, to) => {
+    ///     self.counters
+    ///         .guard()
+    ///         .move_to(from.current_index(), to.current_index());
+    /// }
+    /// ```
+    ///
+    /// Calling this method again replaces the drag sources and
+    /// drop targets of the previous call.
+    pub fn enable_drag_reorder<F, Msg>(&mut self, sender: &Sender<Msg>, f: F)
+    where
+        F: Fn(DynamicIndex, DynamicIndex) -> Msg + 'static,
+        Msg: 'static,
+    {
+        // Only accept drops of elements that were dragged from this factory.
+        let dragged: Rc<RefCell<Option<DynamicIndex>>> = Rc::default();
+        let sender = sender.clone();
+        let f = Rc::new(f);
+
+        let drag_setup = move |root: &C::Root, index: Option<&DynamicIndex>| {
+            let widget: &gtk::Widget = root.as_ref();
+
+            // Replace the controllers of a previous call.
+            if let Some((drag_source, drop_target)) = unsafe {
+                widget.steal_data::<(gtk::DragSource, gtk::DropTarget)>(DRAG_CONTROLLERS_KEY)
+            } {
+                widget.remove_controller(&drag_source);
+                widget.remove_controller(&drop_target);
+            }
+
+            let Some(index) = index else {
+                return;
+            };
+
+            let drag_source = gtk::DragSource::builder()
+                .actions(gdk::DragAction::MOVE)
+                .build();
+            {
+                let dragged = dragged.clone();
+                let index = index.clone();
+                drag_source.connect_prepare(move |_, _, _| {
+                    let value = (index.current_index() as u64).to_value();
+                    *dragged.borrow_mut() = Some(index.clone());
+                    Some(gdk::ContentProvider::for_value(&value))
+                });
+            }
+            {
+                let dragged = dragged.clone();
+                drag_source.connect_drag_end(move |_, _, _| {
+                    dragged.borrow_mut().take();
+                });
+            }
+            widget.add_controller(drag_source.clone());
+
+            let drop_target = gtk::DropTarget::new(u64::static_type(), gdk::DragAction::MOVE);
+            {
+                let dragged = dragged.clone();
+                let index = index.clone();
+                let sender = sender.clone();
+                let f = f.clone();
+                drop_target.connect_drop(move |_, _: &glib::Value, _, _| {
+                    if let Some(from) = dragged.borrow_mut().take() {
+                        if from != index {
+                            sender.emit(f(from, index.clone()));
+                        }
+                        true
+                    } else {
+                        false
+                    }
+                });
+            }
+            widget.add_controller(drop_target.clone());
+
+            unsafe { widget.set_data(DRAG_CONTROLLERS_KEY, (drag_source, drop_target)) };
+        };
+
+        for (component, state) in self.components.iter().zip(self.model_state.iter()) {
+            drag_setup(component.widget(), Some(&state.index));
+        }
+
+        self.drag_setup = Some(Box::new(drag_setup));
+    }
+}
+
+/// Creates a [`FactoryVecDeque`] with a default parent widget.
+///
+/// Use [`FactoryVecDeque::from_iter`] to use a custom parent widget instead.
+impl<C> FromIterator<C::Init> for FactoryVecDeque<C>
+where
+    C: FactoryComponent<Index = DynamicIndex>,
+    C::ParentWidget: Default,
+{
+    fn from_iter<T: IntoIterator<Item = C::Init>>(iter: T) -> Self {
+        Self::from_iter(iter, C::ParentWidget::default())
+    }
+}
+
+impl<C> FactoryVecDeque<C>
+where
+    C: RecyclableFactoryComponent + FactoryComponent<Index = DynamicIndex>,
+{
+    /// Reuses the widgets of discarded elements for new elements.
+    ///
+    /// The widgets of up to `capacity` elements that are discarded by
+    /// [`FactoryVecDequeGuard::clear`], [`FactoryVecDequeGuard::retain`] or
+    /// [`FactoryVecDequeGuard::retain_mut`] are kept when the elements are shut down.
+    /// New elements then reuse the root widget and widgets instead of building new ones,
+    /// which is much faster for complex widget trees, for example when paging through a list.
+    /// The model of a recycled element is created with
+    /// [`init_model`](FactoryComponent::init_model) as usual, then
+    /// [`rebind`](RecyclableFactoryComponent::rebind) is called to update the widgets.
+    ///
+    /// Only the widgets are reused. Discarded elements are shut down as usual,
+    /// which cancels their commands, and the new element gets its own
+    /// [`DynamicIndex`] and senders. Senders and indices of the discarded element
+    /// never reach the new element, so only components whose signal handlers
+    /// didn't capture them implement [`RecyclableFactoryComponent`].
+    /// Elements that are removed and returned, for example by
+    /// [`FactoryVecDequeGuard::remove`], are never recycled.
+    pub fn enable_recycling(&mut self, capacity: usize) {
+        if let Some(pool) = &mut self.recycle_pool {
+            pool.capacity = capacity;
+            pool.elements.truncate(capacity);
+        } else {
+            self.recycle_pool = Some(RecyclePool {
+                capacity,
+                elements: Vec::new(),
+                rebind: C::rebind,
+            });
+        }
+    }
+}
+
+impl<C> FactoryVecDeque<C>
+where
+    C: CloneableFactoryComponent + FactoryComponent<Index = DynamicIndex>,
+{
+    /// Starts recording insertions, removals, moves and swaps,
+    /// so they can be reverted with [`FactoryVecDequeGuard::undo`].
+    ///
+    /// All changes made through one guard are undone together.
+    /// Removed elements are recreated from the initialization data
+    /// returned by [`CloneableFactoryComponent::get_init`].
+    pub fn enable_journal(&mut self) {
+        if self.journal.is_none() {
+            self.journal = Some(Journal::new(C::get_init));
+        }
+    }
+}
+
+impl<C> FactoryVecDeque<C>
+where
+    C: PersistentComponent + FactoryComponent<Index = DynamicIndex>,
+{
+    /// Captures the states of all elements, so they can be
+    /// restored with [`FactoryVecDequeGuard::restore_states`].
+    #[must_use]
+    pub fn save_states(&self) -> Vec<C::State> {
+        self.iter().map(C::save_state).collect()
+    }
+}
+
+impl<'a, C> FactoryVecDequeGuard<'a, C>
+where
+    C: PersistentComponent + FactoryComponent<Index = DynamicIndex>,
+{
+    /// Restores the states saved by [`FactoryVecDeque::save_states`].
+    ///
+    /// The first state is applied to the first element, the second state to
+    /// the second element and so on.
+    /// Elements or states without a counterpart are left untouched, so usually,
+    /// you want to insert the elements from their initialization data first.
+    pub fn restore_states<I>(&mut self, states: I)
+    where
+        I: IntoIterator<Item = C::State>,
+    {
+        for (element, state) in self.iter_mut().zip(states) {
+            element.restore_state(state);
+        }
+    }
+}
+
 ///Implements the Clone Trait for `FactoryVecDeque<C>` where C is Cloneable
 impl<C> Clone for FactoryVecDeque<C>
 where
@@ -752,3 +2066,686 @@ where
         clone
     }
 }
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<C> FactoryVecDeque<C>
+where
+    C: FactoryComponent<Index = DynamicIndex>,
+{
+    /// Serializes the models of all elements as a sequence.
+    pub fn serialize_models<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: serde::Serialize,
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+
+    /// Serializes the initialization data of all elements as a sequence.
+    ///
+    /// Use [`Self::deserialize_inits`] to rebuild the factory from the serialized data.
+    pub fn serialize_inits<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: CloneableFactoryComponent,
+        C::Init: serde::Serialize,
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.iter().map(C::get_init))
+    }
+
+    /// Creates a [`FactoryVecDeque`] from a serialized sequence of initialization data.
+    ///
+    /// If the models of the elements and [`FactoryComponent::Init`] share the same
+    /// representation, the output of [`Self::serialize_models`] can be used as well.
+    pub fn deserialize_inits<'de, D>(
+        deserializer: D,
+        widget: C::ParentWidget,
+    ) -> Result<Self, D::Error>
+    where
+        C::Init: serde::Deserialize<'de>,
+        D: serde::Deserializer<'de>,
+    {
+        let inits = <Vec<C::Init> as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(Self::from_iter(inits, widget))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    use gtk::glib::MainContext;
+    use gtk::prelude::{Cast, ListModelExt, WidgetExt};
+
+    use crate::factory::{
+        CloneableFactoryComponent, DynamicIndex, FactoryComponent, FactorySender, FactoryView,
+        RecyclableFactoryComponent,
+    };
+    use crate::RelmIterChildrenExt;
+
+    use super::FactoryVecDeque;
+
+    thread_local! {
+        /// Values of all elements that were shut down.
+        static SHUT_DOWN: RefCell<Vec<u8>> = RefCell::default();
+    }
+
+    #[derive(Debug)]
+    struct Element {
+        value: u8,
+        index: DynamicIndex,
+        received: Vec<u8>,
+        view_updates: Cell<usize>,
+    }
+
+    impl FactoryComponent for Element {
+        type ParentWidget = gtk::Box;
+        type CommandOutput = ();
+        type Input = u8;
+        type Output = u8;
+        type Init = u8;
+        type Root = gtk::Label;
+        type Widgets = gtk::Label;
+        type Index = DynamicIndex;
+
+        fn init_model(value: Self::Init, index: &DynamicIndex, _: FactorySender<Self>) -> Self {
+            Self {
+                value,
+                index: index.clone(),
+                received: Vec::new(),
+                view_updates: Cell::new(0),
+            }
+        }
+
+        fn init_root(&self) -> Self::Root {
+            gtk::Label::default()
+        }
+
+        fn init_widgets(
+            &mut self,
+            _: &DynamicIndex,
+            root: Self::Root,
+            _: &<Self::ParentWidget as FactoryView>::ReturnedWidget,
+            _: FactorySender<Self>,
+        ) -> Self::Widgets {
+            root.set_label(&self.value.to_string());
+            root
+        }
+
+        fn update(&mut self, message: Self::Input, sender: FactorySender<Self>) {
+            self.received.push(message);
+            sender.output(message).ok();
+        }
+
+        fn update_view(&self, label: &mut Self::Widgets, _: FactorySender<Self>) {
+            self.view_updates.set(self.view_updates.get() + 1);
+            label.set_label(&self.value.to_string());
+        }
+
+        fn shutdown(&mut self, _: &mut Self::Widgets, _: crate::Sender<Self::Output>) {
+            SHUT_DOWN.with(|values| values.borrow_mut().push(self.value));
+        }
+    }
+
+    impl CloneableFactoryComponent for Element {
+        fn get_init(&self) -> Self::Init {
+            self.value
+        }
+    }
+
+    impl RecyclableFactoryComponent for Element {
+        fn rebind(
+            &mut self,
+            _: &DynamicIndex,
+            label: &mut Self::Widgets,
+            sender: FactorySender<Self>,
+        ) {
+            self.update_view(label, sender);
+        }
+    }
+
+    fn factory(values: &[u8]) -> FactoryVecDeque<Element> {
+        let mut factory = FactoryVecDeque::builder().launch_default().detach();
+        factory.guard().extend(values.iter().copied());
+        factory
+    }
+
+    /// Creates a factory and a receiver of the outputs of its elements.
+    fn factory_with_outputs() -> (FactoryVecDeque<Element>, flume::Receiver<u8>) {
+        let connector = FactoryVecDeque::builder().launch_default();
+        let outputs = connector.output_receiver.0.clone();
+        (connector.detach(), outputs)
+    }
+
+    fn process_messages() {
+        while MainContext::default().iteration(false) {}
+    }
+
+    fn values(factory: &FactoryVecDeque<Element>) -> Vec<u8> {
+        factory.iter().map(|element| element.value).collect()
+    }
+
+    fn view_updates(factory: &FactoryVecDeque<Element>) -> Vec<usize> {
+        factory
+            .iter()
+            .map(|element| element.view_updates.get())
+            .collect()
+    }
+
+    /// Values shown by the visible widgets, in the order of the widgets.
+    fn rendered(factory: &FactoryVecDeque<Element>) -> Vec<u8> {
+        factory
+            .widget()
+            .iter_children()
+            .filter(WidgetExt::is_visible)
+            .map(|child| {
+                let label = child.downcast::<gtk::Label>().unwrap();
+                label.label().parse().unwrap()
+            })
+            .collect()
+    }
+
+    fn was_shut_down(value: u8) -> bool {
+        SHUT_DOWN.with(|values| values.borrow().contains(&value))
+    }
+
+    fn record_empty_changes(factory: &mut FactoryVecDeque<Element>) -> Rc<RefCell<Vec<bool>>> {
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let recorder = changes.clone();
+        factory.connect_empty_changed(move |empty| recorder.borrow_mut().push(empty));
+        changes
+    }
+
+    #[gtk::test]
+    fn empty_changed_on_clear() {
+        let mut factory = factory(&[1, 2]);
+        let changes = record_empty_changes(&mut factory);
+
+        factory.guard().clear();
+        assert_eq!(*changes.borrow(), [true]);
+
+        factory.guard().clear();
+        assert_eq!(*changes.borrow(), [true]);
+    }
+
+    #[gtk::test]
+    fn empty_changed_on_pop_to_empty() {
+        let mut factory = factory(&[1, 2]);
+        let changes = record_empty_changes(&mut factory);
+
+        factory.guard().pop_back();
+        assert!(changes.borrow().is_empty());
+
+        factory.guard().pop_front();
+        assert_eq!(*changes.borrow(), [true]);
+    }
+
+    #[gtk::test]
+    fn empty_changed_on_push_from_empty() {
+        let mut factory = factory(&[]);
+        let changes = record_empty_changes(&mut factory);
+
+        factory.guard().push_back(1);
+        assert_eq!(*changes.borrow(), [false]);
+
+        factory.guard().push_back(2);
+        assert_eq!(*changes.borrow(), [false]);
+
+        // Only the rendered state is reported.
+        let mut guard = factory.guard();
+        guard.clear();
+        guard.push_back(3);
+        guard.drop();
+        assert_eq!(*changes.borrow(), [false]);
+    }
+
+    #[gtk::test]
+    #[should_panic(expected = "attempted to index slice up to maximum usize")]
+    fn drain_inclusive_max() {
+        let mut factory = factory(&[1, 2]);
+        factory.guard().drain(..=usize::MAX);
+    }
+
+    #[gtk::test]
+    fn recycled_element_gets_new_channels() {
+        let mut factory = factory(&[100]);
+        factory.enable_recycling(1);
+
+        let old_root = factory.widget().first_child().unwrap();
+        let old_sender = factory.element_sender(0).unwrap();
+        let old_index = factory[0].index.clone();
+
+        factory.guard().clear();
+        assert!(was_shut_down(100));
+
+        factory.guard().push_back(101);
+        assert_eq!(factory.widget().first_child().unwrap(), old_root);
+
+        // Messages of the discarded element don't reach the new element.
+        assert!(old_sender.is_removed());
+        assert!(old_sender.send(1).is_err());
+        factory.send(0, 2);
+        process_messages();
+        assert_eq!(factory[0].received, [2]);
+
+        // The new element doesn't share the index of the discarded element.
+        factory.guard().push_front(102);
+        assert_eq!(values(&factory), [102, 101]);
+        assert_eq!(factory[1].index.current_index(), 1);
+        assert_eq!(old_index.current_index(), 0);
+    }
+
+    #[gtk::test]
+    fn sort_by_key_reorders_widgets() {
+        let mut factory = factory(&[3, 1, 2]);
+        let index = factory[1].index.clone();
+
+        factory.guard().sort_by_key(|element| element.value);
+        assert_eq!(values(&factory), [1, 2, 3]);
+        assert_eq!(rendered(&factory), [1, 2, 3]);
+
+        // The component was moved, not recreated.
+        assert_eq!(index.current_index(), 0);
+        assert_eq!(factory[0].index, index);
+
+        factory
+            .guard()
+            .sort_by(|first, second| second.value.cmp(&first.value));
+        assert_eq!(values(&factory), [3, 2, 1]);
+        assert_eq!(rendered(&factory), [3, 2, 1]);
+        assert_eq!(index.current_index(), 2);
+    }
+
+    #[gtk::test]
+    fn sort_is_stable() {
+        let mut factory = factory(&[21, 10, 20, 11]);
+
+        factory.guard().sort_by_key(|element| element.value / 10);
+        assert_eq!(values(&factory), [10, 11, 21, 20]);
+        assert_eq!(rendered(&factory), [10, 11, 21, 20]);
+    }
+
+    #[gtk::test]
+    fn filter_hides_elements() {
+        let mut factory = factory(&[1, 2, 3, 4]);
+
+        factory.guard().set_filter(|element| element.value % 2 == 0);
+        assert_eq!(values(&factory), [1, 2, 3, 4]);
+        assert_eq!(rendered(&factory), [2, 4]);
+
+        // The filter is evaluated again on every render.
+        factory.guard().get_mut(0).unwrap().value = 6;
+        process_messages();
+        assert_eq!(rendered(&factory), [6, 2, 4]);
+
+        factory.guard().clear_filter();
+        assert_eq!(rendered(&factory), [6, 2, 3, 4]);
+    }
+
+    #[gtk::test]
+    fn retain_removes_elements() {
+        let mut factory = factory(&[81, 82, 83, 84]);
+
+        factory.guard().retain(|element| element.value % 2 == 0);
+        assert_eq!(values(&factory), [82, 84]);
+        assert_eq!(rendered(&factory), [82, 84]);
+        assert!(was_shut_down(81) && was_shut_down(83));
+        assert!(!was_shut_down(82) && !was_shut_down(84));
+
+        assert_eq!(factory[0].index.current_index(), 0);
+        assert_eq!(factory[1].index.current_index(), 1);
+    }
+
+    #[gtk::test]
+    fn retain_mut_updates_elements() {
+        let mut factory = factory(&[1, 2, 3]);
+
+        factory.guard().retain_mut(|element| {
+            element.value *= 10;
+            element.value != 20
+        });
+        process_messages();
+        assert_eq!(values(&factory), [10, 30]);
+        assert_eq!(rendered(&factory), [10, 30]);
+    }
+
+    #[gtk::test]
+    fn collect_into_factory() {
+        let factory: FactoryVecDeque<Element> = [1, 2, 3].into_iter().collect();
+        assert_eq!(values(&factory), [1, 2, 3]);
+        assert_eq!(rendered(&factory), [1, 2, 3]);
+
+        let widget = gtk::Box::default();
+        let factory = FactoryVecDeque::<Element>::from_iter([4, 5], widget.clone());
+        assert_eq!(factory.widget(), &widget);
+        assert_eq!(rendered(&factory), [4, 5]);
+    }
+
+    #[gtk::test]
+    fn extend_appends_elements() {
+        let mut factory = factory(&[1]);
+
+        let mut guard = factory.guard();
+        guard.push_front(0);
+        guard.extend([2, 3]);
+        guard.drop();
+
+        assert_eq!(values(&factory), [0, 1, 2, 3]);
+        assert_eq!(rendered(&factory), [0, 1, 2, 3]);
+        for (index, element) in factory.iter().enumerate() {
+            assert_eq!(element.index.current_index(), index);
+        }
+    }
+
+    #[gtk::test]
+    fn drain_removes_range() {
+        let mut factory = factory(&[1, 2, 3, 4, 5]);
+
+        let drained = factory.guard().drain(1..3);
+        let drained: Vec<u8> = drained.iter().map(|element| element.value).collect();
+        assert_eq!(drained, [2, 3]);
+        assert_eq!(values(&factory), [1, 4, 5]);
+        assert_eq!(rendered(&factory), [1, 4, 5]);
+        assert_eq!(factory[1].index.current_index(), 1);
+        assert_eq!(factory[2].index.current_index(), 2);
+
+        let drained = factory.guard().drain(..);
+        assert_eq!(drained.len(), 3);
+        assert!(factory.is_empty());
+        assert!(rendered(&factory).is_empty());
+    }
+
+    #[gtk::test]
+    fn swap_remove_moves_last_element() {
+        let mut factory = factory(&[1, 2, 3]);
+
+        let removed = factory.guard().swap_remove(0).unwrap();
+        assert_eq!(removed.value, 1);
+        assert_eq!(values(&factory), [3, 2]);
+        assert_eq!(rendered(&factory), [3, 2]);
+        assert_eq!(factory[0].index.current_index(), 0);
+
+        assert!(factory.guard().swap_remove(2).is_none());
+        assert_eq!(values(&factory), [3, 2]);
+    }
+
+    #[gtk::test]
+    fn sync_from_iter_reuses_elements() {
+        let mut factory = factory(&[111, 112, 113]);
+        let index = factory[1].index.clone();
+        factory.send(1, 7);
+        process_messages();
+
+        factory
+            .guard()
+            .sync_from_iter([(113, 113), (114, 114), (112, 112)], |element| {
+                element.value
+            });
+        assert_eq!(values(&factory), [113, 114, 112]);
+        assert_eq!(rendered(&factory), [113, 114, 112]);
+        assert!(was_shut_down(111));
+
+        // The existing element was moved, not recreated.
+        assert_eq!(index.current_index(), 2);
+        assert_eq!(factory[2].received, [7]);
+    }
+
+    #[gtk::test]
+    fn find_by_predicate() {
+        let mut factory = factory(&[5, 6, 7]);
+
+        assert_eq!(factory.position(|element| element.value == 6), Some(1));
+        assert_eq!(factory.position(|element| element.value == 8), None);
+
+        let (index, element) = factory.find(|element| element.value > 5).unwrap();
+        assert_eq!(index.current_index(), 1);
+        assert_eq!(element.value, 6);
+        assert!(factory.find(|element| element.value > 7).is_none());
+
+        let mut guard = factory.guard();
+        let (index, element) = guard.find_mut(|element| element.value == 7).unwrap();
+        assert_eq!(index.current_index(), 2);
+        element.value = 8;
+        assert!(guard.find_mut(|element| element.value == 7).is_none());
+        guard.drop();
+
+        process_messages();
+        assert_eq!(rendered(&factory), [5, 6, 8]);
+    }
+
+    #[gtk::test]
+    fn selection_follows_elements() {
+        let mut factory = factory(&[1, 2, 3, 4]);
+
+        let mut guard = factory.guard();
+        guard.set_selected(1, true);
+        guard.select_range(2..);
+        assert!(!guard.toggle_selected(1));
+        guard.drop();
+
+        assert!(!factory.is_selected(1));
+        assert!(!factory.is_selected(10));
+        assert_eq!(factory.selected_indices().collect::<Vec<_>>(), [2, 3]);
+
+        // The selection is moved with the element and forgotten once it's removed.
+        factory.guard().move_to(3, 0);
+        assert_eq!(factory.selected_indices().collect::<Vec<_>>(), [0, 3]);
+        let selected: Vec<u8> = factory
+            .iter_selected()
+            .map(|(index, element)| {
+                assert_eq!(factory[index.current_index()].value, element.value);
+                element.value
+            })
+            .collect();
+        assert_eq!(selected, [4, 3]);
+
+        factory.guard().remove(0);
+        assert_eq!(factory.selected_indices().collect::<Vec<_>>(), [2]);
+
+        factory.guard().clear_selection();
+        assert_eq!(factory.selected_indices().count(), 0);
+
+        factory.guard().select_all();
+        assert_eq!(factory.selected_indices().collect::<Vec<_>>(), [0, 1, 2]);
+    }
+
+    #[gtk::test]
+    fn pagination_shows_one_page() {
+        let mut factory = factory(&[1, 2, 3, 4, 5]);
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let recorder = changes.clone();
+        factory.connect_page_changed(move |page, count| recorder.borrow_mut().push((page, count)));
+
+        factory.guard().set_page_size(Some(2));
+        assert_eq!((factory.page(), factory.page_count()), (0, 3));
+        assert_eq!(rendered(&factory), [1, 2]);
+
+        assert!(factory.guard().next_page());
+        assert_eq!(rendered(&factory), [3, 4]);
+
+        // Pages beyond the last page show the last page.
+        factory.guard().set_page(10);
+        assert_eq!(factory.page(), 2);
+        assert_eq!(rendered(&factory), [5]);
+        assert!(!factory.guard().next_page());
+
+        assert!(factory.guard().prev_page());
+        assert_eq!(rendered(&factory), [3, 4]);
+
+        // Pages only contain elements that match the filter.
+        factory.guard().set_filter(|element| element.value % 2 == 1);
+        assert_eq!((factory.page(), factory.page_count()), (1, 2));
+        assert_eq!(rendered(&factory), [5]);
+
+        factory.guard().set_page_size(None);
+        assert_eq!((factory.page(), factory.page_count()), (0, 1));
+        assert_eq!(rendered(&factory), [1, 3, 5]);
+
+        assert_eq!(
+            *changes.borrow(),
+            [(0, 3), (1, 3), (2, 3), (1, 3), (1, 2), (0, 1)]
+        );
+    }
+
+    #[gtk::test]
+    fn journal_undo_redo() {
+        let mut factory = factory(&[1, 2, 3]);
+        factory.enable_journal();
+        assert!(!factory.can_undo());
+
+        let mut guard = factory.guard();
+        guard.push_back(4);
+        guard.remove(0);
+        guard.drop();
+        assert_eq!(values(&factory), [2, 3, 4]);
+
+        // All changes of a guard are undone together.
+        assert!(factory.guard().undo());
+        assert_eq!(values(&factory), [1, 2, 3]);
+        assert_eq!(rendered(&factory), [1, 2, 3]);
+        assert!(factory.can_redo());
+
+        assert!(factory.guard().redo());
+        assert_eq!(values(&factory), [2, 3, 4]);
+        assert_eq!(rendered(&factory), [2, 3, 4]);
+
+        factory.guard().swap(0, 2);
+        assert_eq!(values(&factory), [4, 3, 2]);
+        assert!(factory.guard().undo());
+        assert!(factory.guard().undo());
+        assert_eq!(values(&factory), [1, 2, 3]);
+        assert_eq!(rendered(&factory), [1, 2, 3]);
+        assert!(!factory.guard().undo());
+
+        // New changes can't be redone.
+        factory.guard().push_back(5);
+        assert!(!factory.can_redo());
+        assert!(!factory.guard().redo());
+
+        // Sorting clears the journal.
+        factory.guard().sort_by_key(|element| element.value);
+        assert!(!factory.can_undo());
+    }
+
+    #[gtk::test]
+    fn iter_mut_tracked_only_updates_touched_elements() {
+        let mut factory = factory(&[1, 2, 3]);
+        process_messages();
+        let before = view_updates(&factory);
+
+        for mut element in factory.guard().iter_mut_tracked() {
+            // Reading doesn't mark the element as changed.
+            if element.value == 2 {
+                element.value = 20;
+            }
+        }
+        process_messages();
+        assert_eq!(rendered(&factory), [1, 20, 3]);
+        assert_eq!(
+            view_updates(&factory),
+            [before[0], before[1] + 1, before[2]]
+        );
+
+        // Untracked mutable access updates all elements.
+        factory.guard().iter_mut().for_each(|_| ());
+        process_messages();
+        assert_eq!(
+            view_updates(&factory),
+            [before[0] + 1, before[1] + 2, before[2] + 1]
+        );
+    }
+
+    #[gtk::test]
+    fn batch_rendering_launches_elements_in_batches() {
+        let mut factory = factory(&[]);
+        let requests = Rc::new(Cell::new(0));
+        let counter = requests.clone();
+        factory.enable_batch_rendering(2, move || counter.set(counter.get() + 1));
+
+        factory.guard().extend([1, 2, 3, 4, 5]);
+        assert_eq!(values(&factory), [1, 2, 3, 4, 5]);
+        assert_eq!(rendered(&factory), [1, 2]);
+        assert!(factory.has_pending_elements());
+
+        // The next render is requested once the main loop is idle.
+        process_messages();
+        assert_eq!(requests.get(), 1);
+
+        factory.guard().drop();
+        assert_eq!(rendered(&factory), [1, 2, 3, 4]);
+        factory.guard().drop();
+        assert_eq!(rendered(&factory), [1, 2, 3, 4, 5]);
+        assert!(!factory.has_pending_elements());
+
+        process_messages();
+        assert_eq!(requests.get(), 2);
+    }
+
+    #[gtk::test]
+    fn append_and_split_off_move_elements() {
+        let mut first = factory(&[71, 72]);
+        let mut second = factory(&[73, 74, 75]);
+
+        first.guard().append(&mut second.guard());
+        assert_eq!(values(&first), [71, 72, 73, 74, 75]);
+        assert_eq!(rendered(&first), [71, 72, 73, 74, 75]);
+        assert!(second.is_empty());
+        assert!(rendered(&second).is_empty());
+
+        first.guard().split_off(3, &mut second.guard());
+        assert_eq!(values(&first), [71, 72, 73]);
+        assert_eq!(rendered(&first), [71, 72, 73]);
+        assert_eq!(values(&second), [74, 75]);
+        assert_eq!(rendered(&second), [74, 75]);
+
+        // The elements were moved, not recreated.
+        assert!((71..=75).all(|value| !was_shut_down(value)));
+        for (index, element) in second.iter().enumerate() {
+            assert_eq!(element.index.current_index(), index);
+        }
+        second.send(1, 9);
+        process_messages();
+        assert_eq!(second[1].received, [9]);
+    }
+
+    #[gtk::test]
+    fn outputs_follow_transferred_elements() {
+        let (mut first, first_outputs) = factory_with_outputs();
+        let (mut second, second_outputs) = factory_with_outputs();
+
+        first.guard().push_back(1);
+        first.send(0, 5);
+        process_messages();
+
+        first.guard().transfer(0, &mut second.guard(), 0);
+        second.send(0, 6);
+        process_messages();
+
+        assert_eq!(first_outputs.try_iter().collect::<Vec<_>>(), [5]);
+        assert_eq!(second_outputs.try_iter().collect::<Vec<_>>(), [6]);
+    }
+
+    #[gtk::test]
+    fn transfer_replaces_drag_controllers() {
+        let (sender, _receiver) = crate::channel::<()>();
+        let mut first = factory(&[1]);
+        let mut second = factory(&[]);
+
+        let root = first.widget().first_child().unwrap();
+        let controllers = || root.observe_controllers().n_items();
+        let initial = controllers();
+
+        first.enable_drag_reorder(&sender, |_, _| ());
+        assert_eq!(controllers(), initial + 2);
+
+        // Elements don't keep the drag and drop of their previous factory.
+        first.guard().transfer(0, &mut second.guard(), 0);
+        assert_eq!(controllers(), initial);
+
+        second.enable_drag_reorder(&sender, |_, _| ());
+        second.guard().transfer(0, &mut first.guard(), 0);
+        assert_eq!(controllers(), initial + 2);
+    }
+}
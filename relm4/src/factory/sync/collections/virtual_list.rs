@@ -0,0 +1,724 @@
+use crate::factory::{
+    DynamicIndex, FactoryComponent, FactoryElementSender, FactorySender, FactoryView,
+    RecyclableFactoryComponent,
+};
+use crate::shutdown::{self, ShutdownSender};
+use crate::{GuardedReceiver, Receiver, Sender};
+
+use gtk::prelude::{Cast, CastNone, ListItemExt};
+use gtk::{gio, glib};
+
+use std::cell::{Ref, RefCell};
+use std::collections::VecDeque;
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::{Rc, Weak};
+
+/// Root and widgets of elements that aren't shown anymore.
+type WidgetPool<C> = RefCell<
+    Vec<(
+        <C as FactoryComponent>::Root,
+        <C as FactoryComponent>::Widgets,
+    )>,
+>;
+
+#[derive(Debug)]
+/// A builder-pattern struct for building a [`FactoryVirtualList`].
+pub struct FactoryVirtualListBuilder<C: FactoryComponent> {
+    _component: PhantomData<C>,
+}
+
+impl<C> Default for FactoryVirtualListBuilder<C>
+where
+    C: RecyclableFactoryComponent<Index = DynamicIndex>,
+    C::ParentWidget: Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C> FactoryVirtualListBuilder<C>
+where
+    C: RecyclableFactoryComponent<Index = DynamicIndex>,
+    C::ParentWidget: Default,
+{
+    /// Creates a new [`FactoryVirtualListBuilder`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            _component: PhantomData,
+        }
+    }
+
+    #[must_use]
+    /// Launch the factory with a new [`gtk::ListView`].
+    pub fn launch_default(self) -> FactoryVirtualListConnector<C> {
+        self.launch(gtk::ListView::default())
+    }
+
+    /// Launch the factory.
+    /// This is similar to [`Connector::launch`](crate::component::ComponentBuilder::launch).
+    ///
+    /// The model and the item factory of `list_view` are replaced.
+    pub fn launch(self, list_view: gtk::ListView) -> FactoryVirtualListConnector<C> {
+        let (output_sender, output_receiver) = crate::channel();
+
+        let store = gio::ListStore::new::<glib::BoxedAnyObject>();
+        let pool: Rc<WidgetPool<C>> = Rc::default();
+
+        let factory = gtk::SignalListItemFactory::new();
+        factory.connect_setup(move |_, list_item| {
+            let list_item = list_item
+                .downcast_ref::<gtk::ListItem>()
+                .expect("Needs to be ListItem");
+
+            // Every row gets its own parent widget that holds the root of the element.
+            list_item.set_child(Some(&C::ParentWidget::default()));
+        });
+
+        let bind_pool = pool.clone();
+        factory.connect_bind(move |_, list_item| {
+            let list_item = list_item
+                .downcast_ref::<gtk::ListItem>()
+                .expect("Needs to be ListItem");
+
+            let parent = list_item.child().and_downcast::<C::ParentWidget>().unwrap();
+            if let Some(element) = list_element::<C>(list_item) {
+                element.attach(parent, &bind_pool);
+            }
+        });
+
+        let unbind_pool = pool.clone();
+        factory.connect_unbind(move |_, list_item| {
+            let list_item = list_item
+                .downcast_ref::<gtk::ListItem>()
+                .expect("Needs to be ListItem");
+
+            // Removed elements were already detached.
+            if let Some(element) = list_element::<C>(list_item) {
+                element.detach(&unbind_pool);
+            }
+        });
+
+        list_view.set_model(Some(&gtk::NoSelection::new(Some(store.clone()))));
+        list_view.set_factory(Some(&factory));
+
+        FactoryVirtualListConnector {
+            list_view,
+            store,
+            pool,
+            output_sender,
+            output_receiver,
+        }
+    }
+}
+
+/// Second stage of the builder-pattern for building a [`FactoryVirtualList`].
+pub struct FactoryVirtualListConnector<C: FactoryComponent> {
+    list_view: gtk::ListView,
+    store: gio::ListStore,
+    pool: Rc<WidgetPool<C>>,
+    output_sender: Sender<C::Output>,
+    output_receiver: Receiver<C::Output>,
+}
+
+impl<C: FactoryComponent> fmt::Debug for FactoryVirtualListConnector<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FactoryVirtualListConnector")
+            .field("list_view", &self.list_view)
+            .field("store", &self.store)
+            .field("output_sender", &self.output_sender)
+            .field("output_receiver", &self.output_receiver)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C> FactoryVirtualListConnector<C>
+where
+    C: FactoryComponent<Index = DynamicIndex>,
+{
+    /// Forwards output events to the designated sender.
+    pub fn forward<F, Msg>(self, sender_: &Sender<Msg>, f: F) -> FactoryVirtualList<C>
+    where
+        F: Fn(C::Output) -> Msg + Send + 'static,
+        C::Output: Send,
+        Msg: Send + 'static,
+    {
+        let Self {
+            list_view,
+            store,
+            pool,
+            output_sender,
+            output_receiver,
+        } = self;
+
+        let sender_clone = sender_.clone();
+
+        crate::spawn(async move {
+            while let Some(msg) = output_receiver.recv().await {
+                if sender_clone.send(f(msg)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        FactoryVirtualList {
+            list_view,
+            store,
+            pool,
+            output_sender,
+            elements: VecDeque::new(),
+        }
+    }
+
+    /// Ignore outputs from the component and finish the builder.
+    pub fn detach(self) -> FactoryVirtualList<C> {
+        let Self {
+            list_view,
+            store,
+            pool,
+            output_sender,
+            ..
+        } = self;
+
+        FactoryVirtualList {
+            list_view,
+            store,
+            pool,
+            output_sender,
+            elements: VecDeque::new(),
+        }
+    }
+}
+
+/// Widgets of an element that is currently shown by the list view.
+struct ElementView<C: FactoryComponent> {
+    parent: C::ParentWidget,
+    root: C::Root,
+    widgets: C::Widgets,
+    returned_widget: <C::ParentWidget as FactoryView>::ReturnedWidget,
+}
+
+/// Model and view of a single element, shared with the list view.
+struct VirtualElement<C: FactoryComponent> {
+    model: RefCell<C>,
+    index: DynamicIndex,
+    sender: FactorySender<C>,
+    view: RefCell<Option<ElementView<C>>>,
+}
+
+impl<C: FactoryComponent> VirtualElement<C> {
+    fn update(&self, message: C::Input) {
+        let mut model = self.model.borrow_mut();
+        if let Some(view) = &mut *self.view.borrow_mut() {
+            model.update_with_view(&mut view.widgets, message, self.sender.clone());
+        } else {
+            model.update(message, self.sender.clone());
+        }
+    }
+
+    fn update_cmd(&self, message: C::CommandOutput) {
+        let mut model = self.model.borrow_mut();
+        if let Some(view) = &mut *self.view.borrow_mut() {
+            model.update_cmd_with_view(&mut view.widgets, message, self.sender.clone());
+        } else {
+            model.update_cmd(message, self.sender.clone());
+        }
+    }
+
+    /// Removes the widgets from their row and returns them to the pool.
+    fn detach(&self, pool: &WidgetPool<C>) {
+        if let Some(mut view) = self.view.borrow_mut().take() {
+            view.parent.factory_remove(&view.returned_widget);
+            self.model
+                .borrow_mut()
+                .on_detach(&mut view.widgets, self.sender.clone());
+            pool.borrow_mut().push((view.root, view.widgets));
+        }
+    }
+}
+
+impl<C: RecyclableFactoryComponent> VirtualElement<C> {
+    /// Shows the element inside of the row `parent`, preferably with recycled widgets.
+    fn attach(&self, parent: C::ParentWidget, pool: &WidgetPool<C>) {
+        let mut view = self.view.borrow_mut();
+        if view.is_some() {
+            return;
+        }
+
+        let mut model = self.model.borrow_mut();
+        let position = C::position(&model, &self.index);
+
+        let recycled = pool.borrow_mut().pop();
+        let (root, mut widgets, returned_widget) = if let Some((root, mut widgets)) = recycled {
+            let returned_widget = parent.factory_append(&root, &position);
+            model.rebind(&self.index, &mut widgets, self.sender.clone());
+            (root, widgets, returned_widget)
+        } else {
+            let root = model.init_root();
+            let returned_widget = parent.factory_append(&root, &position);
+            let widgets = model.init_widgets(
+                &self.index,
+                root.clone(),
+                &returned_widget,
+                self.sender.clone(),
+            );
+            (root, widgets, returned_widget)
+        };
+
+        model.on_attach(&mut widgets, self.sender.clone());
+        *view = Some(ElementView {
+            parent,
+            root,
+            widgets,
+            returned_widget,
+        });
+    }
+}
+
+/// Returns the element shown by a row if it wasn't removed yet.
+fn list_element<C: FactoryComponent>(list_item: &gtk::ListItem) -> Option<Rc<VirtualElement<C>>> {
+    let item = list_item.item().and_downcast::<glib::BoxedAnyObject>()?;
+    let element = item.borrow::<Weak<VirtualElement<C>>>().clone();
+    element.upgrade()
+}
+
+/// An element of the collection together with its runtime.
+struct ElementHandle<C: FactoryComponent> {
+    element: Rc<VirtualElement<C>>,
+    runtime: glib::JoinHandle<()>,
+    shutdown_notifier: ShutdownSender,
+}
+
+/// A factory collection that only creates widgets for the elements
+/// near the visible area of a [`gtk::ListView`].
+///
+/// All models are kept in memory and keep receiving messages,
+/// but widgets are only created for the rows that the list view shows.
+/// Once a row scrolls out of view, its widgets are detached from the
+/// element and reused for the next element that scrolls into view
+/// by calling [`RecyclableFactoryComponent::rebind`].
+/// Put the list view into a [`gtk::ScrolledWindow`] to make it scrollable.
+///
+/// Because widgets are shared between elements, elements that aren't shown
+/// are updated with [`FactoryComponent::update`] instead of
+/// [`FactoryComponent::update_with_view`], and
+/// [`FactoryComponent::shutdown`] is only called for elements that are shown
+/// while they are removed.
+/// Each row gets its own [`ParentWidget`](FactoryComponent::ParentWidget) that
+/// holds the root widget of the element shown in this row.
+pub struct FactoryVirtualList<C>
+where
+    C: FactoryComponent<Index = DynamicIndex>,
+{
+    list_view: gtk::ListView,
+    store: gio::ListStore,
+    pool: Rc<WidgetPool<C>>,
+    output_sender: Sender<C::Output>,
+    elements: VecDeque<ElementHandle<C>>,
+}
+
+impl<C> fmt::Debug for FactoryVirtualList<C>
+where
+    C: FactoryComponent<Index = DynamicIndex>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FactoryVirtualList")
+            .field("list_view", &self.list_view)
+            .field("len", &self.elements.len())
+            .field("pooled_widgets", &self.pool.borrow().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C> Drop for FactoryVirtualList<C>
+where
+    C: FactoryComponent<Index = DynamicIndex>,
+{
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<C> FactoryVirtualList<C>
+where
+    C: RecyclableFactoryComponent<Index = DynamicIndex>,
+    C::ParentWidget: Default,
+{
+    /// Creates a new [`FactoryVirtualListBuilder`].
+    #[must_use]
+    pub fn builder() -> FactoryVirtualListBuilder<C> {
+        FactoryVirtualListBuilder::new()
+    }
+}
+
+impl<C> FactoryVirtualList<C>
+where
+    C: FactoryComponent<Index = DynamicIndex>,
+{
+    /// Returns the number of elements in the [`FactoryVirtualList`].
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns true if the [`FactoryVirtualList`] is empty.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns the list view that shows the elements.
+    pub const fn widget(&self) -> &gtk::ListView {
+        &self.list_view
+    }
+
+    /// Returns a reference to the model of the element at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element is currently updated.
+    pub fn get(&self, index: usize) -> Option<Ref<'_, C>> {
+        self.elements
+            .get(index)
+            .map(|handle| handle.element.model.borrow())
+    }
+
+    /// Returns an iterator over the models of all elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the elements is currently updated.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Ref<'_, C>> + ExactSizeIterator {
+        self.elements
+            .iter()
+            .map(|handle| handle.element.model.borrow())
+    }
+
+    /// Returns true if the element at `index` currently has widgets.
+    pub fn is_shown(&self, index: usize) -> bool {
+        self.elements
+            .get(index)
+            .is_some_and(|handle| handle.element.view.borrow().is_some())
+    }
+
+    /// Send a message to one of the elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn send(&self, index: usize, msg: C::Input) {
+        self.elements[index].element.sender.input(msg);
+    }
+
+    /// Send clone of a message to all of the elements.
+    pub fn broadcast(&self, msg: C::Input)
+    where
+        C::Input: Clone,
+    {
+        for handle in &self.elements {
+            handle.element.sender.input(msg.clone());
+        }
+    }
+
+    /// Returns a sender for the element at `index` that keeps pointing to
+    /// this element when other elements are inserted or removed.
+    pub fn element_sender(&self, index: usize) -> Option<FactoryElementSender<C>> {
+        self.elements
+            .get(index)
+            .map(|handle| FactoryElementSender::new(handle.element.sender.input_sender().clone()))
+    }
+
+    /// Appends an element at the end of the [`FactoryVirtualList`].
+    pub fn push_back(&mut self, init: C::Init) -> DynamicIndex {
+        self.insert(self.len(), init)
+    }
+
+    /// Prepends an element to the [`FactoryVirtualList`].
+    pub fn push_front(&mut self, init: C::Init) -> DynamicIndex {
+        self.insert(0, init)
+    }
+
+    /// Inserts an element at position `index` within the [`FactoryVirtualList`],
+    /// shifting all elements after it to the right.
+    ///
+    /// Widgets are only created once the list view shows the element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length of the [`FactoryVirtualList`].
+    pub fn insert(&mut self, index: usize, init: C::Init) -> DynamicIndex {
+        assert!(index <= self.len(), "Index out of bounds");
+
+        for handle in self.elements.range(index..) {
+            handle.element.index.increment();
+        }
+
+        let dyn_index = DynamicIndex::new(index);
+        let handle = self.launch(&dyn_index, init);
+        self.store.insert(
+            index as u32,
+            &glib::BoxedAnyObject::new(Rc::downgrade(&handle.element)),
+        );
+        self.elements.insert(index, handle);
+
+        dyn_index
+    }
+
+    /// Removes the last element from the [`FactoryVirtualList`] and returns it,
+    /// or [`None`] if it is empty.
+    pub fn pop_back(&mut self) -> Option<C> {
+        self.remove(self.len().checked_sub(1)?)
+    }
+
+    /// Removes the first element from the [`FactoryVirtualList`] and returns it,
+    /// or [`None`] if it is empty.
+    pub fn pop_front(&mut self) -> Option<C> {
+        self.remove(0)
+    }
+
+    /// Removes and returns the element at `index` from the [`FactoryVirtualList`].
+    /// Returns [`None`] if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Option<C> {
+        let handle = self.elements.remove(index)?;
+        for handle in self.elements.range(index..) {
+            handle.element.index.decrement();
+        }
+
+        // Shut down before the row is unbound, so the element still has its widgets.
+        let model = self.shutdown(handle);
+        self.store.remove(index as u32);
+        Some(model)
+    }
+
+    /// Removes all elements from the [`FactoryVirtualList`].
+    pub fn clear(&mut self) {
+        for handle in std::mem::take(&mut self.elements) {
+            self.shutdown(handle);
+        }
+        self.store.remove_all();
+    }
+
+    fn launch(&self, index: &DynamicIndex, init: C::Init) -> ElementHandle<C> {
+        let (input_sender, input_receiver) = crate::channel::<C::Input>();
+        let (cmd_sender, cmd_receiver) = crate::channel::<C::CommandOutput>();
+        let (shutdown_notifier, shutdown_receiver) = shutdown::channel();
+
+        let sender = FactorySender::new(
+            input_sender.clone(),
+            input_sender,
+            self.output_sender.clone(),
+            cmd_sender,
+            shutdown_receiver,
+        );
+        let model = C::init_model(init, index, sender.clone());
+
+        let element = Rc::new(VirtualElement {
+            model: RefCell::new(model),
+            index: index.clone(),
+            sender,
+            view: RefCell::new(None),
+        });
+
+        // The runtime only holds a weak reference, so the element
+        // can be returned after it was removed.
+        let weak_element = Rc::downgrade(&element);
+        let runtime = crate::spawn_local(async move {
+            let mut cmd = GuardedReceiver::new(cmd_receiver);
+            let mut input = GuardedReceiver::new(input_receiver);
+            loop {
+                futures::select!(
+                    message = input => {
+                        if let Some(element) = weak_element.upgrade() {
+                            element.update(message);
+                        }
+                    }
+                    message = cmd => {
+                        if let Some(element) = weak_element.upgrade() {
+                            element.update_cmd(message);
+                        }
+                    }
+                );
+            }
+        });
+
+        ElementHandle {
+            element,
+            runtime,
+            shutdown_notifier,
+        }
+    }
+
+    fn shutdown(&self, handle: ElementHandle<C>) -> C {
+        let ElementHandle {
+            element,
+            runtime,
+            shutdown_notifier,
+        } = handle;
+
+        runtime.abort();
+        shutdown_notifier.shutdown();
+
+        let Ok(element) = Rc::try_unwrap(element) else {
+            panic!("Elements can't be removed while they are updated");
+        };
+
+        let mut model = element.model.into_inner();
+        if let Some(mut view) = element.view.into_inner() {
+            view.parent.factory_remove(&view.returned_widget);
+            model.on_detach(&mut view.widgets, element.sender.clone());
+            model.shutdown(&mut view.widgets, self.output_sender.clone());
+            self.pool.borrow_mut().push((view.root, view.widgets));
+        }
+        model
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use gtk::glib::MainContext;
+    use gtk::prelude::ListModelExt;
+
+    use crate::factory::{
+        DynamicIndex, FactoryComponent, FactorySender, FactoryView, RecyclableFactoryComponent,
+    };
+
+    use super::FactoryVirtualList;
+
+    #[derive(Debug)]
+    struct Element {
+        value: u32,
+        index: DynamicIndex,
+        received: Vec<u32>,
+    }
+
+    impl FactoryComponent for Element {
+        type ParentWidget = gtk::Box;
+        type CommandOutput = ();
+        type Input = u32;
+        type Output = u32;
+        type Init = u32;
+        type Root = gtk::Label;
+        type Widgets = gtk::Label;
+        type Index = DynamicIndex;
+
+        fn init_model(value: Self::Init, index: &DynamicIndex, _: FactorySender<Self>) -> Self {
+            Self {
+                value,
+                index: index.clone(),
+                received: Vec::new(),
+            }
+        }
+
+        fn init_root(&self) -> Self::Root {
+            gtk::Label::default()
+        }
+
+        fn init_widgets(
+            &mut self,
+            _: &DynamicIndex,
+            root: Self::Root,
+            _: &<Self::ParentWidget as FactoryView>::ReturnedWidget,
+            _: FactorySender<Self>,
+        ) -> Self::Widgets {
+            root.set_label(&self.value.to_string());
+            root
+        }
+
+        fn update(&mut self, message: Self::Input, sender: FactorySender<Self>) {
+            self.received.push(message);
+            sender.output(message).ok();
+        }
+
+        fn update_view(&self, label: &mut Self::Widgets, _: FactorySender<Self>) {
+            label.set_label(&self.value.to_string());
+        }
+    }
+
+    impl RecyclableFactoryComponent for Element {
+        fn rebind(
+            &mut self,
+            _: &DynamicIndex,
+            label: &mut Self::Widgets,
+            sender: FactorySender<Self>,
+        ) {
+            self.update_view(label, sender);
+        }
+    }
+
+    fn process_messages() {
+        while MainContext::default().iteration(false) {}
+    }
+
+    fn values(factory: &FactoryVirtualList<Element>) -> Vec<u32> {
+        factory.iter().map(|element| element.value).collect()
+    }
+
+    #[gtk::test]
+    fn keeps_models_without_creating_all_widgets() {
+        let mut factory = FactoryVirtualList::<Element>::builder()
+            .launch_default()
+            .detach();
+        for value in 0..10_000 {
+            factory.push_back(value);
+        }
+        process_messages();
+
+        assert_eq!(factory.len(), 10_000);
+        assert_eq!(factory.widget().model().unwrap().n_items(), 10_000);
+        assert!((0..factory.len()).filter(|i| factory.is_shown(*i)).count() < 10_000);
+        assert_eq!(factory.get(9_999).unwrap().value, 9_999);
+    }
+
+    #[gtk::test]
+    fn updates_models_of_hidden_elements() {
+        let connector = FactoryVirtualList::<Element>::builder().launch_default();
+        let outputs = connector.output_receiver.0.clone();
+        let mut factory = connector.detach();
+        factory.push_back(1);
+        factory.push_back(2);
+
+        factory.send(1, 7);
+        factory.broadcast(3);
+        process_messages();
+
+        assert_eq!(factory.get(0).unwrap().received, [3]);
+        assert_eq!(factory.get(1).unwrap().received, [7, 3]);
+        let mut outputs: Vec<u32> = outputs.drain().collect();
+        outputs.sort_unstable();
+        assert_eq!(outputs, [3, 3, 7]);
+    }
+
+    #[gtk::test]
+    fn insert_and_remove_update_indexes() {
+        let mut factory = FactoryVirtualList::<Element>::builder()
+            .launch_default()
+            .detach();
+        factory.push_back(1);
+        factory.push_back(3);
+        factory.insert(1, 2);
+        factory.push_front(0);
+        assert_eq!(values(&factory), [0, 1, 2, 3]);
+
+        let sender = factory.element_sender(2).unwrap();
+        let removed = factory.remove(1).unwrap();
+        assert_eq!(removed.value, 1);
+        assert_eq!(values(&factory), [0, 2, 3]);
+        assert_eq!(factory.widget().model().unwrap().n_items(), 3);
+
+        let indexes: Vec<usize> = factory
+            .iter()
+            .map(|element| element.index.current_index())
+            .collect();
+        assert_eq!(indexes, [0, 1, 2]);
+
+        // The sender still points to the element with the value 2.
+        sender.emit(5);
+        process_messages();
+        assert_eq!(factory.get(1).unwrap().received, [5]);
+
+        assert_eq!(factory.pop_back().unwrap().value, 3);
+        factory.clear();
+        assert!(factory.is_empty());
+        assert_eq!(factory.widget().model().unwrap().n_items(), 0);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::factory::{FactoryComponent, FactoryView};
+use crate::Sender;
 
-use super::{FactoryBuilder, FactoryHandle};
+use super::{FactoryBuilder, FactoryHandle, RebindFn, RecycledWidgets};
 
 #[derive(Debug)]
 pub(super) enum ComponentStorage<C: FactoryComponent> {
@@ -37,6 +38,13 @@ impl<C: FactoryComponent> ComponentStorage<C> {
         }
     }
 
+    pub(super) fn input_sender(&self) -> &Sender<C::Input> {
+        match self {
+            Self::Builder(builder) => builder.component_sender.input_sender(),
+            Self::Final(handle) => &handle.input,
+        }
+    }
+
     pub(super) fn state_change_notify(&self) {
         if let Self::Final(handle) = self {
             handle.notifier.send(()).unwrap();
@@ -46,22 +54,62 @@ impl<C: FactoryComponent> ComponentStorage<C> {
     pub(super) fn extract(self) -> C {
         match self {
             Self::Builder(builder) => *builder.data,
-            Self::Final(handle) => handle.data.into_inner(),
+            Self::Final(mut handle) => {
+                // The widget was already removed by the factory.
+                handle.on_detach();
+                handle.data.into_inner()
+            }
         }
     }
 
+    /// Shuts down a launched component and returns its widgets,
+    /// so they can be reused for a new element.
+    ///
+    /// Returns the component again if it wasn't launched yet.
+    /// The widget must already be removed from the parent widget.
+    pub(super) fn into_recycled(self, rebind: RebindFn<C>) -> Result<RecycledWidgets<C>, Self> {
+        match self {
+            Self::Builder(builder) => Err(Self::Builder(builder)),
+            Self::Final(mut handle) => {
+                handle.on_detach();
+                Ok(handle.into_recycled(rebind))
+            }
+        }
+    }
+
+    /// Launches the component or re-attaches it after it
+    /// was moved from another factory.
     pub(super) fn launch(
         self,
         index: &C::Index,
         returned_widget: <C::ParentWidget as FactoryView>::ReturnedWidget,
-    ) -> Option<Self> {
-        if let Self::Builder(builder) = self {
-            Some(Self::Final(builder.launch(index, returned_widget)))
-        } else {
-            None
+    ) -> Self {
+        match self {
+            Self::Builder(builder) => Self::Final(builder.launch(index, returned_widget)),
+            Self::Final(mut handle) => {
+                handle.returned_widget = returned_widget;
+                handle.on_attach();
+                Self::Final(handle)
+            }
+        }
+    }
+
+    /// Calls [`FactoryComponent::on_detach`] if the component was launched.
+    pub(super) fn on_detach(&mut self) {
+        if let Self::Final(handle) = self {
+            handle.on_detach();
         }
     }
 
+    /// Sends future outputs of the component to `output_sender`.
+    pub(super) fn set_output_sender(&self, output_sender: Sender<C::Output>) {
+        let output_target = match self {
+            Self::Builder(builder) => &builder.output_target,
+            Self::Final(handle) => &handle.output_target,
+        };
+        *output_target.lock().unwrap() = output_sender;
+    }
+
     pub(super) const fn returned_widget(
         &self,
     ) -> Option<&<C::ParentWidget as FactoryView>::ReturnedWidget> {
@@ -0,0 +1,57 @@
+use std::fmt;
+
+use crate::factory::FactoryComponent;
+use crate::Sender;
+
+/// A cheap, cloneable handle for sending input messages to a single factory element.
+///
+/// The handle stays tied to its element when the element is moved
+/// or when other elements are inserted or removed.
+/// Once the element was removed, messages can't be delivered anymore.
+pub struct FactoryElementSender<C: FactoryComponent> {
+    input: Sender<C::Input>,
+}
+
+impl<C: FactoryComponent> FactoryElementSender<C> {
+    pub(super) fn new(input: Sender<C::Input>) -> Self {
+        Self { input }
+    }
+
+    /// Sends an input message to the element.
+    ///
+    /// **This method ignores errors.**
+    /// Only a log message will appear when the element was removed.
+    pub fn emit(&self, message: C::Input) {
+        self.input.emit(message);
+    }
+
+    /// Sends an input message to the element.
+    ///
+    /// If the element was removed, [`Err`] is returned
+    /// with the content of the message.
+    pub fn send(&self, message: C::Input) -> Result<(), C::Input> {
+        self.input.send(message)
+    }
+
+    /// Returns true if the element was removed from its factory.
+    #[must_use]
+    pub fn is_removed(&self) -> bool {
+        self.input.0.is_disconnected()
+    }
+}
+
+impl<C: FactoryComponent> Clone for FactoryElementSender<C> {
+    fn clone(&self) -> Self {
+        Self {
+            input: self.input.clone(),
+        }
+    }
+}
+
+impl<C: FactoryComponent> fmt::Debug for FactoryElementSender<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FactoryElementSender")
+            .field("input", &self.input)
+            .finish()
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt;
+use std::sync::{Arc, Mutex};
 
-use crate::factory::{DataGuard, FactoryComponent, FactoryView};
+use crate::factory::{DataGuard, FactoryComponent, FactorySender, FactoryView};
 use crate::Sender;
 
 /// Don't allow public access to a [`FactoryHandle`].
@@ -14,6 +15,59 @@ pub(super) struct FactoryHandle<C: FactoryComponent> {
     pub(super) returned_widget: <C::ParentWidget as FactoryView>::ReturnedWidget,
     pub(super) input: Sender<C::Input>,
     pub(super) notifier: Sender<()>,
+    pub(super) sender: FactorySender<C>,
+    pub(super) output_target: Arc<Mutex<Sender<C::Output>>>,
+}
+
+impl<C: FactoryComponent> FactoryHandle<C> {
+    /// Calls [`FactoryComponent::on_attach`].
+    pub(super) fn on_attach(&mut self) {
+        let (data, widgets) = self.data.get_mut_with_widgets();
+        data.on_attach(widgets, self.sender.clone());
+    }
+
+    /// Calls [`FactoryComponent::on_detach`].
+    pub(super) fn on_detach(&mut self) {
+        let (data, widgets) = self.data.get_mut_with_widgets();
+        data.on_detach(widgets, self.sender.clone());
+    }
+
+    /// Shuts down the component and returns its widgets,
+    /// so they can be reused for a new element.
+    pub(super) fn into_recycled(self, rebind: RebindFn<C>) -> RecycledWidgets<C> {
+        let (_, widgets) = self.data.into_parts();
+        RecycledWidgets {
+            root_widget: self.root_widget,
+            widgets: Box::new(widgets),
+            rebind,
+        }
+    }
+}
+
+/// [`RecyclableFactoryComponent::rebind`](crate::factory::RecyclableFactoryComponent::rebind)
+/// of the component.
+pub(super) type RebindFn<C> = fn(
+    &mut C,
+    &<C as FactoryComponent>::Index,
+    &mut <C as FactoryComponent>::Widgets,
+    FactorySender<C>,
+);
+
+/// The widgets of a component that was shut down and
+/// waits to be reused for a new element.
+pub(super) struct RecycledWidgets<C: FactoryComponent> {
+    pub(super) root_widget: C::Root,
+    pub(super) widgets: Box<C::Widgets>,
+    pub(super) rebind: RebindFn<C>,
+}
+
+impl<C: FactoryComponent> fmt::Debug for RecycledWidgets<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecycledWidgets")
+            .field("root_widget", &self.root_widget)
+            .field("widgets", &"<Widgets>")
+            .finish()
+    }
 }
 
 impl<C: FactoryComponent> fmt::Debug for FactoryHandle<C> {
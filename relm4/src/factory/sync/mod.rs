@@ -1,14 +1,21 @@
 mod builder;
 mod collections;
 mod component_storage;
+mod element_sender;
 mod handle;
+mod standalone;
 mod traits;
 
 use builder::FactoryBuilder;
-use handle::FactoryHandle;
+use handle::{FactoryHandle, RebindFn, RecycledWidgets};
 
 pub use collections::{
-    FactoryHashMap, FactoryHashMapBuilder, FactoryHashMapConnector, FactoryVecDeque,
-    FactoryVecDequeBuilder, FactoryVecDequeConnector, FactoryVecDequeGuard,
+    FactoryElementMut, FactoryHashMap, FactoryHashMapBuilder, FactoryHashMapConnector,
+    FactoryHashMapEntry, FactoryHashMapOccupiedEntry, FactoryHashMapVacantEntry, FactoryKeyedVec,
+    FactoryKeyedVecBuilder, FactoryKeyedVecConnector, FactoryVecDeque, FactoryVecDequeBuilder,
+    FactoryVecDequeConnector, FactoryVecDequeGuard, FactoryVirtualList, FactoryVirtualListBuilder,
+    FactoryVirtualListConnector,
 };
-pub use traits::{CloneableFactoryComponent, FactoryComponent};
+pub use element_sender::FactoryElementSender;
+pub use standalone::StandaloneFactoryComponent;
+pub use traits::{CloneableFactoryComponent, FactoryComponent, RecyclableFactoryComponent};
@@ -0,0 +1,138 @@
+use crate::factory::{FactoryComponent, FactoryElementSender, FactoryView};
+use crate::Receiver;
+
+use super::collections::FactoryElementGuard;
+use super::{FactoryBuilder, FactoryHandle};
+
+use std::fmt;
+
+/// A single [`FactoryComponent`] that's launched outside of a factory collection.
+///
+/// This is useful for testing factory components or for reusing
+/// a factory component outside of a list.
+/// The root widget is still added to a parent widget, because
+/// [`FactoryComponent::init_widgets`] needs the returned widget.
+///
+/// The component is shut down when this type is dropped.
+pub struct StandaloneFactoryComponent<C: FactoryComponent> {
+    widget: C::ParentWidget,
+    index: C::Index,
+    handle: Option<FactoryHandle<C>>,
+    output_receiver: Receiver<C::Output>,
+}
+
+impl<C: FactoryComponent> fmt::Debug for StandaloneFactoryComponent<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StandaloneFactoryComponent")
+            .field("widget", &self.widget)
+            .field("index", &"<Index>")
+            .field("handle", &self.handle)
+            .field("output_receiver", &self.output_receiver)
+            .finish()
+    }
+}
+
+impl<C> StandaloneFactoryComponent<C>
+where
+    C: FactoryComponent,
+    C::ParentWidget: Default,
+{
+    /// Launches the component with a default parent widget.
+    #[must_use]
+    pub fn launch_default(index: C::Index, init: C::Init) -> Self {
+        Self::launch(Default::default(), index, init)
+    }
+}
+
+impl<C: FactoryComponent> StandaloneFactoryComponent<C> {
+    /// Launches the component and appends its root widget to `widget`.
+    #[must_use]
+    pub fn launch(widget: C::ParentWidget, index: C::Index, init: C::Init) -> Self {
+        let (output_sender, output_receiver) = crate::channel();
+
+        let builder = FactoryBuilder::new(&index, init, output_sender);
+        let position = C::position(&builder.data, &index);
+        let returned_widget = widget.factory_append(builder.root_widget.clone(), &position);
+        let handle = builder.launch(&index, returned_widget);
+
+        Self {
+            widget,
+            index,
+            handle: Some(handle),
+            output_receiver,
+        }
+    }
+
+    fn handle(&self) -> &FactoryHandle<C> {
+        self.handle.as_ref().unwrap()
+    }
+
+    /// Returns an immutable reference to the model.
+    pub fn model(&self) -> &C {
+        self.handle().data.get()
+    }
+
+    /// Returns a mutable reference to the model.
+    ///
+    /// The view is updated after the guard goes out of scope.
+    pub fn model_mut(&mut self) -> FactoryElementGuard<'_, C> {
+        FactoryElementGuard {
+            inner: self.handle.as_mut().unwrap(),
+        }
+    }
+
+    /// Sends an input message to the component.
+    pub fn emit(&self, message: C::Input) {
+        self.handle().input.emit(message);
+    }
+
+    /// Returns a [`FactoryElementSender`] for sending input messages to the component.
+    pub fn element_sender(&self) -> FactoryElementSender<C> {
+        FactoryElementSender::new(self.handle().input.clone())
+    }
+
+    /// Returns the receiver of the output messages of the component.
+    pub const fn output_receiver(&self) -> &Receiver<C::Output> {
+        &self.output_receiver
+    }
+
+    /// Returns the index the component was launched with.
+    pub const fn index(&self) -> &C::Index {
+        &self.index
+    }
+
+    /// Returns the parent widget.
+    pub const fn widget(&self) -> &C::ParentWidget {
+        &self.widget
+    }
+
+    /// Returns the root widget of the component.
+    pub fn root_widget(&self) -> &C::Root {
+        &self.handle().root_widget
+    }
+
+    /// Returns the widget that was returned when
+    /// the root widget was added to the parent widget.
+    pub fn returned_widget(&self) -> &<C::ParentWidget as FactoryView>::ReturnedWidget {
+        &self.handle().returned_widget
+    }
+
+    /// Shuts the component down and returns its model.
+    pub fn into_model(mut self) -> C {
+        self.shutdown().unwrap()
+    }
+
+    fn shutdown(&mut self) -> Option<C> {
+        self.handle.take().map(|mut handle| {
+            self.widget.factory_remove(&handle.returned_widget);
+            handle.on_detach();
+            handle.data.into_inner()
+        })
+    }
+}
+
+impl<C: FactoryComponent> Drop for StandaloneFactoryComponent<C> {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
@@ -21,6 +21,20 @@ pub trait FactoryComponent:
     type Input: Debug + 'static;
 
     /// The message type that the factory component provides as outputs.
+    ///
+    /// If the factory component contains a factory itself, the outputs of
+    /// the nested elements can bubble up to the parent of this factory by
+    /// forwarding them to [`FactorySender::output_sender`] instead of
+    /// [`FactorySender::input_sender`]:
+    ///
+    /// ```ignore
+    /// let children = FactoryVecDeque::builder()
+    ///     .launch_default()
+    ///     .forward(sender.output_sender(), CardOutput::Child);
+    /// ```
+    ///
+    /// This works across any number of nesting levels
+    /// without relay messages in the components in between.
     type Output: Debug + 'static;
 
     /// The parameter used to initialize the factory component.
@@ -89,7 +103,28 @@ pub trait FactoryComponent:
         self.update_view(widgets, sender);
     }
 
+    /// Called after the root widget was added to the parent widget.
+    ///
+    /// Unlike [`init_model`](Self::init_model), this method is called every time
+    /// the element is attached to a parent widget, for example after it was moved
+    /// to another factory.
+    #[allow(unused)]
+    fn on_attach(&mut self, widgets: &mut Self::Widgets, sender: FactorySender<Self>) {}
+
+    /// Called after the root widget was removed from the parent widget.
+    ///
+    /// When the element is removed from the factory, this method is called
+    /// before [`shutdown`](Self::shutdown).
+    #[allow(unused)]
+    fn on_detach(&mut self, widgets: &mut Self::Widgets, sender: FactorySender<Self>) {}
+
     /// Last method called before a component is shut down.
+    ///
+    /// This happens when the element is removed from its factory.
+    /// At this point, commands spawned with
+    /// [`oneshot_command`](FactorySender::oneshot_command) or
+    /// [`cancellable_command`](FactorySender::cancellable_command)
+    /// are being cancelled.
     #[allow(unused)]
     fn shutdown(&mut self, widgets: &mut Self::Widgets, output: Sender<Self::Output>) {}
 
@@ -102,6 +137,37 @@ pub trait FactoryComponent:
     }
 }
 
+/// Extension for [`FactoryComponent`] that allows reusing the widgets of discarded elements.
+///
+/// If recycling is enabled with
+/// [`FactoryVecDeque::enable_recycling`](crate::factory::FactoryVecDeque::enable_recycling),
+/// new elements can reuse the root and widgets of discarded elements instead of calling
+/// [`init_root`](FactoryComponent::init_root) and [`init_widgets`](FactoryComponent::init_widgets).
+/// [`FactoryVirtualList`](crate::factory::FactoryVirtualList) always reuses the widgets
+/// of elements that were scrolled out of view, while their models stay alive.
+///
+/// The discarded element is shut down before its widgets are reused and the new
+/// element gets its own index and sender, so messages sent through the index or sender
+/// of the discarded element never reach the new element.
+/// In a [`FactoryVirtualList`](crate::factory::FactoryVirtualList), `rebind` is also called
+/// with the index and sender of an existing element each time it scrolls into view.
+/// Only implement this trait if no signal handler of the widgets captured them,
+/// for example if the handlers look up the element through shared state.
+/// Signal handlers connected by the [`view!`](crate::view) macro can't be connected again,
+/// so components that send messages from these handlers can't be recycled.
+pub trait RecyclableFactoryComponent: FactoryComponent {
+    /// Updates recycled widgets after they were assigned to a new model.
+    ///
+    /// This method is called with the model that takes over the widgets and should bring all widgets
+    /// up to date, usually by calling [`update_view`](FactoryComponent::update_view).
+    fn rebind(
+        &mut self,
+        index: &Self::Index,
+        widgets: &mut Self::Widgets,
+        sender: FactorySender<Self>,
+    );
+}
+
 /// Extension for [`FactoryComponent`] that makes elements cloneable.
 pub trait CloneableFactoryComponent: FactoryComponent {
     /// Retrieve the initialization data from an initialized factory component.
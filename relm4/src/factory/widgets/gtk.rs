@@ -1,4 +1,4 @@
-use gtk::prelude::{BoxExt, Cast, FlowBoxChildExt, GridExt, ListBoxRowExt, WidgetExt};
+use gtk::prelude::{BoxExt, Cast, FixedExt, FlowBoxChildExt, GridExt, ListBoxRowExt, WidgetExt};
 
 use crate::factory::{positions, FactoryView};
 
@@ -50,6 +50,10 @@ impl FactoryView for gtk::Box {
     fn factory_move_start(&self, widget: &Self::ReturnedWidget) {
         self.reorder_child_after(widget, None::<&gtk::Widget>);
     }
+
+    fn factory_set_visible(&self, widget: &Self::ReturnedWidget, visible: bool) {
+        widget.set_visible(visible);
+    }
 }
 
 impl FactoryView for gtk::Grid {
@@ -105,8 +109,149 @@ impl FactoryView for gtk::Grid {
         self.factory_remove(widget);
         self.factory_append(widget, position);
     }
+
+    fn factory_set_visible(&self, widget: &Self::ReturnedWidget, visible: bool) {
+        widget.set_visible(visible);
+    }
+}
+
+/// Children of a [`gtk::Fixed`] are placed at the coordinates of their
+/// [`FixedPosition`](positions::FixedPosition).
+///
+/// [`gtk::Fixed`] doesn't have an ordering of its children, so
+/// moving widgets inside the factory has no visual effect.
+/// To move a widget to new coordinates, update the data used by
+/// [`Position::position`](crate::factory::Position::position) and call
+/// [`reposition_all`](crate::factory::FactoryVecDequeGuard::reposition_all).
+impl FactoryView for gtk::Fixed {
+    type Children = gtk::Widget;
+    type ReturnedWidget = gtk::Widget;
+    type Position = positions::FixedPosition;
+
+    fn factory_remove(&self, widget: &Self::ReturnedWidget) {
+        self.remove(widget);
+    }
+
+    fn factory_append(
+        &self,
+        widget: impl AsRef<Self::Children>,
+        position: &Self::Position,
+    ) -> Self::ReturnedWidget {
+        self.put(widget.as_ref(), position.x, position.y);
+        widget.as_ref().clone()
+    }
+
+    fn factory_prepend(
+        &self,
+        widget: impl AsRef<Self::Children>,
+        position: &Self::Position,
+    ) -> Self::ReturnedWidget {
+        self.factory_append(widget, position)
+    }
+
+    fn factory_insert_after(
+        &self,
+        widget: impl AsRef<Self::Children>,
+        position: &Self::Position,
+        _other: &Self::ReturnedWidget,
+    ) -> Self::ReturnedWidget {
+        self.factory_append(widget, position)
+    }
+
+    fn factory_move_after(&self, _widget: &Self::ReturnedWidget, _other: &Self::ReturnedWidget) {}
+
+    fn factory_move_start(&self, _widget: &Self::ReturnedWidget) {}
+
+    fn returned_widget_to_child(returned_widget: &Self::ReturnedWidget) -> Self::Children {
+        returned_widget.clone()
+    }
+
+    fn factory_update_position(&self, widget: &Self::ReturnedWidget, position: &Self::Position) {
+        self.move_(widget, position.x, position.y);
+    }
+
+    fn factory_set_visible(&self, widget: &Self::ReturnedWidget, visible: bool) {
+        widget.set_visible(visible);
+    }
 }
 
+/// Factory elements are added as overlay children of the [`gtk::Overlay`].
+///
+/// The [`OverlayPosition`](positions::OverlayPosition) sets the alignment of the
+/// child and whether it is measured or clipped. Elements later in the
+/// factory are drawn on top of previous elements.
+impl FactoryView for gtk::Overlay {
+    type Children = gtk::Widget;
+    type ReturnedWidget = gtk::Widget;
+    type Position = positions::OverlayPosition;
+
+    fn factory_remove(&self, widget: &Self::ReturnedWidget) {
+        self.remove_overlay(widget);
+    }
+
+    fn factory_append(
+        &self,
+        widget: impl AsRef<Self::Children>,
+        position: &Self::Position,
+    ) -> Self::ReturnedWidget {
+        let widget = widget.as_ref();
+        self.add_overlay(widget);
+        self.factory_update_position(widget, position);
+        widget.clone()
+    }
+
+    fn factory_prepend(
+        &self,
+        widget: impl AsRef<Self::Children>,
+        position: &Self::Position,
+    ) -> Self::ReturnedWidget {
+        let widget = self.factory_append(widget, position);
+        self.factory_move_start(&widget);
+        widget
+    }
+
+    fn factory_insert_after(
+        &self,
+        widget: impl AsRef<Self::Children>,
+        position: &Self::Position,
+        other: &Self::ReturnedWidget,
+    ) -> Self::ReturnedWidget {
+        let widget = self.factory_append(widget, position);
+        self.factory_move_after(&widget, other);
+        widget
+    }
+
+    fn factory_move_after(&self, widget: &Self::ReturnedWidget, other: &Self::ReturnedWidget) {
+        widget.insert_after(self, Some(other));
+    }
+
+    fn factory_move_start(&self, widget: &Self::ReturnedWidget) {
+        // The main child is drawn first, so overlay children are placed after it.
+        widget.insert_after(self, self.child().as_ref());
+    }
+
+    fn returned_widget_to_child(returned_widget: &Self::ReturnedWidget) -> Self::Children {
+        returned_widget.clone()
+    }
+
+    fn factory_update_position(&self, widget: &Self::ReturnedWidget, position: &Self::Position) {
+        widget.set_halign(position.halign);
+        widget.set_valign(position.valign);
+        self.set_measure_overlay(widget, position.measure);
+        self.set_clip_overlay(widget, position.clip);
+    }
+
+    fn factory_set_visible(&self, widget: &Self::ReturnedWidget, visible: bool) {
+        widget.set_visible(visible);
+    }
+}
+
+/// The returned [`gtk::StackPage`] can be used in
+/// [`init_widgets`](crate::factory::FactoryComponent::init_widgets)
+/// to set the title, name and icon of the page.
+///
+/// [`gtk::Stack`] doesn't support reordering its pages,
+/// so new pages are always added at the end.
 impl FactoryView for gtk::Stack {
     type Children = gtk::Widget;
     type ReturnedWidget = gtk::StackPage;
@@ -148,6 +293,84 @@ impl FactoryView for gtk::Stack {
     fn returned_widget_to_child(returned_widget: &Self::ReturnedWidget) -> Self::Children {
         returned_widget.child()
     }
+
+    fn factory_set_visible(&self, widget: &Self::ReturnedWidget, visible: bool) {
+        widget.set_visible(visible);
+    }
+}
+
+/// The returned [`gtk::NotebookPage`] can be used in
+/// [`init_widgets`](crate::factory::FactoryComponent::init_widgets)
+/// to set the tab label and other properties of the page.
+impl FactoryView for gtk::Notebook {
+    type Children = gtk::Widget;
+    type ReturnedWidget = gtk::NotebookPage;
+    type Position = ();
+
+    fn factory_remove(&self, widget: &Self::ReturnedWidget) {
+        if let Some(page_num) = self.page_num(&widget.child()) {
+            self.remove_page(Some(page_num));
+        }
+    }
+
+    fn factory_append(
+        &self,
+        widget: impl AsRef<Self::Children>,
+        _position: &(),
+    ) -> Self::ReturnedWidget {
+        let widget = widget.as_ref();
+        self.append_page(widget, None::<&gtk::Widget>);
+        self.page(widget)
+    }
+
+    fn factory_prepend(
+        &self,
+        widget: impl AsRef<Self::Children>,
+        _position: &(),
+    ) -> Self::ReturnedWidget {
+        let widget = widget.as_ref();
+        self.prepend_page(widget, None::<&gtk::Widget>);
+        self.page(widget)
+    }
+
+    fn factory_insert_after(
+        &self,
+        widget: impl AsRef<Self::Children>,
+        _position: &(),
+        other: &Self::ReturnedWidget,
+    ) -> Self::ReturnedWidget {
+        let widget = widget.as_ref();
+        let new_position = self.page_num(&other.child()).map(|page_num| page_num + 1);
+        self.insert_page(widget, None::<&gtk::Widget>, new_position);
+        self.page(widget)
+    }
+
+    fn factory_move_after(&self, widget: &Self::ReturnedWidget, other: &Self::ReturnedWidget) {
+        let child = widget.child();
+        if let (Some(current_position), Some(other_position)) =
+            (self.page_num(&child), self.page_num(&other.child()))
+        {
+            // The positions of the following pages shift when the page is moved.
+            let new_position = if current_position < other_position {
+                other_position
+            } else {
+                other_position + 1
+            };
+            self.reorder_child(&child, Some(new_position));
+        }
+    }
+
+    fn factory_move_start(&self, widget: &Self::ReturnedWidget) {
+        self.reorder_child(&widget.child(), Some(0));
+    }
+
+    fn returned_widget_to_child(returned_widget: &Self::ReturnedWidget) -> Self::Children {
+        returned_widget.child()
+    }
+
+    fn factory_set_visible(&self, widget: &Self::ReturnedWidget, visible: bool) {
+        widget.child().set_visible(visible);
+    }
 }
 
 impl FactoryView for gtk::ListBox {
@@ -221,6 +444,10 @@ impl FactoryView for gtk::ListBox {
             .child()
             .unwrap_or_else(|| returned_widget.upcast_ref::<gtk::Widget>().clone())
     }
+
+    fn factory_set_visible(&self, widget: &Self::ReturnedWidget, visible: bool) {
+        widget.set_visible(visible);
+    }
 }
 
 impl FactoryView for gtk::FlowBox {
@@ -294,6 +521,10 @@ impl FactoryView for gtk::FlowBox {
             .child()
             .unwrap_or_else(|| returned_widget.upcast_ref::<gtk::Widget>().clone())
     }
+
+    fn factory_set_visible(&self, widget: &Self::ReturnedWidget, visible: bool) {
+        widget.set_visible(visible);
+    }
 }
 
 // impl FactoryView<gtk::TreeViewColumn> for gtk::TreeView {
@@ -331,23 +562,6 @@ impl FactoryView for gtk::FlowBox {
 //     }
 // }
 
-// impl<Widget> FactoryView<Widget> for gtk::Fixed
-// where
-//     Widget: glib::IsA<gtk::Widget>,
-// {
-//     type Position = FixedPosition;
-//     type Root = Widget;
-
-//     fn add(&self, widget: &Widget, position: &FixedPosition) -> Widget {
-//         gtk::prelude::FixedExt::put(self, widget, position.x, position.y);
-//         widget.clone()
-//     }
-
-//     fn remove(&self, widget: &Widget) {
-//         gtk::prelude::FixedExt::remove(self, widget);
-//     }
-// }
-
 // impl<Widget> FactoryView<Widget> for gtk::Grid
 // where
 //     Widget: glib::IsA<gtk::Widget>,
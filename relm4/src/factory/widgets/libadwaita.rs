@@ -3,6 +3,14 @@ use adw::prelude::*;
 
 use crate::factory::FactoryView;
 
+/// The returned [`adw::TabPage`] can be used in
+/// [`init_widgets`](crate::factory::FactoryComponent::init_widgets)
+/// to set the title, icon and attention state of the page.
+///
+/// Pages can be pinned with [`adw::TabView::set_page_pinned`].
+/// Because pinned pages are always placed before regular pages,
+/// the factory might not be able to keep the same order
+/// for pinned pages.
 impl FactoryView for adw::TabView {
     type Children = gtk::Widget;
     type ReturnedWidget = adw::TabPage;
@@ -10,6 +18,10 @@ impl FactoryView for adw::TabView {
     type Position = ();
 
     fn factory_remove(&self, widget: &Self::ReturnedWidget) {
+        // Pinned pages refuse to be closed.
+        if widget.is_pinned() {
+            self.set_page_pinned(widget, false);
+        }
         self.close_page(widget);
         self.close_page_finish(widget, true);
     }
@@ -36,7 +48,8 @@ impl FactoryView for adw::TabView {
         _position: &(),
         other: &Self::ReturnedWidget,
     ) -> Self::ReturnedWidget {
-        let new_position = self.page_position(other) + 1;
+        // Regular pages can't be inserted between pinned pages.
+        let new_position = (self.page_position(other) + 1).max(self.n_pinned_pages());
         self.insert(widget.as_ref(), new_position)
     }
 
@@ -101,8 +114,17 @@ impl FactoryView for adw::PreferencesPage {
     fn factory_move_after(&self, _widget: &Self::ReturnedWidget, _other: &Self::ReturnedWidget) {}
 
     fn factory_move_start(&self, _widget: &Self::ReturnedWidget) {}
+
+    fn factory_set_visible(&self, widget: &Self::ReturnedWidget, visible: bool) {
+        widget.set_visible(visible);
+    }
 }
 
+/// Factory elements are added as rows of the [`adw::ExpanderRow`].
+///
+/// [`adw::ExpanderRow`] doesn't support inserting or reordering rows,
+/// so new rows are always added at the end and moving elements
+/// inside the factory doesn't change the order of the rows.
 impl FactoryView for adw::ExpanderRow {
     type Children = gtk::Widget;
     type ReturnedWidget = gtk::Widget;
@@ -150,6 +172,10 @@ impl FactoryView for adw::ExpanderRow {
         self.factory_remove(widget);
         self.factory_append(widget, position);
     }
+
+    fn factory_set_visible(&self, widget: &Self::ReturnedWidget, visible: bool) {
+        widget.set_visible(visible);
+    }
 }
 
 impl FactoryView for adw::Carousel {
@@ -205,8 +231,17 @@ impl FactoryView for adw::Carousel {
     fn factory_move_start(&self, widget: &Self::ReturnedWidget) {
         self.reorder(widget, 0);
     }
+
+    fn factory_set_visible(&self, widget: &Self::ReturnedWidget, visible: bool) {
+        widget.set_visible(visible);
+    }
 }
 
+/// Factory elements are added as rows of the [`adw::PreferencesGroup`].
+///
+/// [`adw::PreferencesGroup`] doesn't support inserting or reordering rows,
+/// so new rows are always added at the end and moving elements
+/// inside the factory doesn't change the order of the rows.
 impl FactoryView for adw::PreferencesGroup {
     type Children = gtk::Widget;
     type ReturnedWidget = gtk::Widget;
@@ -251,6 +286,10 @@ impl FactoryView for adw::PreferencesGroup {
     fn factory_move_after(&self, _widget: &Self::ReturnedWidget, _other: &Self::ReturnedWidget) {}
 
     fn factory_move_start(&self, _widget: &Self::ReturnedWidget) {}
+
+    fn factory_set_visible(&self, widget: &Self::ReturnedWidget, visible: bool) {
+        widget.set_visible(visible);
+    }
 }
 
 impl FactoryView for adw::Leaflet {
@@ -298,4 +337,109 @@ impl FactoryView for adw::Leaflet {
     fn returned_widget_to_child(returned_widget: &Self::ReturnedWidget) -> Self::Children {
         returned_widget.child()
     }
+
+    fn factory_set_visible(&self, widget: &Self::ReturnedWidget, visible: bool) {
+        widget.child().set_visible(visible);
+    }
+}
+
+#[cfg(feature = "gnome_45")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gnome_45")))]
+mod gnome_45 {
+    use adw::prelude::*;
+
+    use crate::factory::FactoryView;
+
+    /// Returns the pages of the navigation stack from bottom to top.
+    fn navigation_pages(view: &adw::NavigationView) -> Vec<adw::NavigationPage> {
+        let stack = view.navigation_stack();
+        (0..stack.n_items())
+            .filter_map(|i| stack.item(i))
+            .filter_map(|page| page.downcast().ok())
+            .collect()
+    }
+
+    /// The factory elements form the navigation stack of the [`adw::NavigationView`].
+    ///
+    /// The first element is the root page, the last element is the visible page.
+    /// Appending an element pushes a new page onto the stack.
+    impl FactoryView for adw::NavigationView {
+        type Children = adw::NavigationPage;
+        type ReturnedWidget = adw::NavigationPage;
+        type Position = ();
+
+        fn factory_remove(&self, widget: &Self::ReturnedWidget) {
+            let mut pages = navigation_pages(self);
+            pages.retain(|page| page != widget);
+            self.replace(&pages);
+        }
+
+        fn factory_append(
+            &self,
+            widget: impl AsRef<Self::Children>,
+            _position: &Self::Position,
+        ) -> Self::ReturnedWidget {
+            let page = widget.as_ref();
+            self.push(page);
+            page.clone()
+        }
+
+        fn factory_prepend(
+            &self,
+            widget: impl AsRef<Self::Children>,
+            _position: &(),
+        ) -> Self::ReturnedWidget {
+            let page = widget.as_ref();
+            let mut pages = navigation_pages(self);
+            pages.insert(0, page.clone());
+            self.replace(&pages);
+            page.clone()
+        }
+
+        fn factory_insert_after(
+            &self,
+            widget: impl AsRef<Self::Children>,
+            position: &(),
+            other: &Self::ReturnedWidget,
+        ) -> Self::ReturnedWidget {
+            let mut pages = navigation_pages(self);
+            // Fall back to appending if `other` isn't part of the stack.
+            let new_position = pages
+                .iter()
+                .position(|page| page == other)
+                .map_or(pages.len(), |position| position + 1);
+
+            if new_position == pages.len() {
+                self.factory_append(widget, position)
+            } else {
+                let page = widget.as_ref();
+                pages.insert(new_position, page.clone());
+                self.replace(&pages);
+                page.clone()
+            }
+        }
+
+        fn returned_widget_to_child(root_child: &Self::ReturnedWidget) -> Self::Children {
+            root_child.clone()
+        }
+
+        fn factory_move_after(&self, widget: &Self::ReturnedWidget, other: &Self::ReturnedWidget) {
+            let mut pages = navigation_pages(self);
+            pages.retain(|page| page != widget);
+            // Fall back to moving to the end if `other` isn't part of the stack.
+            let new_position = pages
+                .iter()
+                .position(|page| page == other)
+                .map_or(pages.len(), |position| position + 1);
+            pages.insert(new_position, widget.clone());
+            self.replace(&pages);
+        }
+
+        fn factory_move_start(&self, widget: &Self::ReturnedWidget) {
+            let mut pages = navigation_pages(self);
+            pages.retain(|page| page != widget);
+            pages.insert(0, widget.clone());
+            self.replace(&pages);
+        }
+    }
 }
@@ -1,3 +1,5 @@
+use gtk::prelude::WidgetExt;
+
 use crate::factory::FactoryView;
 
 impl FactoryView for panel::Paned {
@@ -40,4 +42,8 @@ impl FactoryView for panel::Paned {
     fn factory_move_start(&self, widget: &gtk::Widget) {
         self.insert(0, widget);
     }
+
+    fn factory_set_visible(&self, widget: &gtk::Widget, visible: bool) {
+        widget.set_visible(visible);
+    }
 }
@@ -1,5 +1,8 @@
 use crate::{
-    factory::{positions::GridPosition, FactoryView},
+    factory::{
+        positions::{FixedPosition, GridPosition, OverlayPosition},
+        FactoryView,
+    },
     RelmIterChildrenExt, WidgetRef,
 };
 use gtk::prelude::{FlowBoxChildExt, ListBoxRowExt};
@@ -127,6 +130,65 @@ fn grid_factory_view() {
     assert_children!(grid: None);
 }
 
+#[gtk::test]
+fn fixed_factory_view() {
+    use gtk::prelude::FixedExt;
+
+    let fixed = gtk::Fixed::default();
+
+    let widget1 = gtk::Label::default();
+    let widget2 = gtk::Switch::default();
+
+    let w1 = fixed.factory_append(&widget1, &FixedPosition { x: 0.0, y: 0.0 });
+    let w2 = fixed.factory_append(&widget2, &FixedPosition { x: 10.0, y: 20.0 });
+
+    assert_eq!(widget1, w1);
+    assert_eq!(widget2, w2);
+
+    assert_children!(fixed: w1, w2);
+    assert_eq!(fixed.child_position(&w2), (10.0, 20.0));
+
+    fixed.factory_update_position(&w2, &FixedPosition { x: 30.0, y: 40.0 });
+    assert_eq!(fixed.child_position(&w2), (30.0, 40.0));
+
+    fixed.factory_remove(&w1);
+    assert_children!(fixed: w2);
+
+    fixed.factory_remove(&w2);
+    assert_children!(fixed: None);
+}
+
+#[gtk::test]
+fn overlay_factory_view() {
+    let overlay = gtk::Overlay::default();
+    let main_child = gtk::Label::default();
+    overlay.set_child(Some(&main_child));
+
+    let widget1 = gtk::Label::default();
+    let widget2 = gtk::Switch::default();
+    let widget3 = gtk::Entry::default();
+
+    let position = OverlayPosition::default();
+    let w2 = overlay.factory_append(&widget2, &position);
+    let w3 = overlay.factory_insert_after(&widget3, &position, &w2);
+    let w1 = overlay.factory_prepend(&widget1, &position);
+
+    assert_children!(overlay: main_child, w1, w2, w3);
+
+    overlay.factory_move_after(&w1, &w3);
+    assert_children!(overlay: main_child, w2, w3, w1);
+
+    overlay.factory_move_start(&w1);
+    assert_children!(overlay: main_child, w1, w2, w3);
+
+    overlay.factory_remove(&w2);
+    assert_children!(overlay: main_child, w1, w3);
+
+    overlay.factory_remove(&w1);
+    overlay.factory_remove(&w3);
+    assert_children!(overlay: main_child);
+}
+
 #[gtk::test]
 fn stack_factory_view() {
     let stack = gtk::Stack::default();
@@ -149,6 +211,15 @@ fn stack_factory_view() {
 
     assert_children!(stack: widget1, widget2, widget3);
 
+    page2.set_name("page2");
+    page2.set_title("Page 2");
+    page2.set_icon_name("document-open");
+    assert_eq!(
+        stack.child_by_name("page2").as_ref(),
+        Some(widget2.as_ref())
+    );
+    assert_eq!(page2.title().as_deref(), Some("Page 2"));
+
     stack.factory_remove(&page2);
     assert_children!(stack: widget1, widget3);
 
@@ -159,6 +230,52 @@ fn stack_factory_view() {
     assert_children!(stack: None);
 }
 
+#[gtk::test]
+fn notebook_factory_view() {
+    let notebook = gtk::Notebook::default();
+
+    let widget1 = gtk::Label::default();
+    let widget2 = gtk::Switch::default();
+    let widget3 = gtk::Entry::default();
+
+    let pages = |notebook: &gtk::Notebook| -> Vec<gtk::Widget> {
+        (0..notebook.n_pages())
+            .map(|i| notebook.nth_page(Some(i)).unwrap())
+            .collect()
+    };
+
+    let page2 = notebook.factory_append(&widget2, &());
+    let page3 = notebook.factory_insert_after(&widget3, &(), &page2);
+    let page1 = notebook.factory_prepend(&widget1, &());
+
+    assert_eq!(page1.child(), widget1);
+    assert_eq!(page2.child(), widget2);
+    assert_eq!(page3.child(), widget3);
+
+    assert_eq!(gtk::Notebook::returned_widget_to_child(&page1), widget1);
+    assert_eq!(gtk::Notebook::returned_widget_to_child(&page2), widget2);
+    assert_eq!(gtk::Notebook::returned_widget_to_child(&page3), widget3);
+
+    let (w1, w2, w3) = (page1.child(), page2.child(), page3.child());
+    assert_eq!(pages(&notebook), [w1.clone(), w2.clone(), w3.clone()]);
+
+    notebook.factory_move_after(&page3, &page1);
+    assert_eq!(pages(&notebook), [w1.clone(), w3.clone(), w2.clone()]);
+
+    notebook.factory_move_after(&page1, &page2);
+    assert_eq!(pages(&notebook), [w3.clone(), w2.clone(), w1.clone()]);
+
+    notebook.factory_move_start(&page2);
+    assert_eq!(pages(&notebook), [w2.clone(), w3.clone(), w1.clone()]);
+
+    notebook.factory_remove(&page3);
+    assert_eq!(pages(&notebook), [w2.clone(), w1.clone()]);
+
+    notebook.factory_remove(&page2);
+    notebook.factory_remove(&page1);
+    assert_eq!(notebook.n_pages(), 0);
+}
+
 #[gtk::test]
 fn list_box_factory_view() {
     let list_box = gtk::ListBox::default();
@@ -278,4 +395,57 @@ fn tab_view_factory_view() {
 
     tab_view.factory_move_start(&page2);
     assert_children!(tab_view: widget2, widget3, widget1);
+
+    tab_view.set_page_pinned(&page3, true);
+    let widget4 = gtk::Label::default();
+    let page4 = tab_view.factory_insert_after(&widget4, &(), &page3);
+    assert_eq!(tab_view.page_position(&page4), 1);
+
+    tab_view.factory_remove(&page3);
+    assert_eq!(tab_view.n_pages(), 3);
+    assert_eq!(tab_view.n_pinned_pages(), 0);
+}
+
+#[gtk::test]
+#[cfg(all(feature = "libadwaita", feature = "gnome_45"))]
+fn navigation_view_factory_view() {
+    use adw::prelude::*;
+
+    let navigation_view = adw::NavigationView::default();
+
+    let page1 = adw::NavigationPage::new(&gtk::Label::default(), "Page 1");
+    let page2 = adw::NavigationPage::new(&gtk::Label::default(), "Page 2");
+    let page3 = adw::NavigationPage::new(&gtk::Label::default(), "Page 3");
+
+    let navigation_pages = |view: &adw::NavigationView| -> Vec<adw::NavigationPage> {
+        let stack = view.navigation_stack();
+        (0..stack.n_items())
+            .map(|i| stack.item(i).unwrap().downcast().unwrap())
+            .collect()
+    };
+
+    let p2 = navigation_view.factory_append(&page2, &());
+    let p3 = navigation_view.factory_insert_after(&page3, &(), &p2);
+    let p1 = navigation_view.factory_prepend(&page1, &());
+
+    assert_eq!(adw::NavigationView::returned_widget_to_child(&p1), page1);
+    assert_eq!(
+        navigation_pages(&navigation_view),
+        [p1.clone(), p2.clone(), p3.clone()]
+    );
+
+    navigation_view.factory_move_after(&p3, &p1);
+    assert_eq!(
+        navigation_pages(&navigation_view),
+        [p1.clone(), p3.clone(), p2.clone()]
+    );
+
+    navigation_view.factory_move_start(&p2);
+    assert_eq!(
+        navigation_pages(&navigation_view),
+        [p2.clone(), p1.clone(), p3.clone()]
+    );
+
+    navigation_view.factory_remove(&p1);
+    assert_eq!(navigation_pages(&navigation_view), [p2.clone(), p3.clone()]);
 }
@@ -59,6 +59,12 @@ pub trait FactoryView: IsA<gtk::Widget> {
 
     /// Update the position inside positioned containers like [`gtk::Grid`].
     fn factory_update_position(&self, _widget: &Self::ReturnedWidget, _position: &Self::Position) {}
+
+    /// Show or hide a widget without removing it from the container.
+    ///
+    /// This is used for filtering factory elements.
+    /// Containers that can't hide their children ignore this.
+    fn factory_set_visible(&self, _widget: &Self::ReturnedWidget, _visible: bool) {}
 }
 
 /// Returns the position of an element inside a
@@ -32,6 +32,7 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 mod app;
+mod blocking_pool;
 mod channel;
 mod extensions;
 pub(crate) mod late_initialization;
@@ -48,16 +49,17 @@ pub mod typed_view;
 
 pub use channel::ComponentSender;
 pub use channel::*;
-pub use component::worker::{Worker, WorkerController, WorkerHandle};
+pub use component::worker::{Worker, WorkerController, WorkerHandle, WorkerPool, WorkerPoolHandle};
 pub use component::{
     Component, ComponentBuilder, ComponentController, ComponentParts, Controller, MessageBroker,
-    SimpleComponent,
+    MessageBus, SimpleComponent,
 };
 pub use extensions::*;
 pub use shared_state::{Reducer, Reducible, SharedState};
 pub use shutdown::ShutdownReceiver;
 
 pub use app::RelmApp;
+pub use blocking_pool::{BlockingPool, BlockingQueuePolicy};
 pub use tokio::task::JoinHandle;
 
 use gtk::prelude::{Cast, IsA};
@@ -180,12 +182,25 @@ where
 }
 
 static RUNTIME: Lazy<Runtime> = Lazy::new(|| {
-    tokio::runtime::Builder::new_multi_thread()
+    let pool = blocking_pool::BLOCKING_POOL.get_or_init(BlockingPool::new);
+    let max_blocking_threads = pool
+        .max_threads
+        .unwrap_or_else(|| *RELM_BLOCKING_THREADS.get_or_init(|| 512));
+
+    let mut builder = tokio::runtime::Builder::new_multi_thread();
+    builder
         .enable_all()
         .worker_threads(*RELM_THREADS.get_or_init(|| 1))
-        .max_blocking_threads(*RELM_BLOCKING_THREADS.get_or_init(|| 512))
-        .build()
-        .unwrap()
+        .max_blocking_threads(max_blocking_threads);
+
+    if let Some(name) = &pool.thread_name {
+        builder.thread_name(name);
+    }
+    if let Some(keep_alive) = pool.keep_alive {
+        builder.thread_keep_alive(keep_alive);
+    }
+
+    builder.build().unwrap()
 });
 
 /// Spawns a [`Send`]-able future to the shared component runtime.
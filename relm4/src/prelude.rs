@@ -9,8 +9,8 @@ pub use crate::component::{
 };
 pub use crate::factory::{DynamicIndex, FactoryComponent, FactorySender};
 pub use crate::{
-    Component, ComponentController, ComponentParts, ComponentSender, Controller, RelmApp,
-    RelmWidgetExt, SimpleComponent, WidgetRef, WidgetTemplate,
+    Component, ComponentController, ComponentParts, ComponentSender, Controller, RelmAccessibleExt,
+    RelmApp, RelmWidgetExt, SimpleComponent, WidgetRef, WidgetTemplate,
 };
 
 #[cfg(feature = "libadwaita")]
@@ -1,7 +1,13 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::task::Poll;
+use std::time::Duration;
 
 use flume::r#async::RecvStream;
 use futures::{future::FusedFuture, pin_mut, Future, Stream};
+use gtk::glib;
 use once_cell::sync::Lazy;
 use std::sync::Mutex;
 use tokio::sync::mpsc;
@@ -12,19 +18,120 @@ use crate::{
 };
 
 /// Stores the shutdown senders of all components ever created during
-/// the runtime of the application.
-static SHUTDOWN_SENDERS: Lazy<Mutex<Vec<mpsc::Sender<()>>>> = Lazy::new(Mutex::default);
+/// the runtime of the application, together with a flag that
+/// marks their shutdown as pending.
+///
+/// Workers have no flag, because their runtime doesn't run on the main context.
+static SHUTDOWN_SENDERS: Lazy<Mutex<Vec<(mpsc::Sender<()>, Option<Arc<AtomicBool>>)>>> =
+    Lazy::new(Mutex::default);
 
 /// On application shutdown, components won't trigger their shutdown
 /// method automatically, so we make sure they are shutdown by sending
 /// a shutdown message to all components.
+///
+/// Every component on the main context that receives the message
+/// is counted as pending right away, so [`wait_for_shutdown_hooks`] also
+/// waits for components whose runtime hasn't handled the message yet.
 pub(crate) fn shutdown_all() {
     let mut guard = SHUTDOWN_SENDERS.lock().unwrap();
-    for sender in guard.drain(..) {
-        sender.blocking_send(()).ok();
+    for (sender, pending) in guard.drain(..) {
+        if sender.blocking_send(()).is_ok() {
+            if let Some(pending) = pending {
+                if !pending.swap(true, Ordering::SeqCst) {
+                    PENDING_SHUTDOWN_HOOKS.fetch_add(1, Ordering::SeqCst);
+                }
+            }
+        }
+    }
+}
+
+/// The default deadline for asynchronous shutdown hooks of components.
+pub(crate) const DEFAULT_SHUTDOWN_DEADLINE: Duration = Duration::from_secs(5);
+
+/// The maximum time [`wait_for_shutdown_hooks`] runs the main loop.
+const SHUTDOWN_WAIT_DEADLINE: Duration = Duration::from_secs(10);
+
+/// The number of asynchronous shutdown hooks that are still running
+/// and of components that were shut down by [`shutdown_all`]
+/// but whose runtime hasn't finished yet.
+static PENDING_SHUTDOWN_HOOKS: AtomicUsize = AtomicUsize::new(0);
+
+/// Runs an asynchronous shutdown hook until it completes or the deadline is exceeded.
+pub(crate) async fn run_shutdown_hook<F>(hook: F, deadline: Duration, component: &str)
+where
+    F: Future<Output = ()>,
+{
+    PENDING_SHUTDOWN_HOOKS.fetch_add(1, Ordering::SeqCst);
+
+    let hook = futures::FutureExt::fuse(hook);
+    let timeout = futures::FutureExt::fuse(glib::timeout_future(deadline));
+    pin_mut!(hook, timeout);
+    futures::select!(
+        () = hook => {},
+        () = timeout => {
+            tracing::warn!("Asynchronous shutdown of component {component} exceeded its deadline");
+        }
+    );
+
+    PENDING_SHUTDOWN_HOOKS.fetch_sub(1, Ordering::SeqCst);
+}
+
+/// Runs the main loop until all asynchronous shutdown hooks have finished
+/// or [`SHUTDOWN_WAIT_DEADLINE`] is exceeded.
+pub(crate) fn wait_for_shutdown_hooks() {
+    let context = glib::MainContext::ref_thread_default();
+
+    // Wakes up the main loop once the deadline is exceeded.
+    let expired = Rc::new(Cell::new(false));
+    let timeout = glib::timeout_add_local_once(SHUTDOWN_WAIT_DEADLINE, {
+        let expired = expired.clone();
+        move || expired.set(true)
+    });
+
+    while PENDING_SHUTDOWN_HOOKS.load(Ordering::SeqCst) > 0 && !expired.get() {
+        context.iteration(true);
+    }
+
+    if expired.get() {
+        tracing::warn!("Shutting down the application exceeded its deadline");
+    } else {
+        timeout.remove();
     }
 }
 
+/// Notifies the runtime once the root widget of a component is mapped.
+///
+/// If the root widget is already mapped, the notification is sent right away.
+/// Roots that aren't widgets are notified once the main loop becomes idle.
+pub(crate) fn schedule_post_init(root: Option<gtk::Widget>, sender: Sender<()>) {
+    use gtk::prelude::{ObjectExt, WidgetExt};
+
+    let Some(root) = root else {
+        glib::idle_add_local_once(move || {
+            sender.send(()).ok();
+        });
+        return;
+    };
+
+    if root.is_mapped() {
+        sender.send(()).ok();
+        return;
+    }
+
+    // Disconnects the handler after the first emission.
+    let handler = Rc::new(Cell::new(None));
+    let id = root.connect_map({
+        let handler = handler.clone();
+        move |root| {
+            sender.send(()).ok();
+            if let Some(id) = handler.take() {
+                root.disconnect(id);
+            }
+        }
+    });
+    handler.set(Some(id));
+}
+
 /// A type that destroys an [`AsyncComponent`](crate::async_component::AsyncComponent)
 /// as soon as it is dropped.
 #[derive(Debug)]
@@ -68,7 +175,20 @@ pub(super) struct RuntimeSenders<Output, Command> {
 }
 
 impl<Output, Command> RuntimeSenders<Output, Command> {
+    /// Creates the senders of a runtime on the main context.
     pub(super) fn new() -> Self {
+        Self::with_pending_flag(Some(Arc::default()))
+    }
+
+    /// Creates the senders of a worker runtime.
+    ///
+    /// Workers run on their own thread, so the application
+    /// doesn't wait for them once it shuts down.
+    pub(super) fn new_worker() -> Self {
+        Self::with_pending_flag(None)
+    }
+
+    fn with_pending_flag(pending: Option<Arc<AtomicBool>>) -> Self {
         // Used by this component to send events to be handled externally by the caller.
         let (output_sender, output_receiver) = crate::channel::<Output>();
 
@@ -86,10 +206,10 @@ impl<Output, Command> RuntimeSenders<Output, Command> {
         SHUTDOWN_SENDERS
             .lock()
             .unwrap()
-            .push(shutdown_event_sender.clone());
+            .push((shutdown_event_sender.clone(), pending.clone()));
 
         let shutdown_on_drop = ShutdownOnDrop::new(shutdown_event_sender);
-        let shutdown_event = ShutdownEvent::new(shutdown_event_receiver);
+        let shutdown_event = ShutdownEvent::new(shutdown_event_receiver, pending);
 
         Self {
             output_sender,
@@ -111,16 +231,35 @@ impl<Output, Command> RuntimeSenders<Output, Command> {
 /// If the runtime is detached, the receiver will resolve
 /// with an error, but this catches the error and returns
 /// [`Poll::Pending`] instead so the shutdown isn't triggered.
+///
+/// Dropping this type, which happens once the runtime finished,
+/// releases the pending shutdown registered by [`shutdown_all`].
 pub(super) struct ShutdownEvent {
     shutdown_receiver: mpsc::Receiver<()>,
     detached: bool,
+    pending: Option<Arc<AtomicBool>>,
 }
 
 impl ShutdownEvent {
-    fn new(shutdown_receiver: mpsc::Receiver<()>) -> Self {
+    fn new(shutdown_receiver: mpsc::Receiver<()>, pending: Option<Arc<AtomicBool>>) -> Self {
         Self {
             shutdown_receiver,
             detached: false,
+            pending,
+        }
+    }
+}
+
+impl Drop for ShutdownEvent {
+    fn drop(&mut self) {
+        // Prevents new shutdown messages from being counted as pending.
+        self.shutdown_receiver.close();
+        let released = self
+            .pending
+            .as_ref()
+            .map_or(false, |pending| pending.swap(false, Ordering::SeqCst));
+        if released {
+            PENDING_SHUTDOWN_HOOKS.fetch_sub(1, Ordering::SeqCst);
         }
     }
 }
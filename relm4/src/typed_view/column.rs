@@ -1,16 +1,27 @@
 //! Idiomatic and high-level abstraction over [`gtk::ColumnView`].
 
-use super::{get_mut_value, get_value, Filter, OrdFn, RelmSelectionExt, TypedListItem};
+use super::{
+    get_mut_value, get_value, paged, Filter, OrdFn, PagedLoader, RelmSelectionExt, SearchFilter,
+    SelectionBehavior, TypedListItem,
+};
+use crate::{RelmRemoveAllExt, Sender};
 use gtk::{
     gio, glib,
-    prelude::{Cast, CastNone, IsA, ListItemExt, ListModelExt, ObjectExt},
+    prelude::{
+        BoxExt, ButtonExt, Cast, CastNone, CheckButtonExt, EditableExt, IsA, ListItemExt,
+        ListModelExt, ObjectExt, PopoverExt, WidgetExt,
+    },
 };
 use std::{
     any::Any,
+    cell::Cell,
     cmp::Ordering,
     collections::HashMap,
     fmt::{Debug, Display},
+    future::Future,
     marker::PhantomData,
+    ops::Range,
+    rc::Rc,
 };
 
 /// An item of a [`TypedColumnView`].
@@ -107,6 +118,162 @@ where
     }
 }
 
+/// A widget that edits the cells of an [`EditableColumn`].
+pub trait CellEditor: 'static {
+    /// The widget that edits a cell.
+    type Widget: IsA<gtk::Widget>;
+    /// The value that is edited.
+    type Value: PartialEq;
+
+    /// Construct the widget.
+    fn create() -> Self::Widget;
+
+    /// Show `value` in the widget.
+    fn set_value(widget: &Self::Widget, value: &Self::Value);
+
+    /// Call `f` with the new value whenever the user edited the value.
+    fn connect_edited<F: Fn(Self::Value) + 'static>(
+        widget: &Self::Widget,
+        f: F,
+    ) -> glib::SignalHandlerId;
+}
+
+/// Edits text with a [`gtk::EditableLabel`].
+///
+/// The new text is reported once the user stops editing.
+#[derive(Debug)]
+pub struct EntryEditor;
+
+impl CellEditor for EntryEditor {
+    type Widget = gtk::EditableLabel;
+    type Value = String;
+
+    fn create() -> Self::Widget {
+        gtk::EditableLabel::new("")
+    }
+
+    fn set_value(widget: &Self::Widget, value: &Self::Value) {
+        widget.set_text(value);
+    }
+
+    fn connect_edited<F: Fn(Self::Value) + 'static>(
+        widget: &Self::Widget,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        widget.connect_editing_notify(move |label| {
+            if !label.is_editing() {
+                f(label.text().into());
+            }
+        })
+    }
+}
+
+/// Edits numbers with a [`gtk::SpinButton`].
+///
+/// The range of the spin button can be changed in [`EditableColumn::setup_editor()`].
+#[derive(Debug)]
+pub struct SpinEditor;
+
+impl CellEditor for SpinEditor {
+    type Widget = gtk::SpinButton;
+    type Value = f64;
+
+    fn create() -> Self::Widget {
+        gtk::SpinButton::with_range(0.0, 100.0, 1.0)
+    }
+
+    fn set_value(widget: &Self::Widget, value: &Self::Value) {
+        widget.set_value(*value);
+    }
+
+    fn connect_edited<F: Fn(Self::Value) + 'static>(
+        widget: &Self::Widget,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        widget.connect_value_changed(move |spin_button| f(spin_button.value()))
+    }
+}
+
+/// Edits booleans with a [`gtk::CheckButton`].
+#[derive(Debug)]
+pub struct CheckEditor;
+
+impl CellEditor for CheckEditor {
+    type Widget = gtk::CheckButton;
+    type Value = bool;
+
+    fn create() -> Self::Widget {
+        gtk::CheckButton::new()
+    }
+
+    fn set_value(widget: &Self::Widget, value: &Self::Value) {
+        widget.set_active(*value);
+    }
+
+    fn connect_edited<F: Fn(Self::Value) + 'static>(
+        widget: &Self::Widget,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        widget.connect_toggled(move |check_button| f(check_button.is_active()))
+    }
+}
+
+/// Simplified trait for creating columns whose cells can be edited by the user.
+///
+/// Editable columns are added with [`TypedColumnView::append_editable_column()`].
+pub trait EditableColumn: 'static {
+    /// Item of the model
+    type Item: Any;
+    /// Widget that edits the cells, for example [`EntryEditor`],
+    /// [`SpinEditor`] or [`CheckEditor`].
+    type Editor: CellEditor;
+
+    /// Name of the column
+    const COLUMN_NAME: &'static str;
+    /// Whether to enable resizing for this column
+    const ENABLE_RESIZE: bool = false;
+    /// Whether to enable automatic expanding for this column
+    const ENABLE_EXPAND: bool = false;
+
+    /// Get the value that this column represents.
+    fn get_cell_value(item: &Self::Item) -> <Self::Editor as CellEditor>::Value;
+
+    /// Configure the editor widget after it was created.
+    fn setup_editor(_editor: &<Self::Editor as CellEditor>::Widget) {}
+
+    /// Sorter for column.
+    #[must_use]
+    fn sort_fn() -> OrdFn<Self::Item> {
+        None
+    }
+}
+
+/// The sorting of a [`TypedColumnView`], which can be saved to restore it later.
+///
+/// With the `serde` feature, this type implements `Serialize` and `Deserialize`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SortState {
+    /// The name of the column that sorts the items, see [`RelmColumn::COLUMN_NAME`].
+    pub column: String,
+    /// Whether the items are sorted in descending order.
+    pub descending: bool,
+}
+
+/// The state of a column of a [`TypedColumnView`], which can be saved to restore it later.
+///
+/// With the `serde` feature, this type implements `Serialize` and `Deserialize`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColumnState {
+    /// The name of the column, see [`RelmColumn::COLUMN_NAME`].
+    pub name: String,
+    /// Whether the column is shown.
+    pub visible: bool,
+    /// The width the user resized the column to.
+    pub fixed_width: Option<i32>,
+}
+
 /// A high-level wrapper around [`gio::ListStore`],
 /// [`gtk::SignalListItemFactory`] and [`gtk::ColumnView`].
 ///
@@ -226,6 +393,12 @@ where
         }
 
         factory.connect_bind(move |_, list_item| {
+            if let Some(obj) = list_item
+                .downcast_ref::<gtk::ListItem>()
+                .and_then(|list_item| list_item.item())
+            {
+                paged::request_page(&obj);
+            }
             modify_widgets::<T, C>(list_item.upcast_ref(), |obj, widgets, root| {
                 C::bind(obj, widgets, root);
             });
@@ -245,11 +418,110 @@ where
             C::teardown(list_item);
         });
 
-        let sort_fn = C::sort_fn();
+        self.insert_column(
+            C::COLUMN_NAME,
+            &factory,
+            C::ENABLE_RESIZE,
+            C::ENABLE_EXPAND,
+            C::sort_fn(),
+        );
+    }
+
+    /// Append a column whose cells can be edited.
+    ///
+    /// Whenever the user edited a cell, `f` is called with the item of the row
+    /// and the new value. The resulting message is sent to `sender`.
+    /// The item isn't changed automatically, so you usually want to
+    /// update it when handling the message:
+    ///
+    /// ```ignore
+    /// Msg::Rename(item, name) => {
+    ///     item.borrow_mut().name = name;
+    /// }
+    /// ```
+    pub fn append_editable_column<C, F, Msg>(&mut self, sender: &Sender<Msg>, f: F)
+    where
+        C: EditableColumn<Item = T>,
+        F: Fn(TypedListItem<T>, <C::Editor as CellEditor>::Value) -> Msg + 'static,
+        Msg: 'static,
+    {
+        let factory = gtk::SignalListItemFactory::new();
+        factory.connect_setup(move |_, list_item| {
+            let list_item = list_item
+                .downcast_ref::<gtk::ListItem>()
+                .expect("Needs to be ListItem");
+
+            let editor = C::Editor::create();
+            C::setup_editor(&editor);
+            list_item.set_child(Some(&editor));
+        });
+
+        let sender = sender.clone();
+        let f = Rc::new(f);
+        factory.connect_bind(move |_, list_item| {
+            let list_item = list_item
+                .downcast_ref::<gtk::ListItem>()
+                .expect("Needs to be ListItem");
+
+            let editor = list_item
+                .child()
+                .and_downcast::<<C::Editor as CellEditor>::Widget>()
+                .unwrap();
+            let obj = list_item
+                .item()
+                .and_downcast::<glib::BoxedAnyObject>()
+                .unwrap();
+            paged::request_page(obj.upcast_ref());
+
+            // Show the value before connecting to avoid reporting it as edit.
+            C::Editor::set_value(&editor, &C::get_cell_value(&obj.borrow::<T>()));
+
+            let sender = sender.clone();
+            let f = f.clone();
+            let handler = C::Editor::connect_edited(&editor, move |value| {
+                let item = TypedListItem::<T>::new(obj.clone());
+                let changed = value != C::get_cell_value(&item.borrow());
+                if changed {
+                    sender.emit(f(item, value));
+                }
+            });
+            unsafe { editor.set_data("handler", handler) };
+        });
+
+        factory.connect_unbind(move |_, list_item| {
+            let list_item = list_item
+                .downcast_ref::<gtk::ListItem>()
+                .expect("Needs to be ListItem");
+
+            if let Some(editor) = list_item.child() {
+                if let Some(handler) =
+                    unsafe { editor.steal_data::<glib::SignalHandlerId>("handler") }
+                {
+                    editor.disconnect(handler);
+                }
+            }
+        });
+
+        self.insert_column(
+            C::COLUMN_NAME,
+            &factory,
+            C::ENABLE_RESIZE,
+            C::ENABLE_EXPAND,
+            C::sort_fn(),
+        );
+    }
 
-        let c = gtk::ColumnViewColumn::new(Some(C::COLUMN_NAME), Some(factory));
-        c.set_resizable(C::ENABLE_RESIZE);
-        c.set_expand(C::ENABLE_EXPAND);
+    fn insert_column(
+        &mut self,
+        name: &'static str,
+        factory: &gtk::SignalListItemFactory,
+        resizable: bool,
+        expand: bool,
+        sort_fn: OrdFn<T>,
+    ) {
+        let c = gtk::ColumnViewColumn::new(Some(name), Some(factory.clone()));
+        c.set_resizable(resizable);
+        c.set_expand(expand);
 
         if let Some(sort_fn) = sort_fn {
             c.set_sorter(Some(&gtk::CustomSorter::new(move |first, second| {
@@ -261,7 +533,158 @@ where
         }
 
         self.view.append_column(&c);
-        self.columns.insert(C::COLUMN_NAME, c);
+        self.columns.insert(name, c);
+    }
+
+    /// Returns the current sorting of the view.
+    ///
+    /// Returns [`None`] if the items aren't sorted by any column.
+    #[cfg(feature = "gnome_44")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "gnome_44")))]
+    pub fn sort_state(&self) -> Option<SortState> {
+        let sorter = self
+            .view
+            .sorter()?
+            .downcast::<gtk::ColumnViewSorter>()
+            .ok()?;
+        let sort_column = sorter.primary_sort_column()?;
+
+        self.columns
+            .iter()
+            .find(|(_, column)| **column == sort_column)
+            .map(|(name, _)| SortState {
+                column: (*name).to_owned(),
+                descending: sorter.primary_sort_order() == gtk::SortType::Descending,
+            })
+    }
+
+    /// Sorts the view like described by `state`, for example
+    /// to restore the sorting of [`sort_state()`](Self::sort_state) after a restart.
+    ///
+    /// If `state` is [`None`], the items are no longer sorted.
+    /// Returns `false` if no column with the given name was appended.
+    pub fn set_sort_state(&self, state: Option<&SortState>) -> bool {
+        if let Some(state) = state {
+            if let Some(column) = self.columns.get(state.column.as_str()) {
+                let order = if state.descending {
+                    gtk::SortType::Descending
+                } else {
+                    gtk::SortType::Ascending
+                };
+                self.view.sort_by_column(Some(column), order);
+                true
+            } else {
+                false
+            }
+        } else {
+            self.view
+                .sort_by_column(None::<&gtk::ColumnViewColumn>, gtk::SortType::Ascending);
+            true
+        }
+    }
+
+    /// Show or hide a column.
+    ///
+    /// Returns `false` if no column with the given name was appended.
+    pub fn set_column_visible(&self, name: &str, visible: bool) -> bool {
+        if let Some(column) = self.columns.get(name) {
+            column.set_visible(visible);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns whether a column is shown or [`None`]
+    /// if no column with the given name was appended.
+    #[must_use]
+    pub fn is_column_visible(&self, name: &str) -> Option<bool> {
+        self.columns
+            .get(name)
+            .map(gtk::ColumnViewColumn::is_visible)
+    }
+
+    /// Move a column to a new position.
+    ///
+    /// Returns `false` if no column with the given name was appended.
+    pub fn move_column(&self, name: &str, position: u32) -> bool {
+        if let Some(column) = self.columns.get(name) {
+            self.view.insert_column(position, column);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the state of all columns in the order they are shown,
+    /// for example to restore the layout with
+    /// [`set_column_layout()`](Self::set_column_layout) after a restart.
+    #[must_use]
+    pub fn column_layout(&self) -> Vec<ColumnState> {
+        view_columns(&self.view)
+            .into_iter()
+            .filter_map(|column| {
+                let (name, _) = self.columns.iter().find(|(_, c)| **c == column)?;
+                let fixed_width = column.fixed_width();
+                Some(ColumnState {
+                    name: (*name).to_owned(),
+                    visible: column.is_visible(),
+                    fixed_width: (fixed_width >= 0).then_some(fixed_width),
+                })
+            })
+            .collect()
+    }
+
+    /// Orders, shows and hides the columns like described by `layout`.
+    ///
+    /// Columns that aren't part of the layout are placed after the other columns
+    /// and states of columns that weren't appended are ignored.
+    pub fn set_column_layout(&self, layout: &[ColumnState]) {
+        let mut position = 0;
+        for state in layout {
+            if let Some(column) = self.columns.get(state.name.as_str()) {
+                self.view.insert_column(position, column);
+                column.set_visible(state.visible);
+                column.set_fixed_width(state.fixed_width.unwrap_or(-1));
+                position += 1;
+            }
+        }
+    }
+
+    /// Creates a popover that lets users show, hide and reorder the columns.
+    ///
+    /// The popover is usually added to a [`gtk::MenuButton`]:
+    ///
+    /// ```ignore
+    /// menu_button.set_popover(Some(&view_wrapper.column_popover()));
+    /// ```
+    #[must_use]
+    pub fn column_popover(&self) -> gtk::Popover {
+        let list = gtk::Box::new(gtk::Orientation::Vertical, 5);
+        fill_column_list(&list, &self.view);
+
+        // Update the list whenever the columns are reordered.
+        let weak_list = list.downgrade();
+        let weak_view = self.view.downgrade();
+        let columns = self.view.columns();
+        let handler = columns.connect_items_changed(move |_, _, _, _| {
+            if let (Some(list), Some(view)) = (weak_list.upgrade(), weak_view.upgrade()) {
+                fill_column_list(&list, &view);
+            }
+        });
+
+        let popover = gtk::Popover::new();
+        popover.set_child(Some(&list));
+
+        // Stop updating the list once the popover is gone.
+        let handler = Cell::new(Some(handler));
+        popover.connect_destroy(move |_| {
+            if let Some(handler) = handler.take() {
+                columns.disconnect(handler);
+            }
+        });
+
+        popover
     }
 
     /// Add a function to filter the stored items.
@@ -273,6 +696,34 @@ where
             let value = get_value::<T>(obj);
             f(&value)
         });
+        self.push_filter(filter);
+    }
+
+    /// Add a filter that matches the stored items against a search query.
+    /// The returned [`SearchFilter`] is used to update the query.
+    ///
+    /// Like other filters, it can be toggled with
+    /// [`set_filter_status()`](Self::set_filter_status).
+    pub fn add_search_filter<F: Fn(&T, &str) -> bool + 'static>(&mut self, f: F) -> SearchFilter {
+        let search = SearchFilter::new(f);
+        self.push_filter(search.filter().clone());
+        search
+    }
+
+    /// Add a search filter whose query is the text of `entry`.
+    ///
+    /// See [`add_search_filter()`](Self::add_search_filter) for details.
+    pub fn bind_search_entry<F: Fn(&T, &str) -> bool + 'static>(
+        &mut self,
+        entry: &gtk::SearchEntry,
+        f: F,
+    ) -> SearchFilter {
+        let search = self.add_search_filter(f);
+        search.bind_search_entry(entry);
+        search
+    }
+
+    fn push_filter(&mut self, filter: gtk::CustomFilter) {
         let filter_model =
             gtk::FilterListModel::new(Some(self.active_model.clone()), Some(filter.clone()));
         self.active_model = filter_model.clone().upcast();
@@ -288,6 +739,27 @@ where
         &self.columns
     }
 
+    /// Replace all items with placeholder items and load the
+    /// actual items in pages of `page_size` items from `fetch`.
+    ///
+    /// `placeholder` creates the placeholder item at a given position.
+    /// `fetch` is called with the range of positions of a page
+    /// once one of its placeholder items is shown. See [`PagedLoader`] for details.
+    pub fn load_paged<P, F, Fut>(
+        &mut self,
+        len: u32,
+        page_size: u32,
+        placeholder: P,
+        fetch: F,
+    ) -> PagedLoader<T>
+    where
+        P: Fn(u32) -> T + 'static,
+        F: Fn(Range<u32>) -> Fut + 'static,
+        Fut: Future<Output = Vec<T>> + 'static,
+    {
+        PagedLoader::new(self.store.clone(), len, page_size, placeholder, fetch)
+    }
+
     /// Returns the amount of filters that were added.
     pub fn filters_len(&self) -> usize {
         self.filters.len()
@@ -413,3 +885,84 @@ where
         self.store.remove_all();
     }
 }
+
+impl<T> TypedColumnView<T, gtk::SelectionModel>
+where
+    T: Any,
+{
+    /// Returns the current selection behavior.
+    #[must_use]
+    pub fn selection_behavior(&self) -> SelectionBehavior {
+        SelectionBehavior::of(&self.selection_model).unwrap_or(SelectionBehavior::Single)
+    }
+
+    /// Switch between no, single and multi selection.
+    ///
+    /// The selected items are kept as far as possible. This replaces
+    /// [`selection_model`](Self::selection_model), so signal handlers
+    /// connected to the old selection model need to be connected again.
+    pub fn set_selection_behavior(&mut self, behavior: SelectionBehavior) {
+        if self.selection_behavior() != behavior {
+            self.selection_model = behavior.switch(&self.selection_model, &self.active_model);
+            self.view.set_model(Some(&self.selection_model));
+        }
+    }
+}
+
+/// Returns the columns of a column view in the order they are shown.
+fn view_columns(view: &gtk::ColumnView) -> Vec<gtk::ColumnViewColumn> {
+    let columns = view.columns();
+    (0..columns.n_items())
+        .filter_map(|position| columns.item(position).and_downcast())
+        .collect()
+}
+
+/// Adds a row for each column of `view` to the list of [`TypedColumnView::column_popover`].
+fn fill_column_list(list: &gtk::Box, view: &gtk::ColumnView) {
+    list.remove_all();
+
+    let columns = view_columns(view);
+    let last = columns.len().saturating_sub(1);
+
+    for (position, column) in columns.into_iter().enumerate() {
+        let row = gtk::Box::new(gtk::Orientation::Horizontal, 5);
+
+        let title = column.title().unwrap_or_default();
+        let check_button = gtk::CheckButton::with_label(&title);
+        check_button.set_hexpand(true);
+        column
+            .bind_property("visible", &check_button, "active")
+            .bidirectional()
+            .sync_create()
+            .build();
+        row.append(&check_button);
+
+        let position = position as u32;
+        let up_button = gtk::Button::from_icon_name("go-up-symbolic");
+        up_button.set_sensitive(position > 0);
+        {
+            let view = view.downgrade();
+            let column = column.clone();
+            up_button.connect_clicked(move |_| {
+                if let Some(view) = view.upgrade() {
+                    view.insert_column(position - 1, &column);
+                }
+            });
+        }
+        row.append(&up_button);
+
+        let down_button = gtk::Button::from_icon_name("go-down-symbolic");
+        down_button.set_sensitive((position as usize) < last);
+        {
+            let view = view.downgrade();
+            down_button.connect_clicked(move |_| {
+                if let Some(view) = view.upgrade() {
+                    view.insert_column(position + 1, &column);
+                }
+            });
+        }
+        row.append(&down_button);
+
+        list.append(&row);
+    }
+}
@@ -1,11 +1,32 @@
 //! Idiomatic and high-level abstraction over [`gtk::GridView`].
 
-use super::{get_mut_value, get_value, Filter, OrdFn, RelmSelectionExt, TypedListItem};
+use super::{
+    get_mut_value, get_value, paged, Filter, OrdFn, PagedLoader, RelmSelectionExt, SearchFilter,
+    SelectionBehavior, TypedListItem,
+};
 use gtk::{
     gio, glib,
-    prelude::{Cast, CastNone, IsA, ListItemExt, ListModelExt, ObjectExt},
+    prelude::{Cast, CastNone, IsA, ListItemExt, ListModelExt, ObjectExt, WidgetExt},
+};
+use std::{any::Any, cmp::Ordering, future::Future, marker::PhantomData, ops::Range};
+
+#[cfg(feature = "gnome_43")]
+use {
+    crate::{RelmWidgetExt, Sender},
+    gtk::{
+        gdk,
+        prelude::{StaticType, ToValue},
+    },
+    std::rc::Rc,
 };
-use std::{any::Any, cmp::Ordering, marker::PhantomData};
+
+/// CSS class of grid items while an item is dragged over their first half.
+#[cfg(feature = "gnome_43")]
+const DROP_BEFORE_CLASS: &str = "drop-before";
+
+/// CSS class of grid items while an item is dragged over their second half.
+#[cfg(feature = "gnome_43")]
+const DROP_AFTER_CLASS: &str = "drop-after";
 
 /// An item of a [`TypedGridView`].
 pub trait RelmGridItem: Any {
@@ -118,6 +139,7 @@ where
                 .child();
 
             let obj = list_item.item().unwrap();
+            paged::request_page(&obj);
             let mut obj = get_mut_value::<T>(&obj);
 
             let mut root = widget.and_downcast::<T::Root>().unwrap();
@@ -196,6 +218,34 @@ where
             let value = get_value::<T>(obj);
             f(&value)
         });
+        self.push_filter(filter);
+    }
+
+    /// Add a filter that matches the stored items against a search query.
+    /// The returned [`SearchFilter`] is used to update the query.
+    ///
+    /// Like other filters, it can be toggled with
+    /// [`set_filter_status()`](Self::set_filter_status).
+    pub fn add_search_filter<F: Fn(&T, &str) -> bool + 'static>(&mut self, f: F) -> SearchFilter {
+        let search = SearchFilter::new(f);
+        self.push_filter(search.filter().clone());
+        search
+    }
+
+    /// Add a search filter whose query is the text of `entry`.
+    ///
+    /// See [`add_search_filter()`](Self::add_search_filter) for details.
+    pub fn bind_search_entry<F: Fn(&T, &str) -> bool + 'static>(
+        &mut self,
+        entry: &gtk::SearchEntry,
+        f: F,
+    ) -> SearchFilter {
+        let search = self.add_search_filter(f);
+        search.bind_search_entry(entry);
+        search
+    }
+
+    fn push_filter(&mut self, filter: gtk::CustomFilter) {
         let filter_model =
             gtk::FilterListModel::new(Some(self.active_model.clone()), Some(filter.clone()));
         self.active_model = filter_model.clone().upcast();
@@ -206,6 +256,27 @@ where
         });
     }
 
+    /// Replace all items with placeholder items and load the
+    /// actual items in pages of `page_size` items from `fetch`.
+    ///
+    /// `placeholder` creates the placeholder item at a given position.
+    /// `fetch` is called with the range of positions of a page
+    /// once one of its placeholder items is shown. See [`PagedLoader`] for details.
+    pub fn load_paged<P, F, Fut>(
+        &mut self,
+        len: u32,
+        page_size: u32,
+        placeholder: P,
+        fetch: F,
+    ) -> PagedLoader<T>
+    where
+        P: Fn(u32) -> T + 'static,
+        F: Fn(Range<u32>) -> Fut + 'static,
+        Fut: Future<Output = Vec<T>> + 'static,
+    {
+        PagedLoader::new(self.store.clone(), len, page_size, placeholder, fetch)
+    }
+
     /// Returns the amount of filters that were added.
     pub fn filters_len(&self) -> usize {
         self.filters.len()
@@ -331,4 +402,186 @@ where
     pub fn clear(&mut self) {
         self.store.remove_all();
     }
+
+    #[cfg(feature = "gnome_43")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "gnome_43")))]
+    /// Move an item of this list to a new position.
+    ///
+    /// The position refers to the list without filters and sorting,
+    /// before the item is removed from its old position.
+    /// Returns `false` if the item isn't part of this list.
+    pub fn move_item(&mut self, item: &TypedListItem<T>, position: u32) -> bool {
+        if let Some(old_position) = self.store.find(&item.inner) {
+            self.store.remove(old_position);
+            let position = if position > old_position {
+                position - 1
+            } else {
+                position
+            };
+            self.store
+                .insert(position.min(self.store.n_items()), &item.inner);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Allows users to drag items out of the grid and to drop items onto it.
+    ///
+    /// Every grid item gets a drag source and a drop target.
+    /// When an item is dropped, `f` is called with the dropped item
+    /// and the position it should be inserted at. The resulting message
+    /// is sent to `sender`. Items can be dropped from any [`TypedGridView`]
+    /// with the same item type, including this one.
+    ///
+    /// Like the position of [`move_item()`](Self::move_item), the insertion position
+    /// refers to the list without filters and sorting: the item is inserted before
+    /// or after the item it was dropped on. While an item is dragged
+    /// over a grid item, the grid item gets the CSS class `drop-before`
+    /// or `drop-after` to indicate where the item will be inserted.
+    ///
+    /// The grid isn't changed automatically, so you usually want to
+    /// call [`move_item()`](Self::move_item) when handling the message:
+    ///
+    /// ```ignore
+    /// Msg::Move(item, position) => {
+    ///     self.grid_view_wrapper.move_item(&item, position);
+    /// }
+    /// ```
+    ///
+    /// This method should only be called once.
+    #[cfg(feature = "gnome_43")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "gnome_43")))]
+    pub fn enable_drag_and_drop<F, Msg>(&mut self, sender: &Sender<Msg>, f: F)
+    where
+        F: Fn(TypedListItem<T>, u32) -> Msg + 'static,
+        Msg: 'static,
+    {
+        let Some(factory) = self
+            .view
+            .factory()
+            .and_downcast::<gtk::SignalListItemFactory>()
+        else {
+            return;
+        };
+
+        let sender = sender.clone();
+        let f = Rc::new(f);
+        let store = self.store.clone();
+
+        factory.connect_setup(move |_, list_item| {
+            let list_item = list_item
+                .downcast_ref::<gtk::ListItem>()
+                .expect("Needs to be ListItem");
+            let Some(root) = list_item.child() else {
+                return;
+            };
+
+            let drag_source = gtk::DragSource::builder()
+                .actions(gdk::DragAction::MOVE)
+                .build();
+            {
+                let list_item = list_item.downgrade();
+                drag_source.connect_prepare(move |_, _, _| {
+                    let item = list_item.upgrade()?.item()?;
+                    Some(gdk::ContentProvider::for_value(&item.to_value()))
+                });
+            }
+            {
+                let root = root.downgrade();
+                drag_source.connect_drag_begin(move |drag_source, _| {
+                    if let Some(root) = root.upgrade() {
+                        let icon = gtk::WidgetPaintable::new(Some(&root));
+                        drag_source.set_icon(Some(&icon), 0, 0);
+                    }
+                });
+            }
+            root.add_controller(drag_source);
+
+            let drop_target =
+                gtk::DropTarget::new(glib::BoxedAnyObject::static_type(), gdk::DragAction::MOVE);
+            {
+                let root = root.downgrade();
+                drop_target.connect_motion(move |_, x, _| {
+                    if let Some(root) = root.upgrade() {
+                        let after = x > f64::from(root.width()) / 2.0;
+                        root.set_class_active(DROP_BEFORE_CLASS, !after);
+                        root.set_class_active(DROP_AFTER_CLASS, after);
+                    }
+                    gdk::DragAction::MOVE
+                });
+            }
+            {
+                let root = root.downgrade();
+                drop_target.connect_leave(move |_| {
+                    if let Some(root) = root.upgrade() {
+                        root.remove_css_class(DROP_BEFORE_CLASS);
+                        root.remove_css_class(DROP_AFTER_CLASS);
+                    }
+                });
+            }
+            {
+                let root = root.downgrade();
+                let list_item = list_item.downgrade();
+                let sender = sender.clone();
+                let f = f.clone();
+                let store = store.clone();
+                drop_target.connect_drop(move |_, value, x, _| {
+                    let (Some(root), Some(list_item)) = (root.upgrade(), list_item.upgrade())
+                    else {
+                        return false;
+                    };
+                    root.remove_css_class(DROP_BEFORE_CLASS);
+                    root.remove_css_class(DROP_AFTER_CLASS);
+
+                    // Ignore items of grid views with other item types.
+                    let Ok(obj) = value.get::<glib::BoxedAnyObject>() else {
+                        return false;
+                    };
+                    if obj.try_borrow::<T>().is_err() {
+                        return false;
+                    }
+
+                    // Convert the position in the view to a position in the store.
+                    let Some(mut position) = list_item.item().and_then(|item| store.find(&item))
+                    else {
+                        return false;
+                    };
+                    if x > f64::from(root.width()) / 2.0 {
+                        position += 1;
+                    }
+                    sender.emit(f(TypedListItem::new(obj), position));
+                    true
+                });
+            }
+            root.add_controller(drop_target);
+        });
+
+        // Recreate the widgets of all items, so they get the controllers as well.
+        self.view.set_factory(None::<&gtk::ListItemFactory>);
+        self.view.set_factory(Some(&factory));
+    }
+}
+
+impl<T> TypedGridView<T, gtk::SelectionModel>
+where
+    T: RelmGridItem,
+{
+    /// Returns the current selection behavior.
+    #[must_use]
+    pub fn selection_behavior(&self) -> SelectionBehavior {
+        SelectionBehavior::of(&self.selection_model).unwrap_or(SelectionBehavior::Single)
+    }
+
+    /// Switch between no, single and multi selection.
+    ///
+    /// The selected items are kept as far as possible. This replaces
+    /// [`selection_model`](Self::selection_model), so signal handlers
+    /// connected to the old selection model need to be connected again.
+    pub fn set_selection_behavior(&mut self, behavior: SelectionBehavior) {
+        if self.selection_behavior() != behavior {
+            self.selection_model = behavior.switch(&self.selection_model, &self.active_model);
+            self.view.set_model(Some(&self.selection_model));
+        }
+    }
 }
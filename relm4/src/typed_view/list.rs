@@ -1,11 +1,14 @@
 //! Idiomatic and high-level abstraction over [`gtk::ListView`].
 
-use super::{get_mut_value, get_value, Filter, OrdFn, RelmSelectionExt, TypedListItem};
+use super::{
+    get_mut_value, get_value, paged, Filter, OrdFn, PagedLoader, RelmSelectionExt, SearchFilter,
+    SelectionBehavior, TypedListItem,
+};
 use gtk::{
     gio, glib,
     prelude::{Cast, CastNone, IsA, ListItemExt, ListModelExt, ObjectExt},
 };
-use std::{any::Any, cmp::Ordering, marker::PhantomData};
+use std::{any::Any, cmp::Ordering, future::Future, marker::PhantomData, ops::Range};
 
 /// An item of a [`TypedListView`].
 pub trait RelmListItem: Any {
@@ -28,6 +31,39 @@ pub trait RelmListItem: Any {
     fn teardown(_list_item: &gtk::ListItem) {}
 }
 
+/// An item of a [`TypedListView`] that belongs to a section.
+///
+/// Items with the same section are grouped together and a header
+/// is shown above each group.
+#[cfg(feature = "gnome_45")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gnome_45")))]
+pub trait RelmSectionItem: RelmListItem {
+    /// The key that identifies the section of an item.
+    ///
+    /// Sections are sorted by their key.
+    type Section: Ord;
+
+    /// The top-level widget for the section header.
+    type HeaderRoot: IsA<gtk::Widget>;
+
+    /// The widgets created for the section header.
+    type HeaderWidgets;
+
+    /// Returns the section of the item.
+    fn section(&self) -> Self::Section;
+
+    /// Construct the widgets of a section header.
+    fn setup_header(header: &gtk::ListHeader) -> (Self::HeaderRoot, Self::HeaderWidgets);
+
+    /// Bind the widgets of a section header to match the section.
+    fn bind_header(
+        _section: &Self::Section,
+        _widgets: &mut Self::HeaderWidgets,
+        _root: &mut Self::HeaderRoot,
+    ) {
+    }
+}
+
 /// A high-level wrapper around [`gio::ListStore`],
 /// [`gtk::SignalListItemFactory`] and [`gtk::ListView`].
 ///
@@ -72,6 +108,87 @@ where
     }
 }
 
+#[cfg(feature = "gnome_45")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gnome_45")))]
+impl<T, S> TypedListView<T, S>
+where
+    T: RelmSectionItem,
+    S: RelmSelectionExt,
+{
+    /// Create a new [`TypedListView`] that groups the items
+    /// by their section and shows a header above each section.
+    ///
+    /// Items of the same section keep the order in which they were added.
+    #[must_use]
+    pub fn with_sections() -> Self {
+        Self::init_sections(None)
+    }
+
+    /// Create a new [`TypedListView`] that groups the items
+    /// by their section and sorts the items of each section
+    /// based on the [`Ord`] trait.
+    #[must_use]
+    pub fn with_sections_and_sorting() -> Self
+    where
+        T: Ord,
+    {
+        Self::init_sections(Some(Box::new(T::cmp)))
+    }
+
+    fn init_sections(sort_fn: OrdFn<T>) -> Self {
+        let this = Self::init(Some(Box::new(move |first: &T, second: &T| {
+            first.section().cmp(&second.section()).then_with(|| {
+                sort_fn
+                    .as_ref()
+                    .map_or(Ordering::Equal, |f| f(first, second))
+            })
+        })));
+
+        let section_sorter = gtk::CustomSorter::new(|first, second| {
+            let first = get_value::<T>(first).section();
+            let second = get_value::<T>(second).section();
+            match first.cmp(&second) {
+                Ordering::Less => gtk::Ordering::Smaller,
+                Ordering::Equal => gtk::Ordering::Equal,
+                Ordering::Greater => gtk::Ordering::Larger,
+            }
+        });
+        this.base_model
+            .downcast_ref::<gtk::SortListModel>()
+            .expect("Needs to be SortListModel")
+            .set_section_sorter(Some(&section_sorter));
+
+        let header_factory = gtk::SignalListItemFactory::new();
+        header_factory.connect_setup(move |_, header| {
+            let header = header
+                .downcast_ref::<gtk::ListHeader>()
+                .expect("Needs to be ListHeader");
+
+            let (root, widgets) = T::setup_header(header);
+            unsafe { root.set_data("widgets", widgets) };
+            header.set_child(Some(&root));
+        });
+
+        header_factory.connect_bind(move |_, header| {
+            let header = header
+                .downcast_ref::<gtk::ListHeader>()
+                .expect("Needs to be ListHeader");
+
+            let obj = header.item().unwrap();
+            let section = get_value::<T>(&obj).section();
+
+            let mut root = header.child().and_downcast::<T::HeaderRoot>().unwrap();
+
+            let mut widgets = unsafe { root.steal_data("widgets") }.unwrap();
+            T::bind_header(&section, &mut widgets, &mut root);
+            unsafe { root.set_data("widgets", widgets) };
+        });
+
+        this.view.set_header_factory(Some(&header_factory));
+        this
+    }
+}
+
 impl<T, S> Default for TypedListView<T, S>
 where
     T: RelmListItem,
@@ -118,6 +235,7 @@ where
                 .child();
 
             let obj = list_item.item().unwrap();
+            paged::request_page(&obj);
             let mut obj = get_mut_value::<T>(&obj);
 
             let mut root = widget.and_downcast::<T::Root>().unwrap();
@@ -196,6 +314,34 @@ where
             let value = get_value::<T>(obj);
             f(&value)
         });
+        self.push_filter(filter);
+    }
+
+    /// Add a filter that matches the stored items against a search query.
+    /// The returned [`SearchFilter`] is used to update the query.
+    ///
+    /// Like other filters, it can be toggled with
+    /// [`set_filter_status()`](Self::set_filter_status).
+    pub fn add_search_filter<F: Fn(&T, &str) -> bool + 'static>(&mut self, f: F) -> SearchFilter {
+        let search = SearchFilter::new(f);
+        self.push_filter(search.filter().clone());
+        search
+    }
+
+    /// Add a search filter whose query is the text of `entry`.
+    ///
+    /// See [`add_search_filter()`](Self::add_search_filter) for details.
+    pub fn bind_search_entry<F: Fn(&T, &str) -> bool + 'static>(
+        &mut self,
+        entry: &gtk::SearchEntry,
+        f: F,
+    ) -> SearchFilter {
+        let search = self.add_search_filter(f);
+        search.bind_search_entry(entry);
+        search
+    }
+
+    fn push_filter(&mut self, filter: gtk::CustomFilter) {
         let filter_model =
             gtk::FilterListModel::new(Some(self.active_model.clone()), Some(filter.clone()));
         self.active_model = filter_model.clone().upcast();
@@ -206,6 +352,27 @@ where
         });
     }
 
+    /// Replace all items with placeholder items and load the
+    /// actual items in pages of `page_size` items from `fetch`.
+    ///
+    /// `placeholder` creates the placeholder item at a given position.
+    /// `fetch` is called with the range of positions of a page
+    /// once one of its placeholder items is shown. See [`PagedLoader`] for details.
+    pub fn load_paged<P, F, Fut>(
+        &mut self,
+        len: u32,
+        page_size: u32,
+        placeholder: P,
+        fetch: F,
+    ) -> PagedLoader<T>
+    where
+        P: Fn(u32) -> T + 'static,
+        F: Fn(Range<u32>) -> Fut + 'static,
+        Fut: Future<Output = Vec<T>> + 'static,
+    {
+        PagedLoader::new(self.store.clone(), len, page_size, placeholder, fetch)
+    }
+
     /// Returns the amount of filters that were added.
     pub fn filters_len(&self) -> usize {
         self.filters.len()
@@ -332,3 +499,26 @@ where
         self.store.remove_all();
     }
 }
+
+impl<T> TypedListView<T, gtk::SelectionModel>
+where
+    T: RelmListItem,
+{
+    /// Returns the current selection behavior.
+    #[must_use]
+    pub fn selection_behavior(&self) -> SelectionBehavior {
+        SelectionBehavior::of(&self.selection_model).unwrap_or(SelectionBehavior::Single)
+    }
+
+    /// Switch between no, single and multi selection.
+    ///
+    /// The selected items are kept as far as possible. This replaces
+    /// [`selection_model`](Self::selection_model), so signal handlers
+    /// connected to the old selection model need to be connected again.
+    pub fn set_selection_behavior(&mut self, behavior: SelectionBehavior) {
+        if self.selection_behavior() != behavior {
+            self.selection_model = behavior.switch(&self.selection_model, &self.active_model);
+            self.view.set_model(Some(&self.selection_model));
+        }
+    }
+}
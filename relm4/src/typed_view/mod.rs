@@ -3,10 +3,18 @@
 pub mod column;
 pub mod grid;
 pub mod list;
+mod paged;
+mod search;
 mod selection_ext;
 
+pub use self::paged::PagedLoader;
+pub use self::search::SearchFilter;
+
 use self::selection_ext::RelmSelectionExt;
-use gtk::{glib, prelude::Cast};
+use gtk::{
+    gio, glib,
+    prelude::{Cast, SelectionModelExt},
+};
 use std::{
     cell::{Ref, RefMut},
     cmp::Ordering,
@@ -20,6 +28,60 @@ struct Filter {
     filter: gtk::CustomFilter,
     model: gtk::FilterListModel,
 }
+/// How users can select the items of a typed view.
+///
+/// Typed views that use [`gtk::SelectionModel`] as selection model
+/// can switch their selection behavior at runtime with `set_selection_behavior`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionBehavior {
+    /// No items can be selected, see [`gtk::NoSelection`].
+    None,
+    /// One item can be selected, see [`gtk::SingleSelection`].
+    Single,
+    /// Multiple items can be selected, see [`gtk::MultiSelection`].
+    Multi,
+}
+
+impl SelectionBehavior {
+    /// Returns the behavior of a selection model.
+    ///
+    /// Returns [`None`] for selection models other than
+    /// [`gtk::NoSelection`], [`gtk::SingleSelection`] and [`gtk::MultiSelection`].
+    #[must_use]
+    pub fn of(selection_model: &gtk::SelectionModel) -> Option<Self> {
+        if selection_model.is::<gtk::NoSelection>() {
+            Some(Self::None)
+        } else if selection_model.is::<gtk::SingleSelection>() {
+            Some(Self::Single)
+        } else if selection_model.is::<gtk::MultiSelection>() {
+            Some(Self::Multi)
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new selection model with this behavior that keeps
+    /// as much of the selection of `old` as possible.
+    fn switch(self, old: &gtk::SelectionModel, model: &gio::ListModel) -> gtk::SelectionModel {
+        let selected = old.selection();
+        match self {
+            Self::None => gtk::NoSelection::new(Some(model.clone())).upcast(),
+            Self::Single => {
+                let selection = gtk::SingleSelection::new(Some(model.clone()));
+                if !selected.is_empty() {
+                    selection.set_selected(selected.minimum());
+                }
+                selection.upcast()
+            }
+            Self::Multi => {
+                let selection = gtk::MultiSelection::new(Some(model.clone()));
+                selection.set_selection(&selected, &selected);
+                selection.upcast()
+            }
+        }
+    }
+}
+
 /// And item of a [`list::TypedListView`].
 ///
 /// The interface is very similar to [`std::cell::RefCell`].
@@ -0,0 +1,190 @@
+//! Loading the items of typed views in pages.
+
+use futures::future::{FutureExt, LocalBoxFuture};
+use gtk::{
+    gio, glib,
+    prelude::{ListModelExt, ObjectExt},
+};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashSet,
+    fmt::Debug,
+    future::Future,
+    ops::Range,
+    rc::{Rc, Weak},
+};
+
+/// The key of the data that placeholder items use to request their page.
+const PAGE_REQUEST_KEY: &str = "relm4-page-request";
+
+type PageRequest = Box<dyn Fn()>;
+
+/// Loads the page of a placeholder item once it is shown in a view.
+pub(super) fn request_page(obj: &glib::Object) {
+    if let Some(request) = unsafe { obj.data::<PageRequest>(PAGE_REQUEST_KEY) } {
+        unsafe { request.as_ref()() };
+    }
+}
+
+struct PagedState<T> {
+    store: gio::ListStore,
+    page_size: u32,
+    placeholder: Box<dyn Fn(u32) -> T>,
+    fetch: Box<dyn Fn(Range<u32>) -> LocalBoxFuture<'static, Vec<T>>>,
+    requested: RefCell<HashSet<u32>>,
+    loading: Cell<usize>,
+    /// Increased on every reload to ignore pages of previous loads.
+    generation: Cell<u64>,
+}
+
+/// Loads the items of a typed view in pages from an async callback.
+///
+/// Paged loaders are created by the `load_paged` methods of
+/// [`TypedListView`](super::list::TypedListView),
+/// [`TypedGridView`](super::grid::TypedGridView) and
+/// [`TypedColumnView`](super::column::TypedColumnView).
+///
+/// Initially, the view is filled with placeholder items.
+/// Once a placeholder item is shown, the page that contains it
+/// is fetched and its placeholder items are replaced by the fetched items.
+///
+/// The loader needs to be kept alive as long as pages should be loaded.
+///
+/// Positions and ranges refer to the list without filters and sorting.
+/// Inserting or removing items while pages are loading
+/// will place the fetched items at the wrong positions.
+pub struct PagedLoader<T> {
+    state: Rc<PagedState<T>>,
+}
+
+impl<T> Debug for PagedLoader<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PagedLoader")
+            .field("store", &self.state.store)
+            .field("page_size", &self.state.page_size)
+            .field("requested", &self.state.requested)
+            .field("loading", &self.state.loading)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: 'static> PagedLoader<T> {
+    pub(super) fn new<P, F, Fut>(
+        store: gio::ListStore,
+        len: u32,
+        page_size: u32,
+        placeholder: P,
+        fetch: F,
+    ) -> Self
+    where
+        P: Fn(u32) -> T + 'static,
+        F: Fn(Range<u32>) -> Fut + 'static,
+        Fut: Future<Output = Vec<T>> + 'static,
+    {
+        assert!(page_size > 0, "The page size must be larger than zero");
+
+        let state = Rc::new(PagedState {
+            store,
+            page_size,
+            placeholder: Box::new(placeholder),
+            fetch: Box::new(move |range| fetch(range).boxed_local()),
+            requested: RefCell::default(),
+            loading: Cell::new(0),
+            generation: Cell::new(0),
+        });
+
+        let loader = Self { state };
+        loader.reload(len);
+        loader
+    }
+
+    /// Returns the amount of items per page.
+    #[must_use]
+    pub fn page_size(&self) -> u32 {
+        self.state.page_size
+    }
+
+    /// Returns `true` while pages are fetched.
+    #[must_use]
+    pub fn is_loading(&self) -> bool {
+        self.state.loading.get() > 0
+    }
+
+    /// Replaces all items with `len` placeholder items
+    /// and fetches their pages again once they are shown.
+    ///
+    /// Pages that are still fetched from a previous load are discarded.
+    pub fn reload(&self, len: u32) {
+        let state = &self.state;
+        state.generation.set(state.generation.get() + 1);
+        state.requested.borrow_mut().clear();
+
+        let placeholders: Vec<glib::BoxedAnyObject> = (0..len)
+            .map(|position| placeholder_object(state, position))
+            .collect();
+        state.store.splice(0, state.store.n_items(), &placeholders);
+    }
+
+    /// Fetches the page that contains the item at `position`
+    /// unless it was already requested.
+    pub fn load_page_at(&self, position: u32) {
+        load_page(&self.state, position / self.state.page_size);
+    }
+}
+
+fn placeholder_object<T: 'static>(
+    state: &Rc<PagedState<T>>,
+    position: u32,
+) -> glib::BoxedAnyObject {
+    let obj = glib::BoxedAnyObject::new((state.placeholder)(position));
+
+    let page = position / state.page_size;
+    let state: Weak<PagedState<T>> = Rc::downgrade(state);
+    let request: PageRequest = Box::new(move || {
+        if let Some(state) = state.upgrade() {
+            load_page(&state, page);
+        }
+    });
+    unsafe { obj.set_data(PAGE_REQUEST_KEY, request) };
+
+    obj
+}
+
+fn load_page<T: 'static>(state: &Rc<PagedState<T>>, page: u32) {
+    if !state.requested.borrow_mut().insert(page) {
+        return;
+    }
+
+    let start = page * state.page_size;
+    let end = (start + state.page_size).min(state.store.n_items());
+    if start >= end {
+        return;
+    }
+
+    let generation = state.generation.get();
+    let future = (state.fetch)(start..end);
+    state.loading.set(state.loading.get() + 1);
+    let state = Rc::downgrade(state);
+
+    crate::spawn_local(async move {
+        let items = future.await;
+
+        let Some(state) = state.upgrade() else {
+            return;
+        };
+        state.loading.set(state.loading.get() - 1);
+
+        // The items were reloaded in the meantime.
+        if state.generation.get() != generation {
+            return;
+        }
+
+        let end = end.min(state.store.n_items());
+        let objects: Vec<glib::BoxedAnyObject> = items
+            .into_iter()
+            .take(end.saturating_sub(start) as usize)
+            .map(glib::BoxedAnyObject::new)
+            .collect();
+        state.store.splice(start, objects.len() as u32, &objects);
+    });
+}
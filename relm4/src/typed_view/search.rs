@@ -0,0 +1,144 @@
+//! Filters that match the items of typed views against a search query.
+
+use super::get_value;
+use gtk::{
+    glib,
+    prelude::{EditableExt, FilterExt, IsA},
+};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration,
+};
+
+/// The default delay of [`SearchFilter::set_query`].
+///
+/// This is the same as the default delay of [`gtk::SearchEntry`].
+const DEFAULT_DELAY: Duration = Duration::from_millis(150);
+
+#[derive(Debug)]
+struct SearchState {
+    query: RefCell<String>,
+    delay: Cell<Duration>,
+    pending: RefCell<Option<glib::SourceId>>,
+}
+
+/// A filter of a typed view that matches the items against a search query.
+///
+/// Search filters are created by the `add_search_filter` and `bind_search_entry`
+/// methods of [`TypedListView`](super::list::TypedListView),
+/// [`TypedGridView`](super::grid::TypedGridView) and
+/// [`TypedColumnView`](super::column::TypedColumnView).
+///
+/// While the query is empty, all items are shown.
+#[derive(Debug, Clone)]
+pub struct SearchFilter {
+    filter: gtk::CustomFilter,
+    state: Rc<SearchState>,
+}
+
+impl SearchFilter {
+    pub(super) fn new<T, F>(f: F) -> Self
+    where
+        T: 'static,
+        F: Fn(&T, &str) -> bool + 'static,
+    {
+        let state = Rc::new(SearchState {
+            query: RefCell::default(),
+            delay: Cell::new(DEFAULT_DELAY),
+            pending: RefCell::default(),
+        });
+
+        let filter_state = state.clone();
+        let filter = gtk::CustomFilter::new(move |obj| {
+            let query = filter_state.query.borrow();
+            query.is_empty() || f(&get_value::<T>(obj), &query)
+        });
+
+        Self { filter, state }
+    }
+
+    pub(super) fn filter(&self) -> &gtk::CustomFilter {
+        &self.filter
+    }
+
+    /// Returns the current search query.
+    #[must_use]
+    pub fn query(&self) -> String {
+        self.state.query.borrow().clone()
+    }
+
+    /// Returns the delay of [`set_query()`](Self::set_query).
+    #[must_use]
+    pub fn delay(&self) -> Duration {
+        self.state.delay.get()
+    }
+
+    /// Sets the delay of [`set_query()`](Self::set_query).
+    ///
+    /// The default delay is 150 milliseconds.
+    pub fn set_delay(&self, delay: Duration) {
+        self.state.delay.set(delay);
+    }
+
+    /// Updates the search query after the [`delay()`](Self::delay) has passed.
+    ///
+    /// Calling this again before the delay has passed restarts the delay,
+    /// so the items are only filtered again once the query stops changing.
+    pub fn set_query(&self, query: &str) {
+        self.cancel_pending();
+
+        let delay = self.delay();
+        if delay.is_zero() {
+            self.set_query_now(query);
+        } else {
+            let search = self.clone();
+            let query = query.to_owned();
+            let source = glib::timeout_add_local_once(delay, move || {
+                search.state.pending.take();
+                search.set_query_now(&query);
+            });
+            self.state.pending.replace(Some(source));
+        }
+    }
+
+    /// Updates the search query immediately and filters the items again.
+    pub fn set_query_now(&self, query: &str) {
+        self.cancel_pending();
+
+        let old_query = self.state.query.replace(query.to_owned());
+        if old_query != query {
+            self.filter.changed(gtk::FilterChange::Different);
+        }
+    }
+
+    /// Updates the search query whenever a search is started in `entry`.
+    ///
+    /// [`gtk::SearchEntry`] already delays its `search-changed` signal,
+    /// so the query is updated without an additional delay.
+    pub fn bind_search_entry(&self, entry: &gtk::SearchEntry) {
+        self.set_query_now(&entry.text());
+
+        let search = self.clone();
+        entry.connect_search_changed(move |entry| {
+            search.set_query_now(&entry.text());
+        });
+    }
+
+    /// Updates the search query whenever the text of `editable` changes,
+    /// using the [`delay()`](Self::delay) of [`set_query()`](Self::set_query).
+    pub fn bind_editable(&self, editable: &impl IsA<gtk::Editable>) {
+        self.set_query_now(&editable.text());
+
+        let search = self.clone();
+        editable.connect_changed(move |editable| {
+            search.set_query(&editable.text());
+        });
+    }
+
+    fn cancel_pending(&self) {
+        if let Some(source) = self.state.pending.take() {
+            source.remove();
+        }
+    }
+}
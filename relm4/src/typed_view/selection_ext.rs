@@ -1,4 +1,7 @@
-use gtk::{gio, prelude::IsA};
+use gtk::{
+    gio,
+    prelude::{Cast, IsA},
+};
 
 pub trait RelmSelectionExt: IsA<gtk::SelectionModel> {
     fn new_model(model: gio::ListModel) -> Self;
@@ -21,3 +24,21 @@ macro_rules! impl_selection (
 impl_selection!(gtk::SingleSelection);
 impl_selection!(gtk::MultiSelection);
 impl_selection!(gtk::NoSelection);
+
+// Starts with single selection, see `SelectionBehavior`
+// to switch the selection behavior at runtime.
+impl RelmSelectionExt for gtk::SelectionModel {
+    fn new_model(model: gio::ListModel) -> Self {
+        gtk::SingleSelection::new(Some(model)).upcast()
+    }
+
+    fn set_list_model(&mut self, model: &gio::ListModel) {
+        if let Some(selection) = self.downcast_ref::<gtk::SingleSelection>() {
+            selection.set_model(Some(model));
+        } else if let Some(selection) = self.downcast_ref::<gtk::MultiSelection>() {
+            selection.set_model(Some(model));
+        } else if let Some(selection) = self.downcast_ref::<gtk::NoSelection>() {
+            selection.set_model(Some(model));
+        }
+    }
+}